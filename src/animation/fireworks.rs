@@ -73,24 +73,23 @@ impl Firework {
                 && x < area.x + area.width
                 && y >= area.y
                 && y < area.y + area.height
+                && let Some(cell) = buf.cell_mut(Position::new(x, y))
             {
-                if let Some(cell) = buf.cell_mut(Position::new(x, y)) {
-                    // Fade character as life decreases
-                    let life_frac = p.life as f64 / p.max_life as f64;
-                    let ch = if life_frac < 0.2 {
-                        '.'
-                    } else if life_frac < 0.5 {
-                        match p.char {
-                            '*' => '+',
-                            'o' => '.',
-                            _ => p.char,
-                        }
-                    } else {
-                        p.char
-                    };
-                    cell.set_char(ch);
-                    cell.set_fg(p.color);
-                }
+                // Fade character as life decreases
+                let life_frac = p.life as f64 / p.max_life as f64;
+                let ch = if life_frac < 0.2 {
+                    '.'
+                } else if life_frac < 0.5 {
+                    match p.char {
+                        '*' => '+',
+                        'o' => '.',
+                        _ => p.char,
+                    }
+                } else {
+                    p.char
+                };
+                cell.set_char(ch);
+                cell.set_fg(p.color);
             }
         }
     }
@@ -107,20 +106,8 @@ impl FireworkManager {
             .push(Firework::new(x, y, colors, count, duration));
     }
 
-    /// Spawn a burst of multiple fireworks scattered around a center point
-    pub fn spawn_burst(
-        &mut self,
-        cx: f64,
-        cy: f64,
-        colors: &[Color],
-        count_per: u32,
-        duration: u32,
-        num_bursts: u32,
-    ) {
-        self.spawn_burst_wide(cx, cy, 12.0, 6.0, colors, count_per, duration, num_bursts);
-    }
-
     /// Spawn a burst with configurable scatter range
+    #[allow(clippy::too_many_arguments)]
     pub fn spawn_burst_wide(
         &mut self,
         cx: f64,
@@ -1,42 +1,69 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use rand::rngs::SmallRng;
-use rand::{Rng, SeedableRng};
 
 use ratatui::style::Color;
 
 use crate::animation::fireworks::FireworkManager;
 use crate::animation::screen_flash::FlashManager;
 use crate::audio::SoundManager;
-use crate::data::chests::loot_table_for;
-use crate::data::items::get_item;
-use crate::data::rebirth_skills::all_rebirth_skills;
-use crate::data::relics::{self, relic_stat_totals};
-use crate::data::skills::all_skills;
-use crate::data::upgrades::all_upgrades;
+use crate::data::achievements::{all_achievements, get_achievement};
+use crate::data::challenges::{all_challenges, get_challenge};
+use crate::data::recipes::{all_recipes, get_recipe};
+use crate::data::rebirth_skills::{all_rebirth_skills, get_rebirth_skill};
+use crate::data::relics;
+use crate::data::sets::{all_item_sets, get_item_set};
+use crate::data::skills::{all_skills, get_skill};
+use crate::data::transcendence_skills::{all_transcendence_skills, get_transcendence_skill};
+use crate::data::upgrades::{UpgradeCategory, all_upgrades, get_upgrade};
+use crate::game::automation::{AutoRule, RuleAction};
+use crate::game::bignum::BigNum;
 use crate::game::chest::{ChestState, ChestType};
+use crate::game::engine::GameEngine;
+use crate::game::events::{Command, GameEvent, LootSource, Proc, RespecTarget};
+use crate::game::inventory::{InventoryQuery, InventoryView};
 use crate::game::item::{ItemInstance, Rarity};
-use crate::game::progression::xp_for_level;
+use crate::game::loot_filter::FilterAction;
+use crate::game::offline::{self, OfflineReport};
+use crate::game::profiles::{self, ProfileSummary};
+use crate::game::relic::RelicState;
+use crate::game::rng::GameRng;
 use crate::game::save;
 use crate::game::state::GameState;
 
+/// Chest tiers per page of the chest menu, one for each digit hotkey.
+pub const CHEST_MENU_PAGE: usize = 9;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ActiveTab {
     Skills,
     Upgrades,
     Relics,
     Inventory,
+    Crafting,
+    Collection,
     Stats,
+    Achievements,
+    Bounties,
     Rebirth,
+    Challenges,
+    Transcendence,
+    Automation,
 }
 
 impl ActiveTab {
-    pub const ALL: [ActiveTab; 6] = [
+    pub const ALL: [ActiveTab; 13] = [
         ActiveTab::Skills,
         ActiveTab::Upgrades,
         ActiveTab::Relics,
         ActiveTab::Inventory,
+        ActiveTab::Crafting,
+        ActiveTab::Collection,
         ActiveTab::Stats,
+        ActiveTab::Achievements,
+        ActiveTab::Bounties,
         ActiveTab::Rebirth,
+        ActiveTab::Challenges,
+        ActiveTab::Transcendence,
+        ActiveTab::Automation,
     ];
 
     pub fn label(self) -> &'static str {
@@ -45,18 +72,27 @@ impl ActiveTab {
             ActiveTab::Upgrades => "Upgrades",
             ActiveTab::Relics => "Relics",
             ActiveTab::Inventory => "Inventory",
+            ActiveTab::Crafting => "Craft",
+            ActiveTab::Collection => "Sets",
             ActiveTab::Stats => "Stats",
+            ActiveTab::Achievements => "Achievements",
+            ActiveTab::Bounties => "Bounties",
             ActiveTab::Rebirth => "Rebirth",
+            ActiveTab::Challenges => "Challenges",
+            ActiveTab::Transcendence => "Transcend",
+            ActiveTab::Automation => "Auto",
         }
     }
 }
 
 pub struct App {
-    pub state: GameState,
+    pub engine: GameEngine,
+    pub seed: Option<u64>,            // --seed, reused when the game is reset
+    pub profile: String,              // save slot being played
+    pub picker: Option<ProfilePicker>, // start-up profile picker, until a profile is opened
     pub active_tab: ActiveTab,
     pub tab_scroll: usize,
     pub show_help: bool,
-    pub rng: SmallRng,
     pub auto_save_counter: u32,
     pub message_log: Vec<(String, u32)>, // (message, ticks_remaining)
     pub float_texts: Vec<FloatText>,
@@ -64,26 +100,28 @@ pub struct App {
     pub flashes: FlashManager,
     pub screen_w: u16,
     pub screen_h: u16,
-    // Skill trigger counters
-    pub chests_since_xp_surge: u32,
-    pub idle_income_ticks: u32,
-    // New skill counters
-    pub consecutive_chests: u32,      // momentum skill
-    pub empty_streak: u32,            // gambler_spirit skill
-    pub chaos_buff_ticks: u32,        // chaos_surge skill
-    pub chaos_buff_type: Option<u8>,  // 0=GP, 1=XP, 2=Speed
-    pub items_sold_count: u64,        // alchemy tracking
-    pub catalyst_stacks: f64,         // catalyst_brew (resets on rebirth)
     pub rebirth_confirm: bool,        // R key double-press confirmation
-    pub rare_streak_count: u32,       // lucky_streak tracking
-    pub consecutive_crits: u32,       // combo_counter tracking
+    pub transcend_confirm: bool,      // T key double-press confirmation
+    pub respec_confirm: Option<RespecTarget>, // X/Z double-press confirmation
+    pub challenge_confirm: Option<String>, // E double-press to start this challenge
+    pub abandon_confirm: bool,        // A double-press to abandon the challenge
     pub auto_opener_paused: bool,     // pause auto opener with 'P'
     pub show_chest_menu: bool,        // show chest selection popup
-    pub chest_menu_selected: usize,   // selected chest in menu (0-6)
+    pub chest_menu_selected: usize,   // selected chest in menu, index into ChestType::ALL
     pub show_settings: bool,          // show settings menu
     pub settings_selected: usize,     // selected setting option
     pub show_dev_options: bool,       // show dev options submenu
     pub dev_option_selected: usize,   // selected dev option
+    pub restore_offer: Option<RestoreOffer>, // save failed to load; offer a backup
+    pub offline_report: Option<OfflineReport>, // "While you were away" summary
+    pub inventory_query: InventoryQuery, // Inventory tab sort, rarity filter and search
+    pub search_typing: bool,          // typing into the inventory search after '/'
+    pub filter_open: bool,            // loot filter editor on the Inventory tab
+    pub filter_selected: usize,       // selected row in the loot filter editor
+    pub fusion_picks: Vec<String>,    // relics marked for fusion on the Relics tab
+    pub fusion_confirm: bool,         // fusion confirmation box is open
+    pub loadout_pick: bool,           // next digit picks a slot to save the relics into
+    pub loadout_naming: Option<(usize, String)>, // slot and name being typed for a loadout
     // Settings
     pub setting_show_animations: bool,   // show fireworks/flashes
     pub setting_chest_sounds: bool,      // play chest open/reveal/collect/level-up sounds
@@ -93,6 +131,68 @@ pub struct App {
     pub sound: Option<SoundManager>,
 }
 
+/// A save that failed to load, and the newest backup that still loads.
+pub struct RestoreOffer {
+    pub error: String,
+    pub backup: save::Backup,
+}
+
+/// The save slot list shown at start-up.
+pub struct ProfilePicker {
+    pub profiles: Vec<ProfileSummary>,
+    pub selected: usize,
+    pub input: Option<NameInput>,   // typing a name for New/Copy/Rename
+    pub confirm_delete: bool,       // D key double-press confirmation
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameAction {
+    New,
+    Copy,
+    Rename,
+}
+
+impl NameAction {
+    pub fn label(self) -> &'static str {
+        match self {
+            NameAction::New => "New profile",
+            NameAction::Copy => "Copy to",
+            NameAction::Rename => "Rename to",
+        }
+    }
+}
+
+pub struct NameInput {
+    pub action: NameAction,
+    pub text: String,
+}
+
+impl ProfilePicker {
+    fn new() -> Self {
+        Self {
+            profiles: profiles::list(),
+            selected: 0,
+            input: None,
+            confirm_delete: false,
+            error: None,
+        }
+    }
+
+    fn selected_name(&self) -> Option<String> {
+        self.profiles.get(self.selected).map(|p| p.name.clone())
+    }
+
+    /// Re-read the profile list and select `name` if it is there.
+    fn refresh(&mut self, name: Option<&str>) {
+        self.profiles = profiles::list();
+        if let Some(idx) = name.and_then(|n| self.profiles.iter().position(|p| p.name == n)) {
+            self.selected = idx;
+        }
+        self.selected = self.selected.min(self.profiles.len().saturating_sub(1));
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FloatDir {
     Up,
@@ -111,33 +211,26 @@ pub struct FloatText {
 }
 
 impl App {
-    pub fn new() -> Self {
-        let mut state = save::load_game().unwrap_or_default();
-
-        // Migrate old saves: convert old key/auto_opener upgrades to skills
-        let old_iron = state.upgrades.get_level("iron_key") >= 1;
-        let old_silver = state.upgrades.get_level("silver_key") >= 1;
-        let old_gold = state.upgrades.get_level("gold_key") >= 1;
-        let old_auto = state.upgrades.get_level("auto_opener") >= 1;
-
-        // Only migrate if skill_tree is empty (fresh load of old save)
-        if state.skill_tree.learned.is_empty() && state.player.level > 1 {
-            state
-                .skill_tree
-                .migrate_from_old_save(state.player.level, old_iron, old_silver, old_gold, old_auto);
-        }
+    /// `seed` (from `--seed`) restarts the loot RNG from the start of that
+    /// seed's stream instead of continuing the saved one. Without `profile`
+    /// (from `--profile`) the player picks one, unless there are none yet.
+    pub fn new(seed: Option<u64>, profile: Option<String>) -> Self {
+        profiles::adopt_legacy_save();
 
+        let state = GameState::default();
         let saved_volume = state.volume;
         let saved_animations = state.show_animations;
         let saved_chest_sounds = state.chest_sounds;
         let saved_ui_sounds = state.ui_sounds;
 
         let mut app = Self {
-            state,
+            engine: GameEngine::new(state),
+            seed,
+            profile: String::new(),
+            picker: None,
             active_tab: ActiveTab::Skills,
             tab_scroll: 0,
             show_help: false,
-            rng: SmallRng::from_os_rng(),
             auto_save_counter: 0,
             message_log: Vec::new(),
             float_texts: Vec::new(),
@@ -145,17 +238,11 @@ impl App {
             flashes: FlashManager::default(),
             screen_w: 80,
             screen_h: 24,
-            chests_since_xp_surge: 0,
-            idle_income_ticks: 0,
-            consecutive_chests: 0,
-            empty_streak: 0,
-            chaos_buff_ticks: 0,
-            chaos_buff_type: None,
-            items_sold_count: 0,
-            catalyst_stacks: 0.0,
             rebirth_confirm: false,
-            rare_streak_count: 0,
-            consecutive_crits: 0,
+            transcend_confirm: false,
+            respec_confirm: None,
+            challenge_confirm: None,
+            abandon_confirm: false,
             auto_opener_paused: false,
             show_chest_menu: false,
             chest_menu_selected: 0,
@@ -163,6 +250,16 @@ impl App {
             settings_selected: 0,
             show_dev_options: false,
             dev_option_selected: 0,
+            restore_offer: None,
+            offline_report: None,
+            inventory_query: InventoryQuery::default(),
+            search_typing: false,
+            filter_open: false,
+            filter_selected: 0,
+            fusion_picks: Vec::new(),
+            fusion_confirm: false,
+            loadout_pick: false,
+            loadout_naming: None,
             setting_show_animations: saved_animations,
             setting_chest_sounds: saved_chest_sounds,
             setting_ui_sounds: saved_ui_sounds,
@@ -170,16 +267,69 @@ impl App {
             sound: SoundManager::new(),
         };
 
-        // Apply volume from saved state
-        if let Some(ref mut snd) = app.sound {
-            snd.set_volume(app.setting_volume);
+        match profile {
+            Some(name) => app.open_profile(name),
+            None => {
+                let picker = ProfilePicker::new();
+                if picker.profiles.is_empty() {
+                    app.open_profile(profiles::DEFAULT_PROFILE.to_string());
+                } else {
+                    app.picker = Some(picker);
+                }
+            }
+        }
+
+        app
+    }
+
+    /// Load a profile's save (or start a new game in it) and play it.
+    fn open_profile(&mut self, name: String) {
+        let (mut state, load_error) = match save::load_game(&name) {
+            Ok(state) => (state.unwrap_or_default(), None),
+            Err(e) => (GameState::default(), Some(e)),
+        };
+        if let Some(seed) = self.seed {
+            state.rng = GameRng::from_seed(seed);
+        }
+        self.picker = None;
+        self.load_state(state);
+        self.add_message(format!("Playing profile \"{}\"", name));
+
+        // Never autosave a new game over a save we couldn't read
+        if let Some(e) = load_error {
+            let msg = match save::set_aside_unreadable_save(&name) {
+                Some(path) => format!("Save not loaded: {}. Kept it at {}", e, path.display()),
+                None => format!("Save not loaded: {}", e),
+            };
+            self.message_log.push((msg, 300));
+            self.restore_offer = save::load_backups(&name)
+                .into_iter()
+                .next()
+                .map(|backup| RestoreOffer {
+                    error: e.to_string(),
+                    backup,
+                });
+        } else {
+            self.offline_report = offline::catch_up(&mut self.engine, offline::unix_now());
         }
+        self.profile = name;
+    }
 
-        // Apply rebirth bonuses on load
-        app.apply_rebirth_bonuses();
-        app.recalculate_player_stats();
+    /// Replace the game with `state`, taking its saved settings.
+    fn load_state(&mut self, state: GameState) {
+        self.setting_volume = state.volume;
+        self.setting_show_animations = state.show_animations;
+        self.setting_chest_sounds = state.chest_sounds;
+        self.setting_ui_sounds = state.ui_sounds;
+        if let Some(ref mut snd) = self.sound {
+            snd.set_volume(self.setting_volume);
+        }
+        self.engine = GameEngine::new(state);
+    }
 
-        app
+    /// Read-only view of the game state for rendering.
+    pub fn state(&self) -> &GameState {
+        &self.engine.state
     }
 
     pub fn on_tick(&mut self) {
@@ -189,6 +339,12 @@ impl App {
             self.screen_h = h;
         }
 
+        // Hold the game until a profile is open and the player has decided
+        // about the backup
+        if self.picker.is_some() || self.restore_offer.is_some() {
+            return;
+        }
+
         // Auto-save every ~30 seconds (900 ticks at 30/sec)
         self.auto_save_counter += 1;
         if self.auto_save_counter >= 900 {
@@ -196,71 +352,9 @@ impl App {
             self.save_game();
         }
 
-        // Tick chest progress
-        self.state.chest_progress.tick();
-
-        // Auto-opener: skill tree version or legacy upgrade
-        let has_auto_skill = self.state.skill_tree.has_skill("auto_opener");
-        let has_auto_upgrade = self.state.upgrades.get_level("auto_opener") > 0;
-        if (has_auto_skill || has_auto_upgrade)
-            && self.state.chest_progress.state == ChestState::Idle
-        {
-            self.start_opening();
-        }
-
-        // Auto-collect after reveal
-        let quick_collect = self.state.skill_tree.has_skill("quick_collect");
-        let perpetual = self.state.skill_tree.has_skill("perpetual_motion");
-        if self.state.chest_progress.state == ChestState::Revealing {
-            // Quick Collect: auto-collect after 30 ticks (~1 second)
-            if quick_collect && self.state.chest_progress.reveal_ticks > 30 {
-                self.collect_and_reset();
-            }
-            // Perpetual Motion: auto-collect after 30 ticks (~1 second)
-            else if perpetual
-                && (has_auto_upgrade || has_auto_skill)
-                && self.state.chest_progress.reveal_ticks > 30
-            {
-                self.collect_and_reset();
-            }
-            // Legacy auto-collect or auto opener skill: after 60 ticks
-            else if (has_auto_upgrade || has_auto_skill)
-                && self.state.chest_progress.reveal_ticks > 60
-            {
-                self.collect_and_reset();
-            }
-        }
-
-        // Idle Income: earn GP per second while chest is idle, scales with level + GP multiplier
-        if self.state.skill_tree.has_skill("idle_income")
-            && self.state.chest_progress.state == ChestState::Idle
-        {
-            self.idle_income_ticks += 1;
-            let idle_rate = if self.state.skill_tree.has_skill("temporal_mastery") {
-                4 // ~8 payouts per second
-            } else {
-                15 // ~2 payouts per second
-            };
-            if self.idle_income_ticks >= idle_rate {
-                self.idle_income_ticks = 0;
-                // Base 5 GP, scaling with level and GP multiplier
-                let base = 5.0 + self.state.player.level as f64 * 2.0;
-                let gp = (base * self.state.player.gp_multiplier).max(1.0) as u64;
-                self.state.player.gp += gp;
-                self.state.stats.total_gp_earned += gp;
-                self.state.rebirth.gp_earned_this_run += gp;
-            }
-        } else {
-            self.idle_income_ticks = 0;
-        }
-
-        // Tick chaos buff
-        if self.chaos_buff_ticks > 0 {
-            self.chaos_buff_ticks -= 1;
-            if self.chaos_buff_ticks == 0 {
-                self.chaos_buff_type = None;
-            }
-        }
+        // Advance the game rules
+        let events = self.engine.tick();
+        self.handle_events(events);
 
         // Tick messages
         self.message_log.retain_mut(|m| {
@@ -277,17 +371,34 @@ impl App {
         // Tick animations
         self.fireworks.tick();
         self.flashes.tick();
-
-        // Check if chest just finished opening -> roll loot
-        if self.state.chest_progress.state == ChestState::Revealing
-            && self.state.chest_progress.reveal_ticks == 1
-        {
-            self.roll_loot();
-        }
     }
 
     /// Returns true if the app should quit
     pub fn on_key(&mut self, key: KeyEvent) -> bool {
+        if self.picker.is_some() {
+            return self.handle_picker_input(key);
+        }
+        if self.restore_offer.is_some() {
+            return self.handle_restore_input(key);
+        }
+        if self.offline_report.is_some() {
+            if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                return true;
+            }
+            self.offline_report = None;
+            return false;
+        }
+
+        if self.search_typing && self.active_tab == ActiveTab::Inventory {
+            return self.handle_search_input(key);
+        }
+        if self.fusion_confirm {
+            return self.handle_fusion_confirm(key);
+        }
+        if self.loadout_naming.is_some() {
+            return self.handle_loadout_name_input(key);
+        }
+
         // Global keys
         match key.code {
             KeyCode::Char('q') | KeyCode::Char('Q') => return true,
@@ -320,23 +431,27 @@ impl App {
             return self.handle_chest_menu_input(key);
         }
 
+        if self.filter_open && self.active_tab == ActiveTab::Inventory && self.handle_filter_input(key) {
+            return false;
+        }
+
         match key.code {
             // Chest interaction / Open or collect chest
             KeyCode::Char(' ') => {
                 self.play_chest(|s| s.play_click());
-                match self.state.chest_progress.state {
+                match self.engine.state.chest_progress.state {
                     ChestState::Idle => {
-                        self.start_opening();
+                        self.dispatch(Command::OpenChest);
                     }
                     ChestState::Opening => {} // can't interact while opening
                     ChestState::Revealing => {
                         // Require minimum reveal time so holding space doesn't skip it
-                        if self.state.chest_progress.reveal_ticks >= 5 {
-                            self.collect_and_reset();
+                        if self.engine.state.chest_progress.reveal_ticks >= 5 {
+                            self.dispatch(Command::Collect);
                         }
                     }
                     ChestState::Complete => {
-                        self.collect_and_reset();
+                        self.dispatch(Command::Collect);
                     }
                 }
             }
@@ -351,6 +466,12 @@ impl App {
                 self.active_tab = ActiveTab::ALL[(idx + 1) % ActiveTab::ALL.len()];
                 self.tab_scroll = 0;
                 self.rebirth_confirm = false;
+                self.transcend_confirm = false;
+                self.respec_confirm = None;
+                self.challenge_confirm = None;
+                self.abandon_confirm = false;
+                self.fusion_picks.clear();
+                self.loadout_pick = false;
             }
             KeyCode::BackTab | KeyCode::Left => {
                 self.play_ui(|s| s.play_tab_switch());
@@ -362,13 +483,19 @@ impl App {
                     [(idx + ActiveTab::ALL.len() - 1) % ActiveTab::ALL.len()];
                 self.tab_scroll = 0;
                 self.rebirth_confirm = false;
+                self.transcend_confirm = false;
+                self.respec_confirm = None;
+                self.challenge_confirm = None;
+                self.abandon_confirm = false;
+                self.fusion_picks.clear();
+                self.loadout_pick = false;
             }
 
             // Toggle chest menu with 'C'
             KeyCode::Char('c') | KeyCode::Char('C') => {
                 self.show_chest_menu = !self.show_chest_menu;
                 if self.show_chest_menu {
-                    self.chest_menu_selected = self.state.current_chest_type.index();
+                    self.chest_menu_selected = self.engine.state.current_chest_type.index();
                     self.play_ui(|s| s.play_menu_open());
                 } else {
                     self.play_ui(|s| s.play_menu_close());
@@ -383,10 +510,17 @@ impl App {
                 let max = match self.active_tab {
                     ActiveTab::Skills => all_skills().len().saturating_sub(1),
                     ActiveTab::Upgrades => all_upgrades().len().saturating_sub(1),
-                    ActiveTab::Relics => self.state.relics.owned.len().saturating_sub(1),
-                    ActiveTab::Inventory => self.state.inventory.items.len().saturating_sub(1), // Display count matches items vec
+                    ActiveTab::Relics => self.engine.state.relics.owned.len().saturating_sub(1),
+                    ActiveTab::Inventory => self.inventory_view().len().saturating_sub(1),
+                    ActiveTab::Crafting => all_recipes().len().saturating_sub(1),
+                    ActiveTab::Collection => all_item_sets().len().saturating_sub(1),
+                    ActiveTab::Achievements => all_achievements().len().saturating_sub(1),
+                    ActiveTab::Bounties => self.engine.state.bounties.bounties.len().saturating_sub(1),
                     ActiveTab::Rebirth => all_rebirth_skills().len().saturating_sub(1),
+                    ActiveTab::Challenges => all_challenges().len().saturating_sub(1),
+                    ActiveTab::Transcendence => all_transcendence_skills().len().saturating_sub(1),
                     ActiveTab::Stats => 100, // stats just scrolls freely
+                    ActiveTab::Automation => self.engine.state.automation.rules.len().saturating_sub(1),
                 };
                 if self.tab_scroll < max {
                     self.tab_scroll += 1;
@@ -403,35 +537,121 @@ impl App {
                     self.try_learn_rebirth_skill();
                 } else if self.active_tab == ActiveTab::Relics {
                     self.toggle_relic();
+                } else if self.active_tab == ActiveTab::Crafting {
+                    self.try_craft();
+                } else if self.active_tab == ActiveTab::Achievements {
+                    self.toggle_title();
+                } else if self.active_tab == ActiveTab::Bounties {
+                    self.dispatch(Command::ClaimBounty(self.tab_scroll));
+                } else if self.active_tab == ActiveTab::Challenges {
+                    self.try_start_challenge();
+                } else if self.active_tab == ActiveTab::Transcendence {
+                    self.try_learn_transcendence_skill();
+                } else if self.active_tab == ActiveTab::Automation {
+                    self.edit_selected_rule(|rule| rule.enabled = !rule.enabled);
                 }
             }
 
+            // Automation rules
+            KeyCode::Char(_) if self.active_tab == ActiveTab::Automation => {
+                self.handle_automation_input(key);
+            }
+
             // Unequip all relics
-            KeyCode::Char('u') | KeyCode::Char('U') => {
-                if self.active_tab == ActiveTab::Relics {
-                    self.unequip_all_relics();
+            KeyCode::Char('u') | KeyCode::Char('U') if self.active_tab == ActiveTab::Relics => {
+                self.unequip_all_relics();
+            }
+
+            // Level up a relic with shards
+            KeyCode::Char('l') | KeyCode::Char('L') if self.active_tab == ActiveTab::Relics => {
+                self.upgrade_relic();
+            }
+
+            // Mark relics for fusion
+            KeyCode::Char('f') | KeyCode::Char('F') if self.active_tab == ActiveTab::Relics => {
+                self.pick_fusion_relic();
+            }
+
+            // Save the equipped relics as a loadout
+            KeyCode::Char('s') | KeyCode::Char('S') if self.active_tab == ActiveTab::Relics => {
+                self.loadout_pick = !self.loadout_pick;
+                if self.loadout_pick {
+                    self.add_message(format!(
+                        "Save loadout: pick a slot [1-{}]",
+                        RelicState::MAX_LOADOUTS
+                    ));
                 }
             }
 
-            // Rebirth
-            KeyCode::Char('r') | KeyCode::Char('R') => {
-                if self.active_tab == ActiveTab::Rebirth {
-                    self.try_rebirth();
+            // Relic loadout slots
+            KeyCode::Char(c @ '1'..='9') => {
+                let slot = c as usize - '1' as usize;
+                if slot < RelicState::MAX_LOADOUTS {
+                    self.use_loadout_slot(slot);
                 }
             }
 
-            // Sell item (Alchemy)
-            KeyCode::Char('s') | KeyCode::Char('S') => {
-                if self.active_tab == ActiveTab::Inventory {
-                    self.try_sell_item();
+            // Respec the selected skill or the whole tree
+            KeyCode::Char('x') | KeyCode::Char('X') if self.active_tab == ActiveTab::Skills => {
+                if let Some(skill) = all_skills().get(self.tab_scroll) {
+                    self.try_respec(RespecTarget::Skill(skill.id.to_string()));
                 }
             }
+            KeyCode::Char('z') | KeyCode::Char('Z') if self.active_tab == ActiveTab::Skills => {
+                self.try_respec(RespecTarget::WholeTree);
+            }
+
+            // Rebirth
+            KeyCode::Char('r') | KeyCode::Char('R') if self.active_tab == ActiveTab::Rebirth => {
+                self.try_rebirth();
+            }
+
+            // Transcend
+            KeyCode::Char('t') | KeyCode::Char('T') if self.active_tab == ActiveTab::Transcendence => {
+                self.try_transcend();
+            }
+
+            // Abandon the running challenge
+            KeyCode::Char('a') | KeyCode::Char('A') if self.active_tab == ActiveTab::Challenges => {
+                self.try_abandon_challenge();
+            }
+
+            // Sell item (Alchemy)
+            KeyCode::Char('s') | KeyCode::Char('S') if self.active_tab == ActiveTab::Inventory => {
+                self.try_sell_item();
+            }
 
             // Sell all items (Alchemy)
-            KeyCode::Char('a') | KeyCode::Char('A') => {
-                if self.active_tab == ActiveTab::Inventory {
-                    self.try_sell_all_items();
-                }
+            KeyCode::Char('a') | KeyCode::Char('A') if self.active_tab == ActiveTab::Inventory => {
+                self.try_sell_all_items();
+            }
+
+            // Loot filter (Alchemy)
+            KeyCode::Char('f') | KeyCode::Char('F') if self.active_tab == ActiveTab::Inventory => {
+                self.filter_open = true;
+                self.play_ui(|s| s.play_menu_open());
+            }
+            KeyCode::Char('i') | KeyCode::Char('I') if self.active_tab == ActiveTab::Inventory => {
+                self.cycle_item_filter();
+            }
+
+            // Inventory sort, rarity filter and search
+            KeyCode::Char('o') | KeyCode::Char('O') if self.active_tab == ActiveTab::Inventory => {
+                self.inventory_query.sort = self.inventory_query.sort.next();
+                self.tab_scroll = 0;
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') if self.active_tab == ActiveTab::Inventory => {
+                // All -> Common -> ... -> Mythic -> All
+                let next = match self.inventory_query.rarity {
+                    None => 0,
+                    Some(r) => Rarity::ALL.iter().position(|&x| x == r).unwrap_or(0) + 1,
+                };
+                self.inventory_query.rarity = Rarity::ALL.get(next).copied();
+                self.tab_scroll = 0;
+            }
+            KeyCode::Char('/') if self.active_tab == ActiveTab::Inventory => {
+                self.search_typing = true;
+                self.tab_scroll = 0;
             }
 
             _ => {}
@@ -441,8 +661,6 @@ impl App {
     }
 
     fn handle_chest_menu_input(&mut self, key: KeyEvent) -> bool {
-        use crate::game::chest::ChestType;
-
         match key.code {
             // Close chest menu with Space, C, or Esc
             KeyCode::Char(' ') | KeyCode::Char('c') | KeyCode::Char('C') | KeyCode::Esc => {
@@ -456,35 +674,22 @@ impl App {
             KeyCode::Down => {
                 self.chest_menu_selected = (self.chest_menu_selected + 1).min(ChestType::ALL.len() - 1);
             }
+            // Page through the tiers, keeping the same row on the new page
+            KeyCode::Left | KeyCode::PageUp => {
+                self.chest_menu_selected = self.chest_menu_selected.saturating_sub(CHEST_MENU_PAGE);
+            }
+            KeyCode::Right | KeyCode::PageDown => {
+                self.chest_menu_selected = (self.chest_menu_selected + CHEST_MENU_PAGE).min(ChestType::ALL.len() - 1);
+            }
             // Select chest with E or Enter
             KeyCode::Char('e') | KeyCode::Char('E') | KeyCode::Enter => {
-                let ct = ChestType::ALL[self.chest_menu_selected];
-                if self.state.unlocked_chests.contains(&ct) {
-                    self.play_ui(|s| s.play_click());
-                    self.state.current_chest_type = ct;
-                    self.show_chest_menu = false;
-                    self.start_opening();
-                    self.add_message(format!("Opening {} chest...", ct.name()));
-                } else {
-                    self.play_ui(|s| s.play_error());
-                    self.add_message(format!("{} chest is locked!", ct.name()));
-                }
+                self.select_chest(ChestType::ALL[self.chest_menu_selected]);
             }
-            // Select chest with number keys 1-7
-            KeyCode::Char(c @ '1'..='7') => {
-                let idx = (c as usize) - ('1' as usize);
-                if idx < ChestType::ALL.len() {
-                    let ct = ChestType::ALL[idx];
-                    if self.state.unlocked_chests.contains(&ct) {
-                        self.play_ui(|s| s.play_click());
-                        self.state.current_chest_type = ct;
-                        self.show_chest_menu = false;
-                        self.start_opening();
-                        self.add_message(format!("Opening {} chest...", ct.name()));
-                    } else {
-                        self.play_ui(|s| s.play_error());
-                        self.add_message(format!("{} chest is locked!", ct.name()));
-                    }
+            // Select chest on the current page with number keys 1-9
+            KeyCode::Char(c @ '1'..='9') => {
+                let idx = self.chest_menu_page_range().start + (c as usize) - ('1' as usize);
+                if self.chest_menu_page_range().contains(&idx) {
+                    self.select_chest(ChestType::ALL[idx]);
                 }
             }
             _ => {}
@@ -492,665 +697,412 @@ impl App {
         false
     }
 
-    fn start_opening(&mut self) {
-        if self.state.chest_progress.state != ChestState::Idle {
-            return;
-        }
-
-        // Use player speed for all opening (manual and auto)
-        let mut speed = self.state.player.speed;
+    /// Indices into `ChestType::ALL` shown on the chest menu page that holds
+    /// the selection.
+    pub fn chest_menu_page_range(&self) -> std::ops::Range<usize> {
+        let start = self.chest_menu_selected / CHEST_MENU_PAGE * CHEST_MENU_PAGE;
+        start..(start + CHEST_MENU_PAGE).min(ChestType::ALL.len())
+    }
 
-        // Momentum skill: each consecutive chest opens 3% faster (max 60%)
-        if self.state.skill_tree.has_skill("momentum") {
-            let bonus = (self.consecutive_chests as f64 * 0.03).min(0.60);
-            speed *= 1.0 + bonus;
+    fn select_chest(&mut self, ct: ChestType) {
+        let unlocked = self.engine.state.unlocked_chests.contains(&ct);
+        if unlocked {
+            self.play_ui(|s| s.play_click());
+            self.show_chest_menu = false;
         }
-
-        // Chaos buff: speed
-        if self.chaos_buff_type == Some(2) && self.chaos_buff_ticks > 0 {
-            speed *= 1.5;
+        self.dispatch(Command::SelectChest(ct));
+        if unlocked {
+            self.add_message(format!("Opening {} chest...", ct.name()));
         }
+    }
 
-        self.play_chest(|s| s.play_chest_start());
-        self.state
-            .chest_progress
-            .start_opening(self.state.current_chest_type, speed);
+    /// Run a command through the engine and present whatever it reports.
+    fn dispatch(&mut self, command: Command) {
+        let events = self.engine.apply(command);
+        self.handle_events(events);
+    }
 
-        // Skill: Burst Open - 15% chance to instantly open
-        if self.state.skill_tree.has_skill("burst_open") && self.rng.random::<f64>() < 0.15 {
-            self.state.chest_progress.ticks_elapsed = self.state.chest_progress.ticks_required;
+    fn handle_events(&mut self, events: Vec<GameEvent>) {
+        for event in events {
+            self.handle_event(event);
         }
     }
 
-    fn roll_loot(&mut self) {
-        let table = loot_table_for(self.state.current_chest_type);
-
-        // Upgrade: Void Sight - +15% rare+ item chance per level (increases luck for weighting)
-        let void_sight_level = self.state.upgrades.get_level("void_sight");
-        let effective_luck = if void_sight_level > 0 {
-            self.state.player.luck + (void_sight_level as f64 * 15.0)
-        } else {
-            self.state.player.luck
-        };
-
-        let weighted = table.weighted_entries(effective_luck);
-        let total_weight: f64 = weighted.iter().map(|(_, w)| w).sum();
-        let mut roll: f64 = self.rng.random::<f64>() * total_weight;
-
-        let mut chosen_idx = 0;
-        for (idx, weight) in &weighted {
-            roll -= weight;
-            if roll <= 0.0 {
-                chosen_idx = *idx;
-                break;
+    /// Translate a domain event into messages, sounds and animations.
+    fn handle_event(&mut self, event: GameEvent) {
+        match event {
+            GameEvent::ChestStarted(_) => self.play_chest(|s| s.play_chest_start()),
+            GameEvent::ChestCollected => self.play_chest(|s| s.play_collect()),
+            GameEvent::ItemFound { item, source, .. } => match source {
+                LootSource::Chest => self.show_item_found(&item),
+                LootSource::MultiDrop => {
+                    self.add_message(format!("Multi-Drop: bonus {}!", item.name));
+                }
+                LootSource::Scavenger => {
+                    self.add_message(format!("Scavenger: found {}!", item.name));
+                }
+                LootSource::ChestRadar => {}
+            },
+            GameEvent::Proc(proc) => self.show_proc(proc),
+            GameEvent::LevelUp { level } => {
+                self.play_chest(|s| s.play_level_up());
+                self.add_message(format!("LEVEL UP! Level {} (+1 Skill Point)", level));
+            }
+            GameEvent::ChestUnlocked(ct) => {
+                self.add_message(format!("{} chests unlocked!", ct.name()));
+            }
+            GameEvent::RelicFound { id } => {
+                if let Some(relic) = relics::get_relic(&id) {
+                    self.add_message(format!("RELIC FOUND: {}!", relic.name));
+                    self.float_texts.push(FloatText {
+                        text: format!("NEW RELIC: {}", relic.name),
+                        color: relic.rarity.color(),
+                        ticks_remaining: 90,
+                        total_ticks: 90,
+                        x_offset: 0,
+                        dir: FloatDir::Up,
+                    });
+                }
             }
-        }
-
-        let entry = &table.entries[chosen_idx];
-        let Some(item_def) = get_item(entry.item_id) else {
-            return;
-        };
-
-        let mut item_rarity = item_def.rarity;
-
-        // Determine if pandemonium doubles chaos chances
-        let chaos_mult = if self.state.skill_tree.has_skill("pandemonium") {
-            2.0
-        } else {
-            1.0
-        };
-
-        // Skill: Wild Magic - 5% (or 10% with pandemonium) chance to upgrade rarity
-        if self.state.skill_tree.has_skill("wild_magic") {
-            let chance = 0.05 * chaos_mult;
-            if self.rng.random::<f64>() < chance {
-                item_rarity = match item_rarity {
-                    Rarity::Common => Rarity::Uncommon,
-                    Rarity::Uncommon => Rarity::Rare,
-                    Rarity::Rare => Rarity::Epic,
-                    Rarity::Epic => Rarity::Legendary,
-                    Rarity::Legendary => Rarity::Mythic,
-                    Rarity::Mythic => Rarity::Mythic,
+            GameEvent::SetCompleted { id } => {
+                if let Some(set) = get_item_set(&id) {
+                    self.play_chest(|s| s.play_level_up());
+                    self.add_message(format!("SET COMPLETE: {}! ({})", set.name, set.description));
+                    self.float_texts.push(FloatText {
+                        text: format!("SET COMPLETE: {}", set.name),
+                        color: Color::Yellow,
+                        ticks_remaining: 90,
+                        total_ticks: 90,
+                        x_offset: 0,
+                        dir: FloatDir::Up,
+                    });
+                }
+            }
+            GameEvent::AchievementUnlocked { id } => {
+                let Some(achievement) = get_achievement(&id) else {
+                    return;
                 };
+                self.play_chest(|s| s.play_level_up());
+                if self.setting_show_animations {
+                    self.flashes.spawn(Color::Rgb(255, 215, 0), 12);
+                }
+                self.add_message(format!(
+                    "ACHIEVEMENT: {}! ({})",
+                    achievement.name, achievement.description
+                ));
+                self.float_texts.push(FloatText {
+                    text: format!("ACHIEVEMENT: {}", achievement.name),
+                    color: Color::Rgb(255, 215, 0),
+                    ticks_remaining: 120,
+                    total_ticks: 120,
+                    x_offset: 0,
+                    dir: FloatDir::Up,
+                });
+            }
+            GameEvent::BountyCompleted { index } => {
+                if let Some(bounty) = self.engine.state.bounties.bounties.get(index) {
+                    let objective = bounty.objective.describe();
+                    self.play_ui(|s| s.play_purchase());
+                    self.add_message(format!(
+                        "Bounty done: {}! Claim it on the Bounties tab",
+                        objective
+                    ));
+                }
             }
-        }
-
-        // Upgrade: Horseshoe - +1% rarity upgrade chance per level
-        let horseshoe_level = self.state.upgrades.get_level("horseshoe");
-        if horseshoe_level > 0 {
-            let chance = horseshoe_level as f64 * 0.01;
-            if self.rng.random::<f64>() < chance && item_rarity != Rarity::Mythic {
-                item_rarity = match item_rarity {
-                    Rarity::Common => Rarity::Uncommon,
-                    Rarity::Uncommon => Rarity::Rare,
-                    Rarity::Rare => Rarity::Epic,
-                    Rarity::Epic => Rarity::Legendary,
-                    Rarity::Legendary => Rarity::Mythic,
-                    Rarity::Mythic => Rarity::Mythic,
+            GameEvent::BountyClaimed { reward } => {
+                self.add_message(format!("Bounty claimed: {}", reward.describe()));
+                self.float_texts.push(FloatText {
+                    text: format!("BOUNTY: {}", reward.describe()),
+                    color: Color::Rgb(255, 165, 0),
+                    ticks_remaining: 60,
+                    total_ticks: 60,
+                    x_offset: 0,
+                    dir: FloatDir::Up,
+                });
+            }
+            GameEvent::BountiesRefreshed => {
+                self.add_message("New bounties posted!".to_string());
+            }
+            GameEvent::ChallengeStarted { id } => {
+                if let Some(challenge) = get_challenge(&id) {
+                    self.add_message(format!(
+                        "CHALLENGE: {}! {} within {} minutes",
+                        challenge.name,
+                        challenge.goal.describe(),
+                        challenge.time_limit_secs / 60
+                    ));
+                }
+            }
+            GameEvent::ChallengeCompleted { id, secs } => {
+                let Some(challenge) = get_challenge(&id) else {
+                    return;
                 };
+                self.play_chest(|s| s.play_level_up());
+                if self.setting_show_animations {
+                    self.flashes.spawn(Color::Rgb(255, 215, 0), 12);
+                }
+                self.add_message(format!(
+                    "Challenge complete: {} in {}:{:02}! ({})",
+                    challenge.name,
+                    secs / 60,
+                    secs % 60,
+                    challenge.reward
+                ));
+                self.float_texts.push(FloatText {
+                    text: format!("CHALLENGE: {}", challenge.name),
+                    color: Color::Rgb(255, 215, 0),
+                    ticks_remaining: 120,
+                    total_ticks: 120,
+                    x_offset: 0,
+                    dir: FloatDir::Up,
+                });
+            }
+            GameEvent::ChallengeFailed { id } => {
+                let name = get_challenge(&id).map_or(id.as_str(), |c| c.name);
+                self.add_message(format!("Challenge over: {} failed", name));
+            }
+            GameEvent::TitleChanged { title } => match title {
+                Some(title) => self.add_message(format!("Now known as {}", title)),
+                None => self.add_message("Title hidden".to_string()),
+            },
+            GameEvent::Crafted { recipe, item } => {
+                let name = get_recipe(&recipe).map_or(recipe.as_str(), |r| r.name);
+                match item {
+                    Some(item) => {
+                        self.play_ui(|s| s.play_purchase());
+                        self.add_message(format!("{}: crafted {}!", name, item.name));
+                        self.float_texts.push(FloatText {
+                            text: format!("CRAFTED: {}", item.name),
+                            color: item.rarity.color(),
+                            ticks_remaining: 60,
+                            total_ticks: 60,
+                            x_offset: 0,
+                            dir: FloatDir::Up,
+                        });
+                    }
+                    None => self.add_message(format!("{} failed. The items are gone.", name)),
+                }
             }
-        }
-
-        // Skill: Gambler's Spirit - after 3 empty/common streaks, force Rare+
-        if self.state.skill_tree.has_skill("gambler_spirit") {
-            if matches!(item_rarity, Rarity::Common) {
-                self.empty_streak += 1;
-            } else {
-                self.empty_streak = 0;
+            GameEvent::RelicDuplicate { id } => {
+                if let Some(relic) = relics::get_relic(&id) {
+                    self.add_message(format!(
+                        "Duplicate {} became a {} shard",
+                        relic.name,
+                        relic.rarity.label()
+                    ));
+                }
             }
-            let threshold = if self.state.skill_tree.has_skill("pandemonium") { 2 } else { 3 };
-            if self.empty_streak >= threshold {
-                if matches!(item_rarity, Rarity::Common | Rarity::Uncommon) {
-                    item_rarity = Rarity::Rare;
+            GameEvent::RelicUpgraded { id, level } => {
+                self.play_ui(|s| s.play_purchase());
+                let name = relics::get_relic(&id).map_or(id.as_str(), |r| r.name);
+                self.add_message(format!("{} reached level {}!", name, level));
+            }
+            GameEvent::RelicsFused { inputs, id, duplicate } => {
+                let Some(relic) = relics::get_relic(&id) else {
+                    return;
+                };
+                self.play_chest(|s| s.play_level_up());
+                if duplicate {
+                    self.add_message(format!(
+                        "Fused {} relics into {} (already owned: +1 {} shard)",
+                        inputs.len(),
+                        relic.name,
+                        relic.rarity.label()
+                    ));
+                } else {
+                    self.add_message(format!("Fused {} relics into {}!", inputs.len(), relic.name));
                 }
-                self.empty_streak = 0;
+                self.float_texts.push(FloatText {
+                    text: format!("FUSED: {}", relic.name),
+                    color: relic.rarity.color(),
+                    ticks_remaining: 90,
+                    total_ticks: 90,
+                    x_offset: 0,
+                    dir: FloatDir::Up,
+                });
+                self.tab_scroll = 0;
             }
-        }
-
-        // Track lucky_streak (consecutive rare+ finds)
-        if matches!(item_rarity, Rarity::Rare | Rarity::Epic | Rarity::Legendary | Rarity::Mythic) {
-            self.rare_streak_count += 1;
-        } else {
-            self.rare_streak_count = 0;
-        }
-
-        // XP Surge counter
-        self.chests_since_xp_surge += 1;
-        // Momentum counter
-        self.consecutive_chests += 1;
-
-        // Crit calculation
-        let is_crit = self.rng.random::<f64>() < self.state.player.crit_chance;
-
-        // Base crit multiplier from upgrades/skills
-        let crit_power_bonus = self.state.upgrades.get_level("crit_power") as f64 * 0.2;
-        let executioner_bonus = self.state.upgrades.get_level("executioners_edge") as f64 * 0.3;
-
-        // Skill: Overcharge - crit multiplier 3.5x instead of 2.5x
-        let base_crit_mult = if self.state.skill_tree.has_skill("overcharge") {
-            3.5
-        } else {
-            2.5
-        } + crit_power_bonus + executioner_bonus;
-
-        // Skill: Grand Mastery capstone - crit mult +3x
-        let base_crit_mult = if self.state.skill_tree.has_skill("grand_mastery") {
-            base_crit_mult + 3.0
-        } else {
-            base_crit_mult
-        };
-
-        // Relic crit mult bonus
-        let relic_totals = relic_stat_totals(&self.state.relics.equipped);
-        let base_crit_mult = base_crit_mult + relic_totals.crit_mult;
-
-        let mut crit_mult = if is_crit {
-            // Skill: Chaos Crit - random 1x-5x instead of fixed
-            if self.state.skill_tree.has_skill("chaos_crit") {
-                let random_mult = 1.0 + self.rng.random::<f64>() * 4.0;
-                random_mult + crit_power_bonus + executioner_bonus + relic_totals.crit_mult
-            } else {
-                base_crit_mult
+            GameEvent::UpgradeBought { id, level } => {
+                self.play_ui(|s| s.play_purchase());
+                let name = get_upgrade(&id).map_or(id.as_str(), |u| u.name);
+                self.add_message(format!("Upgraded {} to level {}", name, level));
+            }
+            GameEvent::SkillLearned { id } => {
+                self.play_ui(|s| s.play_purchase());
+                let name = get_skill(&id).map_or(id.as_str(), |s| s.name);
+                self.add_message(format!("Learned: {}!", name));
+            }
+            GameEvent::SkillsRespecced { ids, points, cost } => {
+                self.play_ui(|s| s.play_sell());
+                let what = match ids.as_slice() {
+                    [id] => get_skill(id).map_or(id.as_str(), |s| s.name).to_string(),
+                    _ => format!("{} skills", ids.len()),
+                };
+                self.add_message(format!("Unlearned {} for {} GP (+{} points)", what, cost, points));
             }
-        } else {
-            1.0
-        };
-
-        // Skill: Crit Cascade - 30% chance to trigger another crit (compound)
-        if is_crit && self.state.skill_tree.has_skill("crit_cascade") {
-            while self.rng.random::<f64>() < 0.30 {
-                crit_mult *= base_crit_mult;
+            GameEvent::Transcended { count, aether } => {
+                self.tab_scroll = 0;
+                self.play_ui(|s| s.play_rebirth());
+                if self.setting_show_animations {
+                    self.flashes.spawn(Color::Rgb(120, 220, 255), 30);
+                }
+                self.add_message(format!(
+                    "TRANSCENDENCE #{} complete! +{} Aether",
+                    count, aether
+                ));
+                self.save_game();
+            }
+            GameEvent::TranscendenceSkillLearned { id } => {
+                self.play_ui(|s| s.play_purchase());
+                let name = get_transcendence_skill(&id).map_or(id.as_str(), |s| s.name);
+                self.add_message(format!("Learned transcendence skill: {}!", name));
+            }
+            GameEvent::RebirthSkillLearned { id } => {
+                self.play_ui(|s| s.play_purchase());
+                let name = get_rebirth_skill(&id).map_or(id.as_str(), |s| s.name);
+                self.add_message(format!("Learned rebirth skill: {}!", name));
+            }
+            GameEvent::RelicEquipped { .. } | GameEvent::RelicUnequipped { .. } => {}
+            GameEvent::RelicsUnequipped { count } => {
+                self.add_message(format!("Unequipped {} relics", count));
+            }
+            GameEvent::RelicLoadoutSaved { slot, name } => {
+                self.play_ui(|s| s.play_purchase());
+                self.add_message(format!("Saved loadout {}: {}", slot + 1, name));
+            }
+            GameEvent::RelicLoadoutEquipped { name, missing, no_room, .. } => {
+                self.play_ui(|s| s.play_click());
+                let names = |ids: &[String]| {
+                    ids.iter()
+                        .map(|id| relics::get_relic(id).map_or(id.as_str(), |r| r.name))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                let mut message = format!("Equipped loadout: {}", name);
+                if !missing.is_empty() {
+                    message.push_str(&format!(" (missing {})", names(&missing)));
+                }
+                if !no_room.is_empty() {
+                    message.push_str(&format!(" (no room for {})", names(&no_room)));
+                }
+                self.add_message(message);
+            }
+            GameEvent::Rebirth { count, essence, .. } => {
+                self.tab_scroll = 0;
+                self.play_ui(|s| s.play_rebirth());
+                if self.setting_show_animations {
+                    self.flashes.spawn(Color::Rgb(150, 100, 255), 20);
+                }
+                self.add_message(format!("REBIRTH #{} complete! +{} Essence", count, essence));
+                // Save immediately
+                self.save_game();
+            }
+            GameEvent::ItemSold { name, gp, remaining } => {
+                self.play_ui(|s| s.play_sell());
+                if remaining > 0 {
+                    self.add_message(format!("Sold 1× {} for {} GP ({} left)", name, gp, remaining));
+                } else {
+                    self.add_message(format!("Sold {} for {} GP", name, gp));
+                }
+            }
+            GameEvent::ItemsSold { count, gp } => {
+                self.play_ui(|s| s.play_sell());
+                self.add_message(format!("Sold {} items for {} GP", count, gp));
+                self.tab_scroll = 0;
+            }
+            GameEvent::DropFiltered { name, action, value } => match action {
+                FilterAction::Keep => {}
+                FilterAction::Sell => self.add_message(format!("Auto-sold {} (+{} GP)", name, value)),
+                FilterAction::Salvage => self.add_message(format!("Salvaged {} (+{} XP)", name, value)),
+            },
+            GameEvent::LootFilterToggled { enabled } => {
+                self.play_ui(|s| s.play_click());
+                let state = if enabled { "on" } else { "off" };
+                self.add_message(format!("Loot filter {}", state));
+            }
+            GameEvent::AutomationToggled { enabled } => {
+                self.play_ui(|s| s.play_click());
+                let state = if enabled { "on" } else { "paused" };
+                self.add_message(format!("Upgrade automation {}", state));
+            }
+            GameEvent::AutoBought { id, level } => {
+                let name = get_upgrade(&id).map_or(id.as_str(), |u| u.name);
+                self.add_message(format!("Auto-bought {} (Lv.{})", name, level));
+            }
+            GameEvent::Rejected(reason) => {
+                self.play_ui(|s| s.play_error());
+                self.add_message(reason.to_string());
             }
         }
+    }
 
-        if is_crit {
-            self.state.stats.crits_rolled += 1;
-        }
-
-        // Skill: Midas Touch - +100% base GP on items
-        let gp_base = if self.state.skill_tree.has_skill("midas_touch") {
-            (item_def.base_gp as f64 * 2.0) as u64
-        } else {
-            item_def.base_gp
-        };
-
-        // Skill: Material Insight - +20% GP from Uncommon+ items
-        let material_bonus = if self.state.skill_tree.has_skill("material_insight")
-            && !matches!(item_rarity, Rarity::Common)
-        {
-            1.2
+    /// Float texts, reveal sounds and fireworks for the main chest drop.
+    fn show_item_found(&mut self, item: &ItemInstance) {
+        // Float texts: item name floats up, GP flies left, XP flies right
+        // Item name — floats up
+        self.float_texts.push(FloatText {
+            text: item.name.clone(),
+            color: item.rarity.color(),
+            ticks_remaining: 55,
+            total_ticks: 55,
+            x_offset: 0,
+            dir: FloatDir::Up,
+        });
+        // GP value — flies to the left
+        let gp_text = if item.is_crit {
+            format!("+{} GP CRIT!", item.gp_value)
         } else {
-            1.0
+            format!("+{} GP", item.gp_value)
         };
+        self.float_texts.push(FloatText {
+            text: gp_text,
+            color: Color::Yellow,
+            ticks_remaining: 50,
+            total_ticks: 50,
+            x_offset: 0,
+            dir: FloatDir::Left,
+        });
+        // XP value — flies to the right
+        self.float_texts.push(FloatText {
+            text: format!("+{} XP", item.xp_value),
+            color: Color::Cyan,
+            ticks_remaining: 45,
+            total_ticks: 45,
+            x_offset: 0,
+            dir: FloatDir::Right,
+        });
 
-        let chest_reward = self.state.current_chest_type.reward_multiplier();
-
-        let mut gp_value = (gp_base as f64
-            * item_rarity.gp_multiplier()
-            * self.state.player.gp_multiplier
-            * crit_mult
-            * material_bonus
-            * chest_reward) as u64;
-
-        // Skill: Fortune Favors - +25% GP
-        if self.state.skill_tree.has_skill("fortune_favors") {
-            gp_value = (gp_value as f64 * 1.25) as u64;
-        }
-
-        // Skill: Lucky Streak - consecutive rare+ finds give +20% GP each
-        if self.state.skill_tree.has_skill("lucky_streak") && self.rare_streak_count > 1 {
-            let bonus = 1.0 + (self.rare_streak_count - 1) as f64 * 0.20;
-            gp_value = (gp_value as f64 * bonus) as u64;
-        }
-
-        // Skill: Golden Rain capstone - +80% GP (windfall doubling handled in windfall section)
-        if self.state.skill_tree.has_skill("golden_rain") {
-            gp_value = (gp_value as f64 * 1.80) as u64;
+        // Sound effects for reveal
+        let rarity = item.rarity;
+        self.play_chest(|s| s.play_reveal(rarity));
+        if item.is_crit {
+            self.play_chest(|s| s.play_crit());
         }
 
-        // Skill: Grand Mastery capstone - +40% all multipliers
-        if self.state.skill_tree.has_skill("grand_mastery") {
-            gp_value = (gp_value as f64 * 1.40) as u64;
-        }
+        // Fireworks scaled by rarity — centered near the chest art
+        self.spawn_rarity_fireworks(rarity);
+    }
 
-        // Upgrade: Combo Counter - +5% GP per consecutive crit per level
-        let combo_counter_level = self.state.upgrades.get_level("combo_counter");
-        if combo_counter_level > 0 && is_crit {
-            self.consecutive_crits += 1;
-            if self.consecutive_crits > 1 {
-                let bonus = 1.0 + (self.consecutive_crits - 1) as f64 * (combo_counter_level as f64 * 0.05);
-                gp_value = (gp_value as f64 * bonus) as u64;
-            }
-        } else if !is_crit {
-            self.consecutive_crits = 0;
+    fn show_proc(&mut self, proc: Proc) {
+        let (msg, flash) = match proc {
+            Proc::Windfall => ("WINDFALL! 10x GP!", Some((Color::Yellow, 12))),
+            Proc::GoldRush => ("Gold Rush! Double GP!", None),
+            Proc::RealityTear => ("REALITY TEAR! 20x GP!", Some((Color::Rgb(200, 50, 50), 15))),
+            Proc::Singularity => ("SINGULARITY! Triple loot!", Some((Color::Magenta, 12))),
+            Proc::ChestRadar => ("Chest Radar: Bonus drop!", None),
+            Proc::ElixirOfFortune => ("Elixir of Fortune: double sell!", None),
+        };
+        self.add_message(msg.to_string());
+        if let Some((color, ticks)) = flash
+            && self.setting_show_animations
+        {
+            self.flashes.spawn(color, ticks);
         }
+    }
 
-        // Upgrade: Legendary Focus - +10% legendary GP per level
-        if item_rarity == Rarity::Legendary {
-            let legendary_focus_lvl = self.state.upgrades.get_level("legendary_focus") as f64;
-            if legendary_focus_lvl > 0.0 {
-                gp_value = (gp_value as f64 * (1.0 + legendary_focus_lvl * 0.10)) as u64;
-            }
-        }
-
-        // Skill: Legendary Aura - legendary items give 3x XP
-        let xp_rarity_mult = if item_rarity == Rarity::Legendary
-            && self.state.skill_tree.has_skill("legendary_aura")
-        {
-            item_rarity.xp_multiplier() * 3.0
-        } else {
-            item_rarity.xp_multiplier()
-        };
-
-        let mut xp_value = (item_def.base_xp as f64
-            * xp_rarity_mult
-            * self.state.player.xp_multiplier
-            * crit_mult
-            * chest_reward) as u64;
-
-        // Skill: Deep Knowledge - +100% XP
-        if self.state.skill_tree.has_skill("deep_knowledge") {
-            xp_value = (xp_value as f64 * 2.0) as u64;
-        }
-
-        // Skill: Precision Strike - crits give +50% XP
-        if is_crit && self.state.skill_tree.has_skill("precision_strike") {
-            xp_value = (xp_value as f64 * 1.50) as u64;
-        }
-
-        // Skill: Grand Mastery capstone - +40% all multipliers (XP too)
-        if self.state.skill_tree.has_skill("grand_mastery") {
-            xp_value = (xp_value as f64 * 1.40) as u64;
-        }
-
-        // Skill: XP Surge - every 5th chest gives 5x XP
-        if self.state.skill_tree.has_skill("xp_surge") && self.chests_since_xp_surge >= 5 {
-            self.chests_since_xp_surge = 0;
-            xp_value *= 5;
-        }
-
-        // Skill: Entropy - loot values vary ±30%
-        if self.state.skill_tree.has_skill("entropy") {
-            let variance = 0.7 + self.rng.random::<f64>() * 0.6; // 0.7 to 1.3
-            gp_value = (gp_value as f64 * variance) as u64;
-            xp_value = (xp_value as f64 * variance) as u64;
-        }
-
-        // Skill: Double or Nothing - 30% double, 5% nothing
-        if self.state.skill_tree.has_skill("double_or_nothing") {
-            let don_roll = self.rng.random::<f64>();
-            if don_roll < 0.30 {
-                gp_value *= 2;
-                xp_value *= 2;
-            } else if don_roll < 0.35 {
-                gp_value = 0;
-                xp_value = 0;
-            }
-        }
-
-        // Skill: Golden Touch - 25% chance to double final GP
-        if self.state.skill_tree.has_skill("golden_touch") && self.rng.random::<f64>() < 0.25 {
-            gp_value *= 2;
-        }
-
-        // Skill: Windfall - 3% chance for 10x GP (6% with Golden Rain)
-        if self.state.skill_tree.has_skill("windfall") {
-            let windfall_chance = if self.state.skill_tree.has_skill("golden_rain") {
-                0.06
-            } else {
-                0.03
-            };
-            if self.rng.random::<f64>() < windfall_chance {
-                gp_value *= 10;
-                self.add_message("WINDFALL! 10x GP!".to_string());
-                if self.setting_show_animations {
-                    self.flashes.spawn(Color::Yellow, 12);
-                }
-            }
-        }
-
-        // Skill: High Roller - +15% GP per chest tier above Wooden
-        if self.state.skill_tree.has_skill("high_roller") {
-            let tier = self.state.current_chest_type.index();
-            if tier > 0 {
-                let bonus = 1.0 + tier as f64 * 0.15;
-                gp_value = (gp_value as f64 * bonus) as u64;
-            }
-        }
-
-        // Skill: Gold Rush - 10% chance for bonus GP equal to item value
-        if self.state.skill_tree.has_skill("gold_rush") && self.rng.random::<f64>() < 0.10 {
-            gp_value *= 2;
-            self.add_message("Gold Rush! Double GP!".to_string());
-        }
-
-        // Skill: Reality Tear - 1% (2% with pandemonium) chance for 20x GP
-        if self.state.skill_tree.has_skill("reality_tear") {
-            let chance = 0.01 * chaos_mult;
-            if self.rng.random::<f64>() < chance {
-                gp_value *= 20;
-                self.add_message("REALITY TEAR! 20x GP!".to_string());
-                if self.setting_show_animations {
-                    self.flashes.spawn(Color::Rgb(200, 50, 50), 15);
-                }
-            }
-        }
-
-        // Skill: Singularity capstone - 3% (6% pandemonium) chance to triple all
-        if self.state.skill_tree.has_skill("singularity") {
-            let chance = 0.03 * chaos_mult;
-            if self.rng.random::<f64>() < chance {
-                gp_value *= 3;
-                xp_value *= 3;
-                self.add_message("SINGULARITY! Triple loot!".to_string());
-                if self.setting_show_animations {
-                    self.flashes.spawn(Color::Magenta, 12);
-                }
-            }
-        }
-
-        // Chaos Surge - random buff
-        if self.state.skill_tree.has_skill("chaos_surge") {
-            let buff_type = (self.rng.random::<f64>() * 3.0) as u8;
-            self.chaos_buff_type = Some(buff_type);
-            self.chaos_buff_ticks = 300; // 10 seconds at 30fps
-
-            match buff_type {
-                0 => gp_value = (gp_value as f64 * 1.5) as u64,
-                1 => xp_value = (xp_value as f64 * 1.5) as u64,
-                2 => {} // speed buff applied in start_opening
-                _ => {}
-            }
-        }
-
-        // Catalyst brew bonus
-        if self.state.skill_tree.has_skill("catalyst_brew") && self.catalyst_stacks > 0.0 {
-            gp_value = (gp_value as f64 * (1.0 + self.catalyst_stacks / 100.0)) as u64;
-        }
-
-        // Magnum Opus capstone - +50% GP
-        if self.state.skill_tree.has_skill("magnum_opus") {
-            gp_value = (gp_value as f64 * 1.5) as u64;
-        }
-
-        // Ensure at least 1 GP
-        gp_value = gp_value.max(1);
-
-        let instance = ItemInstance {
-            id: item_def.id.to_string(),
-            name: item_def.name.to_string(),
-            rarity: item_rarity,
-            gp_value,
-            xp_value,
-            is_crit,
-            count: 1,
-        };
-
-        // Update stats
-        self.state.stats.chests_opened += 1;
-        self.state.stats.items_found += 1;
-        self.state.stats.total_gp_earned += gp_value;
-        self.state.stats.total_xp_earned += xp_value;
-        self.state.rebirth.gp_earned_this_run += gp_value;
-        if gp_value > self.state.stats.highest_single_gp {
-            self.state.stats.highest_single_gp = gp_value;
-        }
-        match item_rarity {
-            Rarity::Rare => self.state.stats.rares_found += 1,
-            Rarity::Epic => self.state.stats.epics_found += 1,
-            Rarity::Legendary => self.state.stats.legendaries_found += 1,
-            Rarity::Mythic => self.state.stats.mythics_found += 1,
-            _ => {}
-        }
-
-        // Update highest level ever
-        if self.state.player.level > self.state.rebirth.highest_level_ever {
-            self.state.rebirth.highest_level_ever = self.state.player.level;
-        }
-
-        // Award GP and XP
-        self.state.player.gp += gp_value;
-        self.award_xp(xp_value);
-
-        // Check relic drop
-        self.try_relic_drop(item_rarity);
-
-        // Skill: Recycler - auto-sell Common items for GP instead of adding to inventory
-        let recycled = self.state.skill_tree.has_skill("recycler")
-            && item_rarity == Rarity::Common;
-
-        // Deep Salvage: recycled items give 3x GP
-        if recycled && self.state.skill_tree.has_skill("deep_salvage") {
-            let bonus = gp_value * 2; // already got gp_value, give 2x more
-            self.state.player.gp += bonus;
-            self.state.stats.total_gp_earned += bonus;
-            self.state.rebirth.gp_earned_this_run += bonus;
-        }
-
-        // Float texts: item name floats up, GP flies left, XP flies right
-        // Item name — floats up
-        self.float_texts.push(FloatText {
-            text: item_def.name.to_string(),
-            color: item_rarity.color(),
-            ticks_remaining: 55,
-            total_ticks: 55,
-            x_offset: 0,
-            dir: FloatDir::Up,
-        });
-        // GP value — flies to the left
-        let gp_text = if is_crit {
-            format!("+{} GP CRIT!", gp_value)
-        } else {
-            format!("+{} GP", gp_value)
-        };
-        self.float_texts.push(FloatText {
-            text: gp_text,
-            color: Color::Yellow,
-            ticks_remaining: 50,
-            total_ticks: 50,
-            x_offset: 0,
-            dir: FloatDir::Left,
-        });
-        // XP value — flies to the right
-        self.float_texts.push(FloatText {
-            text: format!("+{} XP", xp_value),
-            color: Color::Cyan,
-            ticks_remaining: 45,
-            total_ticks: 45,
-            x_offset: 0,
-            dir: FloatDir::Right,
-        });
-
-        // Sound effects for reveal
-        self.play_chest(|s| s.play_reveal(item_rarity));
-        if is_crit {
-            self.play_chest(|s| s.play_crit());
-        }
-
-        // Fireworks scaled by rarity — centered near the chest art
-        self.spawn_rarity_fireworks(item_rarity);
-
-        // Store in chest progress for display
-        self.state.chest_progress.last_item = Some(instance.clone());
-
-        // Add to inventory (unless recycled)
-        if !recycled {
-            self.state.inventory.add(instance);
-        }
-
-        // Relic: multi-drop bonus from relics
-        let relic_multi_drop = relic_totals.multi_drop;
-
-        // Upgrade: bonus_loot - +3% multi-drop per level
-        let bonus_loot_chance = self.state.upgrades.get_level("bonus_loot") as f64 * 0.03;
-
-        // Upgrade: lucky_find - +5% multi-drop per level
-        let lucky_find_chance = self.state.upgrades.get_level("lucky_find") as f64 * 0.05;
-
-        // Skill: Multi-Drop - 15% chance for a second item
-        let base_multi = if self.state.skill_tree.has_skill("multi_drop") { 0.15 } else { 0.0 };
-        let total_multi = base_multi + relic_multi_drop + bonus_loot_chance + lucky_find_chance;
-
-        // World Explorer capstone: +50% all drop rates
-        let total_multi = if self.state.skill_tree.has_skill("world_explorer") {
-            total_multi * 1.5
-        } else {
-            total_multi
-        };
-
-        if total_multi > 0.0 && self.rng.random::<f64>() < total_multi {
-            self.roll_bonus_item();
-        }
-
-        // Skill: Scavenger - 5% chance for a bonus Common item
-        let scav_chance = if self.state.skill_tree.has_skill("scavenger") { 0.05 } else { 0.0 };
-        let scav_chance = if self.state.skill_tree.has_skill("world_explorer") {
-            scav_chance * 1.5
-        } else {
-            scav_chance
-        };
-        if scav_chance > 0.0 && self.rng.random::<f64>() < scav_chance {
-            self.roll_scavenger_item();
-        }
-    }
-
-    fn roll_bonus_item(&mut self) {
-        // Roll a second item from the same loot table
-        let table = loot_table_for(self.state.current_chest_type);
-        let weighted = table.weighted_entries(self.state.player.luck);
-        let total_weight: f64 = weighted.iter().map(|(_, w)| w).sum();
-        let mut roll: f64 = self.rng.random::<f64>() * total_weight;
-
-        let mut chosen_idx = 0;
-        for (idx, weight) in &weighted {
-            roll -= weight;
-            if roll <= 0.0 {
-                chosen_idx = *idx;
-                break;
-            }
-        }
-
-        let entry = &table.entries[chosen_idx];
-        if let Some(item_def) = get_item(entry.item_id) {
-            let chest_reward = self.state.current_chest_type.reward_multiplier();
-            let gp_value = (item_def.base_gp as f64
-                * item_def.rarity.gp_multiplier()
-                * self.state.player.gp_multiplier
-                * chest_reward) as u64;
-            let xp_value = (item_def.base_xp as f64
-                * item_def.rarity.xp_multiplier()
-                * self.state.player.xp_multiplier
-                * chest_reward) as u64;
-
-            self.state.player.gp += gp_value;
-            self.state.stats.total_gp_earned += gp_value;
-            self.state.stats.items_found += 1;
-            self.state.rebirth.gp_earned_this_run += gp_value;
-            self.award_xp(xp_value);
-
-            let instance = ItemInstance {
-                id: item_def.id.to_string(),
-                name: item_def.name.to_string(),
-                rarity: item_def.rarity,
-                gp_value,
-                xp_value,
-                is_crit: false,
-                count: 1,
-            };
-
-            self.add_message(format!("Multi-Drop: bonus {}!", item_def.name));
-            self.state.inventory.add(instance);
-        }
-
-        // Upgrade: Chest Radar - +10% chance for bonus chest drops per level
-        let chest_radar_level = self.state.upgrades.get_level("chest_radar");
-        if chest_radar_level > 0 {
-            let chance = chest_radar_level as f64 * 0.10;
-            if self.rng.random::<f64>() < chance {
-                // Roll another loot item
-                self.add_message("Chest Radar: Bonus drop!".to_string());
-                // Recursively call roll_loot (but we need to be careful about infinite recursion)
-                // Instead, let's just give a bonus item from the same table
-                let table = loot_table_for(self.state.current_chest_type);
-                let weighted = table.weighted_entries(self.state.player.luck);
-                let total_weight: f64 = weighted.iter().map(|(_, w)| w).sum();
-                let mut roll: f64 = self.rng.random::<f64>() * total_weight;
-
-                let mut chosen_idx = 0;
-                for (idx, weight) in &weighted {
-                    roll -= weight;
-                    if roll <= 0.0 {
-                        chosen_idx = *idx;
-                        break;
-                    }
-                }
-
-                if let Some(item_def) = get_item(table.entries[chosen_idx].item_id) {
-                    let chest_reward = self.state.current_chest_type.reward_multiplier();
-                    let gp_value = (item_def.base_gp as f64 * chest_reward) as u64;
-                    let xp_value = (item_def.base_xp as f64 * chest_reward) as u64;
-                    let instance = ItemInstance {
-                        id: item_def.id.to_string(),
-                        name: item_def.name.to_string(),
-                        rarity: item_def.rarity,
-                        gp_value,
-                        xp_value,
-                        is_crit: false,
-                        count: 1,
-                    };
-                    self.state.inventory.add(instance);
-                }
-            }
-        }
-    }
-
-    fn roll_scavenger_item(&mut self) {
-        // Roll a random Common item from current chest's loot table
-        let table = loot_table_for(self.state.current_chest_type);
-        // Find first Common entry
-        for entry in &table.entries {
-            if let Some(item_def) = get_item(entry.item_id) {
-                if item_def.rarity == Rarity::Common {
-                    let chest_reward = self.state.current_chest_type.reward_multiplier();
-                    let gp_value = (item_def.base_gp as f64
-                        * item_def.rarity.gp_multiplier()
-                        * self.state.player.gp_multiplier
-                        * chest_reward) as u64;
-                    let xp_value = (item_def.base_xp as f64
-                        * item_def.rarity.xp_multiplier()
-                        * self.state.player.xp_multiplier
-                        * chest_reward) as u64;
-
-                    self.state.player.gp += gp_value;
-                    self.state.stats.total_gp_earned += gp_value;
-                    self.state.stats.items_found += 1;
-                    self.state.rebirth.gp_earned_this_run += gp_value;
-                    self.award_xp(xp_value);
-
-                    let instance = ItemInstance {
-                        id: item_def.id.to_string(),
-                        name: item_def.name.to_string(),
-                        rarity: item_def.rarity,
-                        gp_value,
-                        xp_value,
-                        is_crit: false,
-                        count: 1,
-                    };
-
-                    // If recycler is active, don't add common to inventory
-                    if !self.state.skill_tree.has_skill("recycler") {
-                        self.state.inventory.add(instance);
-                    }
-                    self.add_message(format!("Scavenger: found {}!", item_def.name));
-                    return;
-                }
-            }
-        }
-    }
-
-    fn spawn_rarity_fireworks(&mut self, rarity: Rarity) {
-        if !self.setting_show_animations {
-            return;
+    fn spawn_rarity_fireworks(&mut self, rarity: Rarity) {
+        if !self.setting_show_animations {
+            return;
         }
 
         let game_w = (self.screen_w as f64 * 0.45).max(20.0);
@@ -1207,253 +1159,235 @@ impl App {
                     self.flashes.spawn(Color::Rgb(255, 50, 50), 15);
                 }
             }
+            Rarity::Divine => {
+                self.fireworks.spawn_burst_wide(
+                    cx, cy, spread_x * 2.5, spread_y * 2.5,
+                    &[Color::Rgb(255, 245, 170), Color::White, Color::Rgb(255, 220, 100), Color::Rgb(150, 220, 255), Color::Rgb(255, 255, 230), Color::LightYellow],
+                    70, 120, 20,
+                );
+                if self.setting_show_animations {
+                    self.flashes.spawn(Color::Rgb(255, 245, 170), 20);
+                }
+            }
         }
     }
 
-    fn award_xp(&mut self, xp: u64) {
-        self.state.player.xp += xp;
-        while self.state.player.xp >= self.state.player.xp_to_next {
-            self.state.player.xp -= self.state.player.xp_to_next;
-            self.state.player.level += 1;
-            self.state.player.xp_to_next = xp_for_level(self.state.player.level);
-            // Grant 1 skill point per level
-            self.state.skill_tree.skill_points += 1;
-            self.play_chest(|s| s.play_level_up());
-            self.add_message(format!(
-                "LEVEL UP! Level {} (+1 Skill Point)",
-                self.state.player.level
-            ));
-            // Update highest level
-            if self.state.player.level > self.state.rebirth.highest_level_ever {
-                self.state.rebirth.highest_level_ever = self.state.player.level;
-            }
-            self.check_chest_unlocks();
+    fn try_learn_skill(&mut self) {
+        let skills = all_skills();
+        if self.tab_scroll >= skills.len() {
+            return;
         }
+        self.dispatch(Command::LearnSkill(skills[self.tab_scroll].id.to_string()));
     }
 
-    fn check_chest_unlocks(&mut self) {
-        for ct in ChestType::ALL {
-            if !self.state.unlocked_chests.contains(&ct) {
-                let mut level_req = ct.required_level();
-
-                // Cartographer: Unlock chests 3 levels earlier
-                if self.state.skill_tree.has_skill("cartographer") {
-                    level_req = level_req.saturating_sub(3);
-                }
-
-                let meets_level = self.state.player.level >= level_req;
-
-                let has_key = match ct {
-                    ChestType::Wooden => true,
-                    ChestType::Iron => self.state.upgrades.get_level("iron_key") > 0,
-                    ChestType::Silver => self.state.upgrades.get_level("silver_key") > 0,
-                    ChestType::Gold => self.state.upgrades.get_level("gold_key") > 0,
-                    ChestType::Crystal => self.state.upgrades.get_level("crystal_key") > 0,
-                    ChestType::Shadow => self.state.upgrades.get_level("shadow_key") > 0,
-                    ChestType::Void => self.state.upgrades.get_level("void_key") > 0,
-                };
-
-                if meets_level && has_key {
-                    self.state.unlocked_chests.push(ct);
-                    self.add_message(format!("{} chests unlocked!", ct.name()));
-                }
-            }
+    /// Respecs cost GP, so the first press only names the price.
+    fn try_respec(&mut self, target: RespecTarget) {
+        if self.respec_confirm.as_ref() == Some(&target) {
+            self.respec_confirm = None;
+            self.dispatch(Command::Respec(target));
+            return;
         }
+        let cost = self.engine.respec_cost(&target);
+        let (key, what) = match &target {
+            RespecTarget::Skill(id) => ("X", get_skill(id).map_or(id.as_str(), |s| s.name)),
+            RespecTarget::WholeTree => ("Z", "every skill"),
+        };
+        self.add_message(format!("Press [{}] again to unlearn {} for {} GP", key, what, cost));
+        self.respec_confirm = Some(target);
     }
 
-    fn try_relic_drop(&mut self, item_rarity: Rarity) {
-        // Relics drop from higher chests; Uncommon relics from Silver+, Rare from Gold+, etc.
-        let chest_tier = self.state.current_chest_type.index();
-
-        // Uncommon relics can drop from Silver+ (tier 2+) on any item rarity
-        // Rare+ relics from Gold+ (tier 3+) on Epic/Legendary items
-        let base_drop_chance = match item_rarity {
-            Rarity::Uncommon if chest_tier >= 2 => 0.05,
-            Rarity::Rare if chest_tier >= 2 => 0.08,
-            Rarity::Epic if chest_tier >= 3 => 0.08,
-            Rarity::Legendary if chest_tier >= 3 => 0.20,
-            _ => return,
+    /// Show the selected achievement's title, or hide it if it's showing.
+    fn toggle_title(&mut self) {
+        let Some(achievement) = all_achievements().get(self.tab_scroll) else {
+            return;
         };
-
-        // Skill: Relic Hunter - double relic drop chance
-        let mut drop_chance = if self.state.skill_tree.has_skill("relic_hunter") {
-            base_drop_chance * 2.0
-        } else {
-            base_drop_chance
+        let Some(title) = achievement.title else {
+            return;
         };
-
-        // Deep Salvage: +10% relic drop
-        if self.state.skill_tree.has_skill("deep_salvage") {
-            drop_chance += 0.10;
+        if self.engine.state.achievements.title.as_deref() == Some(title) {
+            self.dispatch(Command::SetTitle(None));
+        } else {
+            self.dispatch(Command::SetTitle(Some(achievement.id.to_string())));
         }
+    }
 
-        // Relic: relic_drop_pct bonus
-        let relic_totals = relic_stat_totals(&self.state.relics.equipped);
-        drop_chance *= 1.0 + relic_totals.relic_drop_pct / 100.0;
-
-        // Upgrade: relic_magnet - +5% per level
-        let magnet_lvl = self.state.upgrades.get_level("relic_magnet") as f64;
-        drop_chance += magnet_lvl * 0.05;
-
-        // Upgrade: treasure_hunter - +8% per level
-        let hunter_lvl = self.state.upgrades.get_level("treasure_hunter") as f64;
-        drop_chance += hunter_lvl * 0.08;
-
-        // Upgrade: artifact_sense - +10% per level
-        let artifact_lvl = self.state.upgrades.get_level("artifact_sense") as f64;
-        drop_chance += artifact_lvl * 0.10;
-
-        // World Explorer capstone: +50% all drop rates
-        if self.state.skill_tree.has_skill("world_explorer") {
-            drop_chance *= 1.5;
+    fn try_buy_upgrade(&mut self) {
+        let upgrades = all_upgrades();
+        if self.tab_scroll >= upgrades.len() {
+            return;
         }
+        self.dispatch(Command::BuyUpgrade(upgrades[self.tab_scroll].id.to_string()));
+    }
 
-        if self.rng.random::<f64>() < drop_chance {
-            // All relics eligible for this chest tier (including already owned)
-            let candidates: Vec<_> = relics::all_relics()
-                .into_iter()
-                .filter(|r| r.min_chest_tier <= chest_tier)
-                .collect();
-
-            if candidates.is_empty() {
-                return;
-            }
-
-            // Weight by rarity: rarer relics are much harder to roll
-            let weights: Vec<f64> = candidates
-                .iter()
-                .map(|r| match r.rarity {
-                    Rarity::Uncommon => 50.0,
-                    Rarity::Rare => 20.0,
-                    Rarity::Epic => 8.0,
-                    Rarity::Legendary => 3.0,
-                    Rarity::Mythic => 1.0,
-                    _ => 0.0,
-                })
-                .collect();
-
-            let total_weight: f64 = weights.iter().sum();
-            let mut roll = self.rng.random::<f64>() * total_weight;
-
-            let mut chosen_idx = 0;
-            for (i, w) in weights.iter().enumerate() {
-                roll -= w;
-                if roll <= 0.0 {
-                    chosen_idx = i;
-                    break;
+    /// Keys on the Auto tab other than E and the arrows.
+    fn handle_automation_input(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('p') | KeyCode::Char('P') => self.dispatch(Command::ToggleAutomation),
+            KeyCode::Char('n') | KeyCode::Char('N') => {
+                self.dispatch(Command::AddRule(AutoRule::default()));
+                self.tab_scroll = self.engine.state.automation.rules.len().saturating_sub(1);
+            }
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                self.dispatch(Command::RemoveRule(self.tab_scroll));
+                self.tab_scroll = self
+                    .tab_scroll
+                    .min(self.engine.state.automation.rules.len().saturating_sub(1));
+            }
+            KeyCode::Char('a') | KeyCode::Char('A') => self.edit_selected_rule(|rule| {
+                rule.action = match rule.action {
+                    RuleAction::Cheapest => RuleAction::Balance { within: 1 },
+                    RuleAction::Balance { within } if within < 5 => {
+                        RuleAction::Balance { within: within + 1 }
+                    }
+                    RuleAction::Balance { .. } => RuleAction::Cheapest,
+                };
+            }),
+            KeyCode::Char('t') | KeyCode::Char('T') => self.edit_selected_rule(|rule| {
+                // Any -> Speed -> Luck -> ... -> Unlock -> Any
+                let next = match rule.category {
+                    None => 0,
+                    Some(c) => UpgradeCategory::ALL.iter().position(|&x| x == c).unwrap_or(0) + 1,
+                };
+                rule.category = UpgradeCategory::ALL.get(next).copied();
+            }),
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.edit_selected_rule(|rule| rule.max_cost_pct = (rule.max_cost_pct + 5).min(100));
+            }
+            KeyCode::Char('-') | KeyCode::Char('_') => {
+                self.edit_selected_rule(|rule| rule.max_cost_pct = rule.max_cost_pct.saturating_sub(5).max(5));
+            }
+            KeyCode::Char('[') | KeyCode::Char(']') => {
+                let up = key.code == KeyCode::Char('[');
+                let count = self.engine.state.automation.rules.len();
+                let moved = if up {
+                    self.tab_scroll.checked_sub(1)
+                } else {
+                    Some(self.tab_scroll + 1).filter(|&i| i < count)
+                };
+                if let Some(moved) = moved {
+                    self.dispatch(Command::MoveRule {
+                        index: self.tab_scroll,
+                        up,
+                    });
+                    self.tab_scroll = moved;
                 }
             }
-
-            let relic = &candidates[chosen_idx];
-
-            // Duplicate: if already owned, nothing happens
-            if self.state.relics.owns(relic.id) {
-                return;
-            }
-
-            self.state.relics.add_relic(relic.id.to_string());
-            self.add_message(format!("RELIC FOUND: {}!", relic.name));
-            self.float_texts.push(FloatText {
-                text: format!("NEW RELIC: {}", relic.name),
-                color: relic.rarity.color(),
-                ticks_remaining: 90,
-                total_ticks: 90,
-                x_offset: 0,
-                dir: FloatDir::Up,
-            });
+            _ => {}
         }
     }
 
-    fn collect_and_reset(&mut self) {
-        self.play_chest(|s| s.play_collect());
-        self.state.chest_progress.collect();
-
+    /// Change the selected automation rule through the engine.
+    fn edit_selected_rule(&mut self, edit: impl FnOnce(&mut AutoRule)) {
+        let Some(mut rule) = self.engine.state.automation.rules.get(self.tab_scroll).copied() else {
+            return;
+        };
+        edit(&mut rule);
+        self.dispatch(Command::SetRule(self.tab_scroll, rule));
     }
 
-    fn try_learn_skill(&mut self) {
-        let skills = all_skills();
-        if self.tab_scroll >= skills.len() {
-            return;
+    fn toggle_relic(&mut self) {
+        if let Some(id) = self.selected_relic_id() {
+            self.dispatch(Command::ToggleRelic(id));
         }
-        let skill = &skills[self.tab_scroll];
-        if self.state.skill_tree.learn(skill.id) {
-            self.play_ui(|s| s.play_purchase());
-            self.add_message(format!("Learned: {}!", skill.name));
-            self.recalculate_player_stats();
-            self.check_chest_unlocks();
-        } else if self.state.skill_tree.has_skill(skill.id) {
-            self.play_ui(|s| s.play_error());
-            self.add_message("Already learned!".to_string());
-        } else if self.state.skill_tree.skill_points == 0 {
-            self.play_ui(|s| s.play_error());
-            self.add_message("No skill points!".to_string());
-        } else {
-            self.play_ui(|s| s.play_error());
-            self.add_message("Prerequisites not met!".to_string());
+    }
+
+    fn upgrade_relic(&mut self) {
+        if let Some(id) = self.selected_relic_id() {
+            self.dispatch(Command::UpgradeRelic(id));
         }
     }
 
-    fn try_buy_upgrade(&mut self) {
-        let upgrades = all_upgrades();
-        if self.tab_scroll >= upgrades.len() {
+    /// Mark or unmark the selected relic for fusion. Marking a relic of
+    /// another rarity starts over; once enough are marked, ask to confirm.
+    fn pick_fusion_relic(&mut self) {
+        let Some(id) = self.selected_relic_id() else {
             return;
-        }
-        let upg = &upgrades[self.tab_scroll];
-        let current_level = self.state.upgrades.get_level(upg.id);
-        if current_level >= upg.max_level {
-            self.play_ui(|s| s.play_error());
-            self.add_message("Already maxed!".to_string());
+        };
+        let Some(relic) = relics::get_relic(&id) else {
+            return;
+        };
+        let Some(needed) = relics::fusion_inputs(relic.rarity) else {
+            self.add_message(format!("{} relics can't be fused", relic.rarity.label()));
             return;
+        };
+        if let Some(pos) = self.fusion_picks.iter().position(|p| *p == id) {
+            self.fusion_picks.remove(pos);
+            return;
+        }
+        let same_rarity = self
+            .fusion_picks
+            .first()
+            .and_then(|p| relics::get_relic(p))
+            .is_none_or(|p| p.rarity == relic.rarity);
+        if !same_rarity {
+            self.fusion_picks.clear();
+        }
+        self.play_ui(|s| s.play_click());
+        self.fusion_picks.push(id);
+        if self.fusion_picks.len() == needed {
+            self.fusion_confirm = true;
+            self.play_ui(|s| s.play_menu_open());
         }
+    }
 
-        // Check level requirement for key upgrades
-        if upg.category == crate::data::upgrades::UpgradeCategory::Unlock {
-            let req_level = match upg.id {
-                "iron_key" => 5,
-                "silver_key" => 10,
-                "gold_key" => 20,
-                "crystal_key" => 30,
-                "shadow_key" => 40,
-                "void_key" => 50,
-                _ => 1,
-            };
-            if self.state.player.level < req_level {
-                self.play_ui(|s| s.play_error());
-                self.add_message(format!("Need level {}!", req_level));
-                return;
+    fn handle_fusion_confirm(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return true,
+            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                let picks = std::mem::take(&mut self.fusion_picks);
+                self.fusion_confirm = false;
+                self.dispatch(Command::FuseRelics(picks));
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                self.fusion_picks.clear();
+                self.fusion_confirm = false;
+                self.play_ui(|s| s.play_menu_close());
             }
+            _ => {}
         }
+        false
+    }
 
-        let cost = upg.cost_at_level(current_level);
-        if self.state.player.gp < cost {
-            self.play_ui(|s| s.play_error());
-            self.add_message(format!("Need {} GP!", cost));
+    /// Equip the loadout in `slot`, or start naming it if a save is pending.
+    fn use_loadout_slot(&mut self, slot: usize) {
+        if !self.loadout_pick {
+            self.dispatch(Command::EquipRelicLoadout(slot));
             return;
         }
-        self.play_ui(|s| s.play_purchase());
-        self.state.player.gp -= cost;
-        self.state.upgrades.increment(upg.id);
-        self.add_message(format!(
-            "Upgraded {} to level {}",
-            upg.name,
-            current_level + 1
-        ));
-        self.recalculate_player_stats();
-        self.check_chest_unlocks();
+        self.loadout_pick = false;
+        let name = match self.engine.state.relics.loadout(slot) {
+            Some(loadout) => loadout.name.clone(),
+            None => format!("Loadout {}", slot + 1),
+        };
+        self.loadout_naming = Some((slot, name));
     }
 
-    fn toggle_relic(&mut self) {
+    fn handle_loadout_name_input(&mut self, key: KeyEvent) -> bool {
+        let Some((slot, name)) = self.loadout_naming.as_mut() else {
+            return false;
+        };
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return true,
+            KeyCode::Enter if !name.trim().is_empty() => {
+                let (slot, name) = (*slot, name.trim().to_string());
+                self.loadout_naming = None;
+                self.dispatch(Command::SaveRelicLoadout { slot, name });
+            }
+            KeyCode::Esc => self.loadout_naming = None,
+            KeyCode::Backspace => {
+                name.pop();
+            }
+            KeyCode::Char(c) if name.chars().count() < 20 => name.push(c),
+            _ => {}
+        }
+        false
+    }
+
+    fn selected_relic_id(&self) -> Option<String> {
         use crate::game::item::Rarity;
 
         // Rebuild the same display order as the UI
-        let owned = &self.state.relics.owned;
-        let rarities_order = [
-            Rarity::Mythic,
-            Rarity::Legendary,
-            Rarity::Epic,
-            Rarity::Rare,
-            Rarity::Uncommon,
-        ];
+        let owned = &self.engine.state.relics.owned;
 
         let mut organized_relics: Vec<(usize, String)> = Vec::new();
         for (original_idx, relic_id) in owned.iter().enumerate() {
@@ -1465,88 +1399,94 @@ impl App {
         // Sort by rarity tier (same as UI)
         organized_relics.sort_by_key(|(_, relic_id)| {
             relics::get_relic(relic_id)
-                .and_then(|def| rarities_order.iter().position(|r| *r == def.rarity))
-                .unwrap_or(99)
+                .and_then(|def| Rarity::ALL.iter().rev().position(|r| *r == def.rarity))
+                .unwrap_or(usize::MAX)
         });
 
-        if self.tab_scroll >= organized_relics.len() {
+        organized_relics
+            .into_iter()
+            .nth(self.tab_scroll)
+            .map(|(_, id)| id)
+    }
+
+    fn try_learn_rebirth_skill(&mut self) {
+        let skills = all_rebirth_skills();
+        if self.tab_scroll >= skills.len() {
             return;
         }
+        self.dispatch(Command::LearnRebirthSkill(skills[self.tab_scroll].id.to_string()));
+    }
 
-        let id = organized_relics[self.tab_scroll].1.clone();
-
-        // Dynamic max equipped based on upgrades and rebirth skills
-        let extra_slots = self.state.upgrades.get_level("deep_pockets") as usize;
-        let rebirth_slot = if self.state.rebirth.has_rebirth_skill("rb_relic_slot") { 1 } else { 0 };
-        let world_explorer_slot = if self.state.skill_tree.has_skill("world_explorer") { 1 } else { 0 };
-        let max_equipped = 3 + extra_slots + rebirth_slot + world_explorer_slot;
-
-        if self.state.relics.is_equipped(&id) {
-            self.state.relics.unequip(&id);
-        } else if self.state.relics.equipped.len() < max_equipped {
-            if self.state.relics.owns(&id) && !self.state.relics.is_equipped(&id) {
-                self.state.relics.equipped.push(id);
-            }
-        } else {
-            self.add_message("All relic slots full!".to_string());
+    fn try_learn_transcendence_skill(&mut self) {
+        let skills = all_transcendence_skills();
+        if self.tab_scroll >= skills.len() {
             return;
         }
-        self.recalculate_player_stats();
+        self.dispatch(Command::LearnTranscendenceSkill(skills[self.tab_scroll].id.to_string()));
     }
 
-    fn unequip_all_relics(&mut self) {
-        if self.state.relics.equipped.is_empty() {
-            self.add_message("No relics equipped!".to_string());
+    fn try_transcend(&mut self) {
+        let state = &self.engine.state;
+        if state.transcendence.can_transcend(state.rebirth.total_essence_earned) && !self.transcend_confirm {
+            self.transcend_confirm = true;
+            let aether = self.engine.aether_reward();
+            self.add_message(format!(
+                "Press [T] again to give up your rebirth skills and essence for {} Aether!",
+                aether
+            ));
             return;
         }
 
-        let count = self.state.relics.equipped.len();
-        self.state.relics.equipped.clear();
-        self.recalculate_player_stats();
-        self.add_message(format!("Unequipped {} relics", count));
+        // Transcend (the engine rejects it if too little essence was earned)
+        self.transcend_confirm = false;
+        self.dispatch(Command::Transcend);
     }
 
-    fn try_learn_rebirth_skill(&mut self) {
-        let skills = all_rebirth_skills();
-        if self.tab_scroll >= skills.len() {
+    fn try_craft(&mut self) {
+        let recipes = all_recipes();
+        if self.tab_scroll >= recipes.len() {
             return;
         }
-        let skill = &skills[self.tab_scroll];
-        if self.state.rebirth.has_rebirth_skill(skill.id) {
-            self.play_ui(|s| s.play_error());
-            self.add_message("Already learned!".to_string());
-        } else if self.state.rebirth.learn_rebirth_skill(skill.id) {
-            self.play_ui(|s| s.play_purchase());
-            self.add_message(format!("Learned rebirth skill: {}!", skill.name));
-            self.apply_rebirth_bonuses();
-            self.recalculate_player_stats();
-        } else if self.state.rebirth.essence < skill.essence_cost {
-            self.play_ui(|s| s.play_error());
-            self.add_message(format!("Need {} Essence!", skill.essence_cost));
-        } else {
-            self.play_ui(|s| s.play_error());
-            self.add_message("Prerequisites not met!".to_string());
-        }
+        self.dispatch(Command::Craft(recipes[self.tab_scroll].id.to_string()));
     }
 
-    fn try_rebirth(&mut self) {
-        let level = self.state.player.level;
-        if !self.state.rebirth.can_rebirth(level) {
-            self.play_ui(|s| s.play_error());
+    fn try_start_challenge(&mut self) {
+        let Some(challenge) = all_challenges().get(self.tab_scroll) else {
+            return;
+        };
+        let state = &self.engine.state;
+        let ready = state.rebirth.challenge.is_none() && state.rebirth.can_rebirth(state.player.level);
+        if ready && self.challenge_confirm.as_deref() != Some(challenge.id) {
+            self.challenge_confirm = Some(challenge.id.to_string());
+            let essence = self.engine.essence_reward();
             self.add_message(format!(
-                "Need level {} to rebirth! (currently {})",
-                self.state.rebirth.min_level_for_rebirth(),
-                level
+                "Press [E] again to rebirth into {} for {} Essence!",
+                challenge.name, essence
             ));
-            self.rebirth_confirm = false;
             return;
         }
 
-        if !self.rebirth_confirm {
+        // The engine rejects it if a challenge is running or the level is too low
+        self.challenge_confirm = None;
+        self.dispatch(Command::StartChallenge(challenge.id.to_string()));
+    }
+
+    fn try_abandon_challenge(&mut self) {
+        if self.engine.state.rebirth.challenge.is_some() && !self.abandon_confirm {
+            self.abandon_confirm = true;
+            self.add_message("Press [A] again to abandon the challenge!".to_string());
+            return;
+        }
+        self.abandon_confirm = false;
+        self.dispatch(Command::AbandonChallenge);
+    }
+
+    fn try_rebirth(&mut self) {
+        let rebirth = &self.engine.state.rebirth;
+        let level = self.engine.state.player.level;
+        if rebirth.can_rebirth(level) && !self.rebirth_confirm {
             self.rebirth_confirm = true;
-            let essence = self.state.rebirth.calculate_essence_reward(
-                self.state.player.gp,
-            );
+            let essence = self.engine.essence_reward();
             self.add_message(format!(
                 "Press [R] again to rebirth for {} Essence!",
                 essence
@@ -1554,464 +1494,304 @@ impl App {
             return;
         }
 
-        // Perform rebirth
-        self.perform_rebirth();
+        // Perform rebirth (the engine rejects it if the level is too low)
         self.rebirth_confirm = false;
+        self.dispatch(Command::Rebirth);
     }
 
-    fn perform_rebirth(&mut self) {
-        let level = self.state.player.level;
-
-        // Calculate essence reward based on current GP
-        let essence = self.state.rebirth.calculate_essence_reward(self.state.player.gp);
-
-        // Award essence
-        self.state.rebirth.essence += essence;
-        self.state.rebirth.total_essence_earned += essence;
-        self.state.rebirth.rebirth_count += 1;
-
-        // Update highest level
-        if level > self.state.rebirth.highest_level_ever {
-            self.state.rebirth.highest_level_ever = level;
-        }
-
-        // Reset run-specific state
-        self.state.rebirth.gp_earned_this_run = 0;
-
-        // Reset player to defaults
-        self.state.player = crate::game::player::Player::default();
-
-        // Reset inventory, upgrades, skills, chests, chest progress
-        self.state.inventory = crate::game::inventory::Inventory::default();
-        self.state.upgrades = crate::game::upgrade::UpgradeState::default();
-        self.state.skill_tree = crate::game::skill_tree::SkillTreeState::default();
-        self.state.chest_progress = crate::game::chest::ChestProgress::default();
-        self.state.current_chest_type = ChestType::Wooden;
-        self.state.unlocked_chests = vec![ChestType::Wooden];
-        self.state.relics = crate::game::relic::RelicState::default();
-
-        // Reset app counters
-        self.chests_since_xp_surge = 0;
-        self.idle_income_ticks = 0;
-        self.consecutive_chests = 0;
-        self.empty_streak = 0;
-        self.chaos_buff_ticks = 0;
-        self.chaos_buff_type = None;
-        self.items_sold_count = 0;
-        self.catalyst_stacks = 0.0;
-        self.rare_streak_count = 0;
-        self.consecutive_crits = 0;
-        self.tab_scroll = 0;
-
-        // Apply rebirth bonuses
-        self.apply_rebirth_bonuses();
-        self.recalculate_player_stats();
-        self.check_chest_unlocks();
-
-        // Flash + message + sound
-        self.play_ui(|s| s.play_rebirth());
-        if self.setting_show_animations {
-            self.flashes.spawn(Color::Rgb(150, 100, 255), 20);
-        }
-        self.add_message(format!(
-            "REBIRTH #{} complete! +{} Essence",
-            self.state.rebirth.rebirth_count, essence
-        ));
+    /// The stacks the Inventory tab shows, in order.
+    pub fn inventory_view(&self) -> InventoryView {
+        InventoryView::new(&self.engine.state.inventory, &self.inventory_query)
+    }
 
-        // Save immediately
-        self.save_game();
+    /// Index into `Inventory.items` of the selected stack.
+    fn selected_item_index(&self) -> Option<usize> {
+        self.inventory_view().item_index(self.tab_scroll)
     }
 
-    fn apply_rebirth_bonuses(&mut self) {
-        let rb = &self.state.rebirth;
+    fn try_sell_item(&mut self) {
+        let Some(original_idx) = self.selected_item_index() else {
+            return;
+        };
+        self.dispatch(Command::SellItem(original_idx));
 
-        // Tier 1
-        if rb.has_rebirth_skill("rb_lucky_start") {
-            self.state.player.base_luck += 5.0;
-        }
-        if rb.has_rebirth_skill("rb_swift_start") {
-            self.state.player.base_speed += 0.3;
-        }
-        if rb.has_rebirth_skill("rb_gp_boost") {
-            self.state.player.base_gp_multiplier += 0.10;
-        }
-        if rb.has_rebirth_skill("rb_xp_boost") {
-            self.state.player.base_xp_multiplier += 0.10;
-        }
-        if rb.has_rebirth_skill("rb_crit_boost") {
-            self.state.player.base_crit_chance += 0.03;
-        }
-        if rb.has_rebirth_skill("rb_head_start") && self.state.player.level < 3 {
-            self.state.player.level = 3;
-            self.state.player.xp_to_next = xp_for_level(3);
-            self.state.skill_tree.skill_points += 2; // levels 2 and 3
-        }
-        if rb.has_rebirth_skill("rb_starting_gp") {
-            self.state.player.gp += 500;
+        // Adjust scroll if the stack was removed
+        let new_display_count = self.inventory_view().len();
+        if self.tab_scroll > 0 && self.tab_scroll >= new_display_count {
+            self.tab_scroll = new_display_count.saturating_sub(1);
         }
+    }
 
-        // Tier 2
-        if rb.has_rebirth_skill("rb_luck_mastery") {
-            self.state.player.base_luck += 10.0;
-        }
-        if rb.has_rebirth_skill("rb_speed_mastery") {
-            self.state.player.base_speed += 0.5;
-        }
-        if rb.has_rebirth_skill("rb_gp_mastery") {
-            self.state.player.base_gp_multiplier += 0.25;
-        }
-        if rb.has_rebirth_skill("rb_xp_mastery") {
-            self.state.player.base_xp_multiplier += 0.25;
-        }
-        if rb.has_rebirth_skill("rb_crit_mastery") {
-            self.state.player.base_crit_chance += 0.05;
-        }
-        // rb_relic_slot - handled in toggle_relic max_equipped calculation
-        if rb.has_rebirth_skill("rb_chest_unlock") {
-            // Start with Iron and Silver unlocked
-            if !self.state.unlocked_chests.contains(&ChestType::Iron) {
-                self.state.unlocked_chests.push(ChestType::Iron);
+    /// Keys while typing an inventory search. The list narrows as you type;
+    /// Enter keeps the search, Esc clears it.
+    fn handle_search_input(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return true,
+            KeyCode::Enter => self.search_typing = false,
+            KeyCode::Esc => {
+                self.search_typing = false;
+                self.inventory_query.search.clear();
             }
-            if !self.state.unlocked_chests.contains(&ChestType::Silver) {
-                self.state.unlocked_chests.push(ChestType::Silver);
+            KeyCode::Backspace => {
+                self.inventory_query.search.pop();
             }
-        }
-        // rb_essence_boost - handled in calculate_essence_reward
-
-        // Tier 3
-        if rb.has_rebirth_skill("rb_all_luck") {
-            self.state.player.base_luck += 20.0;
-            self.state.player.base_gp_multiplier += 0.10;
-        }
-        if rb.has_rebirth_skill("rb_all_speed") {
-            self.state.player.base_speed += 1.0;
-            // auto opener at start is implicitly handled - player gets speed which makes auto_opener work
-        }
-        if rb.has_rebirth_skill("rb_all_wealth") {
-            self.state.player.base_gp_multiplier += 0.50;
-            self.state.player.base_xp_multiplier += 0.50;
-        }
-        if rb.has_rebirth_skill("rb_all_crit") {
-            self.state.player.base_crit_chance += 0.10;
-        }
-        if rb.has_rebirth_skill("rb_ascension") {
-            // All base stats +50%
-            self.state.player.base_luck *= 1.5;
-            self.state.player.base_speed *= 1.5;
-            self.state.player.base_gp_multiplier *= 1.5;
-            self.state.player.base_xp_multiplier *= 1.5;
-            self.state.player.base_crit_chance = (self.state.player.base_crit_chance * 1.5).min(0.75);
-            // Start with Gold chests
-            if !self.state.unlocked_chests.contains(&ChestType::Gold) {
-                self.state.unlocked_chests.push(ChestType::Gold);
+            KeyCode::Char(c) if self.inventory_query.search.chars().count() < 32 => {
+                self.inventory_query.search.push(c);
             }
+            _ => return false,
         }
+        self.tab_scroll = 0;
+        false
     }
 
-    fn try_sell_item(&mut self) {
-        if !self.state.skill_tree.has_skill("transmute_basics") {
-            self.play_ui(|s| s.play_error());
-            self.add_message("Learn Transmute Basics to sell items!".to_string());
+    /// Cycle the selected stack's own filter rule: none, keep, sell, salvage.
+    fn cycle_item_filter(&mut self) {
+        let Some(index) = self.selected_item_index() else {
             return;
-        }
+        };
+        let id = self.engine.state.inventory.items[index].id.clone();
+        let next = match self.engine.state.loot_filter.items.get(&id) {
+            None => Some(FilterAction::Keep),
+            Some(FilterAction::Salvage) => None,
+            Some(action) => Some(action.next()),
+        };
+        self.dispatch(Command::SetItemFilter(id, next));
+    }
 
-        // Build display order mapping (same as UI)
-        use crate::game::item::Rarity;
-        let rarities = [
-            Rarity::Mythic,
-            Rarity::Legendary,
-            Rarity::Epic,
-            Rarity::Rare,
-            Rarity::Uncommon,
-            Rarity::Common,
-        ];
-
-        let mut display_to_original: Vec<usize> = Vec::new();
-        for rarity in &rarities {
-            for (original_idx, item) in self.state.inventory.items.iter().enumerate() {
-                if item.rarity == *rarity {
-                    display_to_original.push(original_idx);
+    /// Keys while the loot filter editor is open. Returns false for keys it
+    /// leaves to the rest of the game (tabs, chest, quit).
+    fn handle_filter_input(&mut self, key: KeyEvent) -> bool {
+        // Rarities, the keep threshold, then one row per item rule
+        let threshold_row = Rarity::ALL.len();
+        let item_rules: Vec<(String, FilterAction)> = self
+            .engine
+            .state
+            .loot_filter
+            .item_rules()
+            .into_iter()
+            .map(|(id, action)| (id.to_string(), action))
+            .collect();
+        let rows = threshold_row + 1 + item_rules.len();
+        let row = self.filter_selected.min(rows - 1);
+        let item_rule = row.checked_sub(threshold_row + 1).and_then(|i| item_rules.get(i));
+
+        match key.code {
+            KeyCode::Char('f') | KeyCode::Char('F') => {
+                self.filter_open = false;
+                self.play_ui(|s| s.play_menu_close());
+            }
+            KeyCode::Up => self.filter_selected = row.saturating_sub(1),
+            KeyCode::Down => self.filter_selected = (row + 1).min(rows - 1),
+            KeyCode::Char('e') | KeyCode::Char('E') | KeyCode::Enter => {
+                if let Some(&rarity) = Rarity::ALL.get(row) {
+                    let action = self.engine.state.loot_filter.rarity_action(rarity).next();
+                    self.dispatch(Command::SetRarityFilter(rarity, action));
+                } else if let Some((id, action)) = item_rule {
+                    self.dispatch(Command::SetItemFilter(id.clone(), Some(action.next())));
                 }
             }
-        }
-
-        if self.tab_scroll >= display_to_original.len() {
-            return;
-        }
-
-        let original_idx = display_to_original[self.tab_scroll];
-
-        // Clone item data before borrowing self mutably
-        let item = self.state.inventory.items[original_idx].clone();
-
-        // Calculate sell value for one item
-        let mut sell_pct = if self.state.skill_tree.has_skill("philosophers_stone") {
-            if matches!(item.rarity, Rarity::Rare | Rarity::Epic | Rarity::Legendary) {
-                1.5
-            } else {
-                1.0
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                if let Some((id, _)) = item_rule {
+                    self.dispatch(Command::SetItemFilter(id.clone(), None));
+                    self.filter_selected = row.min(rows - 2);
+                }
             }
-        } else if self.state.skill_tree.has_skill("gold_synthesis") {
-            0.75
-        } else {
-            0.50
-        };
-
-        // Magnum Opus doubles sell bonuses
-        if self.state.skill_tree.has_skill("magnum_opus") {
-            sell_pct *= 2.0;
-        }
-
-        let mut sell_gp = (item.gp_value as f64 * sell_pct) as u64;
-
-        // Elixir of Fortune: 10% chance to double sell GP
-        if self.state.skill_tree.has_skill("elixir_of_fortune") {
-            if self.rng.random::<f64>() < 0.10 {
-                sell_gp *= 2;
-                self.add_message("Elixir of Fortune: double sell!".to_string());
+            KeyCode::Char('+') | KeyCode::Char('=') if row == threshold_row => {
+                let gp = self.engine.state.loot_filter.keep_above_gp;
+                let raised = if gp.is_zero() { BigNum::from(10u64) } else { gp * 10.0 };
+                self.dispatch(Command::SetKeepAboveGp(raised));
             }
-        }
-
-        sell_gp = sell_gp.max(1);
-
-        self.play_ui(|s| s.play_sell());
-        self.state.player.gp += sell_gp;
-        self.state.stats.total_gp_earned += sell_gp;
-        self.state.rebirth.gp_earned_this_run += sell_gp;
-        self.items_sold_count += 1;
-
-        // Catalyst Brew: +1% GP per sell stack
-        if self.state.skill_tree.has_skill("catalyst_brew") {
-            self.catalyst_stacks += 1.0;
-        }
-
-        // Essence Distill: selling grants +10% XP of GP value
-        if self.state.skill_tree.has_skill("essence_distill") {
-            let xp_bonus = (sell_gp as f64 * 0.10) as u64;
-            self.award_xp(xp_bonus);
-        }
-
-        // Decrement count or remove item
-        if item.count > 1 {
-            self.state.inventory.items[original_idx].count -= 1;
-            self.add_message(format!("Sold 1× {} for {} GP ({} left)", item.name, sell_gp, item.count - 1));
-        } else {
-            self.state.inventory.items.remove(original_idx);
-            self.add_message(format!("Sold {} for {} GP", item.name, sell_gp));
-
-            // Adjust scroll if needed (using display count)
-            let new_display_count = display_to_original.len() - 1;
-            if self.tab_scroll > 0 && self.tab_scroll >= new_display_count {
-                self.tab_scroll = new_display_count.saturating_sub(1);
+            KeyCode::Char('-') | KeyCode::Char('_') if row == threshold_row => {
+                let gp = self.engine.state.loot_filter.keep_above_gp;
+                let lowered = if gp <= 10 { BigNum::ZERO } else { gp / 10.0 };
+                self.dispatch(Command::SetKeepAboveGp(lowered));
             }
+            KeyCode::Char('p') | KeyCode::Char('P') => self.dispatch(Command::ToggleLootFilter),
+            _ => return false,
         }
+        true
     }
 
     fn try_sell_all_items(&mut self) {
-        if !self.state.skill_tree.has_skill("transmute_basics") {
-            self.play_ui(|s| s.play_error());
-            self.add_message("Learn Transmute Basics to sell items!".to_string());
-            return;
-        }
-
-        if self.state.inventory.items.is_empty() {
-            self.play_ui(|s| s.play_error());
-            self.add_message("No items to sell!".to_string());
-            return;
-        }
-
-        let mut total_gp = 0u64;
-        let mut total_sold = 0u32;
+        self.dispatch(Command::SellAll);
+    }
 
-        // Calculate sell value for all items
-        for item in &self.state.inventory.items {
-            let mut sell_pct = if self.state.skill_tree.has_skill("philosophers_stone") {
-                if matches!(item.rarity, Rarity::Rare | Rarity::Epic | Rarity::Legendary) {
-                    1.5
-                } else {
-                    1.0
-                }
-            } else if self.state.skill_tree.has_skill("gold_synthesis") {
-                0.75
-            } else {
-                0.50
-            };
+    pub fn max_equipped_relics(&self) -> usize {
+        self.engine.max_equipped_relics()
+    }
 
-            if self.state.skill_tree.has_skill("magnum_opus") {
-                sell_pct *= 2.0;
-            }
+    fn unequip_all_relics(&mut self) {
+        self.dispatch(Command::UnequipAllRelics);
+    }
 
-            let sell_gp_per_item = ((item.gp_value as f64 * sell_pct) as u64).max(1);
-            total_gp += sell_gp_per_item * item.count as u64;
-            total_sold += item.count;
+    fn play_ui(&mut self, f: impl FnOnce(&mut SoundManager)) {
+        if self.setting_ui_sounds
+            && let Some(ref mut snd) = self.sound
+        {
+            f(snd);
         }
+    }
 
-        // Apply Elixir of Fortune once for the batch
-        if self.state.skill_tree.has_skill("elixir_of_fortune") {
-            if self.rng.random::<f64>() < 0.10 {
-                total_gp *= 2;
-                self.add_message("Elixir of Fortune: double sell!".to_string());
-            }
+    /// Play a chest sound (chest start, reveal, crit, collect, level up).
+    fn play_chest(&mut self, f: impl FnOnce(&mut SoundManager)) {
+        if self.setting_chest_sounds
+            && let Some(ref mut snd) = self.sound
+        {
+            f(snd);
         }
+    }
 
-        self.state.player.gp += total_gp;
-        self.state.stats.total_gp_earned += total_gp;
-        self.state.rebirth.gp_earned_this_run += total_gp;
-        self.items_sold_count += total_sold as u64;
+    fn add_message(&mut self, msg: String) {
+        self.message_log.push((msg, 90)); // 3 seconds
+    }
 
-        // Catalyst Brew: stacks based on count sold
-        if self.state.skill_tree.has_skill("catalyst_brew") {
-            self.catalyst_stacks += total_sold as f64;
+    pub fn save_game(&mut self) {
+        self.engine.state.volume = self.setting_volume;
+        self.engine.state.show_animations = self.setting_show_animations;
+        self.engine.state.chest_sounds = self.setting_chest_sounds;
+        self.engine.state.ui_sounds = self.setting_ui_sounds;
+        self.engine.state.last_saved = offline::unix_now();
+        // Nothing to save before a profile is open, and saving now would
+        // bury the backup the player hasn't answered about
+        if self.picker.is_some() || self.restore_offer.is_some() {
+            return;
         }
-
-        // Essence Distill
-        if self.state.skill_tree.has_skill("essence_distill") {
-            let xp_bonus = (total_gp as f64 * 0.10) as u64;
-            self.award_xp(xp_bonus);
+        if let Err(e) = save::save_game(&self.profile, &self.engine.state) {
+            self.add_message(format!("Save failed: {}", e));
         }
-
-        self.play_ui(|s| s.play_sell());
-        self.add_message(format!("Sold {} items for {} GP", total_sold, total_gp));
-        self.state.inventory.items.clear();
-        self.tab_scroll = 0;
     }
 
-    pub fn recalculate_player_stats(&mut self) {
-        let upgrades = all_upgrades();
-        let mut up_luck = 0.0f64;
-        let mut up_speed = 0.0f64;
-        let mut up_gp = 0.0f64;
-        let mut up_xp = 0.0f64;
-        let mut up_crit = 0.0f64;
-
-        for upg in &upgrades {
-            let lvl = self.state.upgrades.get_level(upg.id) as f64;
-            match upg.id {
-                "swift_hands" => up_speed += lvl * 0.10,
-                "nimble_fingers" => up_speed += lvl * 0.05,
-                "auto_opener" => {} // Just enables auto-opening, doesn't affect speed
-                "lucky_charm" => up_luck += lvl * 1.0,
-                "four_leaf" => up_luck += lvl * 2.0,
-                "critical_eye" => up_crit += lvl * 0.02,
-                "gold_touch" => up_gp += lvl * 0.10,
-                "xp_boost" => up_xp += lvl * 0.10,
-                "treasure_sense" => up_gp += lvl * 0.20,
-                // Speed category
-                "overdrive" => up_speed += lvl * 0.15,
-                "perpetual_gear" => up_speed += lvl * 0.10,
-                "quicksilver_touch" => up_speed += lvl * 0.08,
-                "haste_rune" => up_speed += lvl * 0.20,
-                "chrono_accelerator" => up_speed += lvl * 0.12,
-                // Luck category
-                "fortune_wheel" => up_luck += lvl * 3.0,
-                "horseshoe" => {} // Handled in rarity upgrade logic
-                "rabbits_paw" => up_luck += lvl * 4.0,
-                "lucky_dice" => up_crit += lvl * 0.03,
-                "stars_alignment" => up_luck += lvl * 5.0,
-                // Wealth category
-                "golden_magnet" => up_gp += lvl * 0.15,
-                "wisdom_tome" => up_xp += lvl * 0.15,
-                "alchemist_stone" => up_gp += lvl * 0.25,
-                "scholars_cap" => up_xp += lvl * 0.25,
-                "dragon_hoard_map" => up_gp += lvl * 0.30,
-                // Mastery category
-                "keen_edge" => up_crit += lvl * 0.03,
-                "crit_power" => {} // Handled in roll_loot crit calculation
-                "combo_counter" => {} // Handled in roll_loot
-                "xp_amplifier" => up_xp += lvl * 0.20,
-                "legendary_focus" => {} // Handled in roll_loot
-                "executioners_edge" => {} // Handled in roll_loot crit calculation
-                "precision_mastery" => up_crit += lvl * 0.04,
-                "knowledge_nexus" => up_xp += lvl * 0.30,
-                // Discovery category
-                "relic_magnet" => {} // Handled in try_relic_drop
-                "deep_pockets" => {} // Handled in toggle_relic
-                "bonus_loot" => {} // Handled in roll_loot
-                "chest_radar" => {} // Handled in roll_loot
-                "void_sight" => {} // Handled in loot table weighting
-                "treasure_hunter" => {} // Handled in try_relic_drop
-                "lucky_find" => {} // Handled in roll_loot
-                "artifact_sense" => {} // Handled in try_relic_drop
-                _ => {}
+    fn handle_restore_input(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Char('e') | KeyCode::Enter => {
+                self.restore_backup();
             }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                self.restore_offer = None;
+                self.add_message("Starting a new game".to_string());
+            }
+            KeyCode::Char('q') | KeyCode::Char('Q') => return true,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return true,
+            _ => {}
         }
+        false
+    }
 
-        // Skill tree stat bonuses
-        let tree = &self.state.skill_tree;
-        if tree.has_skill("lucky_charm") {
-            up_luck += 5.0;
-            up_gp += 0.10;
-        }
-        if tree.has_skill("treasure_sense") {
-            up_gp += 0.50;
-        }
-        if tree.has_skill("swift_hands") {
-            up_speed += 0.30;
-        }
-        if tree.has_skill("nimble_fingers") {
-            up_speed += 0.25;
-        }
-        if tree.has_skill("critical_eye") {
-            up_crit += 0.05;
-        }
-
-        // New skill stat bonuses
-        if tree.has_skill("fortune_favors") {
-            up_luck += 15.0;
-        }
-        if tree.has_skill("time_warp") {
-            up_speed += 0.50;
-        }
-        if tree.has_skill("temporal_mastery") {
-            up_speed += 0.75;
-        }
-        if tree.has_skill("precision_strike") {
-            up_crit += 0.12;
-        }
-        if tree.has_skill("elixir_of_fortune") {
-            up_luck += 5.0;
+    fn restore_backup(&mut self) {
+        let Some(offer) = self.restore_offer.take() else {
+            return;
+        };
+        let mut state = offer.backup.state;
+        if let Some(seed) = self.seed {
+            state.rng = GameRng::from_seed(seed);
         }
+        self.load_state(state);
+        self.offline_report = offline::catch_up(&mut self.engine, offline::unix_now());
 
-        let relic_totals = relic_stat_totals(&self.state.relics.equipped);
-
-        self.state.player.recalculate_stats(
-            up_luck, up_speed, up_gp, up_xp, up_crit,
-            relic_totals.luck, relic_totals.speed_pct, relic_totals.gp_pct,
-            relic_totals.xp_pct, relic_totals.crit,
-        );
-    }
-
-    pub fn max_equipped_relics(&self) -> usize {
-        let extra_slots = self.state.upgrades.get_level("deep_pockets") as usize;
-        let rebirth_slot = if self.state.rebirth.has_rebirth_skill("rb_relic_slot") { 1 } else { 0 };
-        let world_explorer_slot = if self.state.skill_tree.has_skill("world_explorer") { 1 } else { 0 };
-        3 + extra_slots + rebirth_slot + world_explorer_slot
+        self.add_message(format!("Restored {}", offer.backup.path.display()));
+        self.save_game();
     }
 
-    /// Play a UI sound (click, tab, menu, error, purchase, sell, rebirth).
-    fn play_ui(&mut self, f: impl FnOnce(&mut SoundManager)) {
-        if self.setting_ui_sounds {
-            if let Some(ref mut snd) = self.sound { f(snd); }
+    fn handle_picker_input(&mut self, key: KeyEvent) -> bool {
+        let Some(picker) = self.picker.as_mut() else {
+            return false;
+        };
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return true;
         }
-    }
 
-    /// Play a chest sound (chest start, reveal, crit, collect, level up).
-    fn play_chest(&mut self, f: impl FnOnce(&mut SoundManager)) {
-        if self.setting_chest_sounds {
-            if let Some(ref mut snd) = self.sound { f(snd); }
+        if let Some(input) = picker.input.as_mut() {
+            match key.code {
+                KeyCode::Esc => {
+                    picker.input = None;
+                    picker.error = None;
+                }
+                KeyCode::Enter => self.submit_profile_name(),
+                KeyCode::Backspace => {
+                    input.text.pop();
+                }
+                KeyCode::Char(c) if input.text.chars().count() < profiles::MAX_NAME_LEN => {
+                    input.text.push(c);
+                }
+                _ => {}
+            }
+            return false;
         }
-    }
 
-    fn add_message(&mut self, msg: String) {
-        self.message_log.push((msg, 90)); // 3 seconds
+        let confirm_delete = std::mem::take(&mut picker.confirm_delete);
+        picker.error = None;
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => return true,
+            KeyCode::Up => {
+                picker.selected = picker.selected.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                picker.selected = (picker.selected + 1).min(picker.profiles.len().saturating_sub(1));
+            }
+            KeyCode::Char('e') | KeyCode::Char('E') | KeyCode::Char(' ') | KeyCode::Enter => {
+                if let Some(name) = picker.selected_name() {
+                    self.play_ui(|s| s.play_click());
+                    self.open_profile(name);
+                }
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') => {
+                picker.input = Some(NameInput {
+                    action: NameAction::New,
+                    text: String::new(),
+                });
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') | KeyCode::Char('r') | KeyCode::Char('R') => {
+                if let Some(name) = picker.selected_name() {
+                    let copy = matches!(key.code, KeyCode::Char('c') | KeyCode::Char('C'));
+                    picker.input = Some(NameInput {
+                        action: if copy { NameAction::Copy } else { NameAction::Rename },
+                        text: name,
+                    });
+                }
+            }
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                if let Some(name) = picker.selected_name() {
+                    if confirm_delete {
+                        match profiles::delete(&name) {
+                            Ok(()) => picker.refresh(None),
+                            Err(e) => picker.error = Some(e.to_string()),
+                        }
+                    } else {
+                        picker.confirm_delete = true;
+                    }
+                }
+            }
+            _ => {}
+        }
+        false
     }
 
-    pub fn save_game(&mut self) {
-        self.state.volume = self.setting_volume;
-        self.state.show_animations = self.setting_show_animations;
-        self.state.chest_sounds = self.setting_chest_sounds;
-        self.state.ui_sounds = self.setting_ui_sounds;
-        save::save_game(&self.state);
+    fn submit_profile_name(&mut self) {
+        let Some(picker) = self.picker.as_mut() else {
+            return;
+        };
+        let Some(input) = picker.input.as_ref() else {
+            return;
+        };
+        let name = input.text.trim().to_string();
+        let selected = picker.selected_name().unwrap_or_default();
+        let result = match input.action {
+            NameAction::New => profiles::create(&name),
+            NameAction::Copy => profiles::copy(&selected, &name),
+            NameAction::Rename => profiles::rename(&selected, &name),
+        };
+        match result {
+            Ok(()) => {
+                picker.input = None;
+                picker.error = None;
+                picker.refresh(Some(&name));
+                self.play_ui(|s| s.play_click());
+            }
+            Err(e) => {
+                picker.error = Some(e.to_string());
+                self.play_ui(|s| s.play_error());
+            }
+        }
     }
 
     fn handle_settings_input(&mut self, key: KeyEvent) -> bool {
@@ -2037,7 +1817,7 @@ impl App {
                     self.setting_volume = (self.setting_volume - 0.1).max(0.0);
                     self.setting_volume = (self.setting_volume * 10.0).round() / 10.0;
                     if let Some(ref mut snd) = self.sound { snd.set_volume(self.setting_volume); }
-                    self.state.volume = self.setting_volume;
+                    self.engine.state.volume = self.setting_volume;
                     if let Some(ref mut snd) = self.sound { snd.play_click(); }
                 }
                 false
@@ -2048,7 +1828,7 @@ impl App {
                     self.setting_volume = (self.setting_volume + 0.1).min(1.0);
                     self.setting_volume = (self.setting_volume * 10.0).round() / 10.0;
                     if let Some(ref mut snd) = self.sound { snd.set_volume(self.setting_volume); }
-                    self.state.volume = self.setting_volume;
+                    self.engine.state.volume = self.setting_volume;
                     if let Some(ref mut snd) = self.sound { snd.play_click(); }
                 }
                 false
@@ -2132,27 +1912,26 @@ impl App {
                     }
                     1 => {
                         // Unlock all chests
-                        use crate::game::chest::ChestType;
                         for ct in ChestType::ALL {
-                            if !self.state.unlocked_chests.contains(&ct) {
-                                self.state.unlocked_chests.push(ct);
+                            if !self.engine.state.unlocked_chests.contains(&ct) {
+                                self.engine.state.unlocked_chests.push(ct);
                             }
                         }
                         self.add_message("All chests unlocked!".to_string());
                     }
                     2 => {
                         // Max money
-                        self.state.player.gp = 999_999_999;
+                        self.engine.state.player.gp = BigNum::from(999_999_999u64);
                         self.add_message("Max GP granted!".to_string());
                     }
                     3 => {
                         // Max skills
-                        self.state.skill_tree.skill_points = 9999;
+                        self.engine.state.skill_tree.skill_points = 9999;
                         self.add_message("Max skill points granted!".to_string());
                     }
                     4 => {
                         // Max essence
-                        self.state.rebirth.essence = 999_999;
+                        self.engine.state.rebirth.essence = BigNum::from(999_999u64);
                         self.add_message("Max essence granted!".to_string());
                     }
                     _ => {}
@@ -2165,32 +1944,30 @@ impl App {
 
     fn reset_game(&mut self) {
         // Create a completely fresh game state
-        self.state = GameState::default();
+        let mut state = GameState::default();
+        if let Some(seed) = self.seed {
+            state.rng = GameRng::from_seed(seed);
+        }
+        self.engine = GameEngine::new(state);
 
         // Reset app state
         self.tab_scroll = 0;
         self.active_tab = ActiveTab::Skills;
-        self.chests_since_xp_surge = 0;
-        self.idle_income_ticks = 0;
-        self.consecutive_chests = 0;
-        self.empty_streak = 0;
-        self.chaos_buff_ticks = 0;
-        self.chaos_buff_type = None;
-        self.items_sold_count = 0;
-        self.catalyst_stacks = 0.0;
         self.rebirth_confirm = false;
-        self.rare_streak_count = 0;
-        self.consecutive_crits = 0;
+        self.transcend_confirm = false;
         self.auto_opener_paused = false;
         self.show_chest_menu = false;
         self.show_settings = false;
         self.settings_selected = 0;
+        self.inventory_query = InventoryQuery::default();
+        self.search_typing = false;
+        self.filter_open = false;
+        self.filter_selected = 0;
         self.float_texts.clear();
         self.message_log.clear();
         self.fireworks = FireworkManager::default();
         self.flashes = FlashManager::default();
 
-        self.recalculate_player_stats();
         self.add_message("Game reset!".to_string());
         self.save_game();
     }
@@ -329,6 +329,22 @@ impl SoundManager {
                     ],
                 ]);
             }
+            Rarity::Divine => {
+                // Full major chord swelling under a two-octave run
+                self.play_layered(&[
+                    vec![(523.0, ms(450))],
+                    vec![(659.0, ms(450))],
+                    vec![(784.0, ms(450))],
+                    vec![
+                        (1047.0, ms(50)),
+                        (1319.0, ms(50)),
+                        (1568.0, ms(50)),
+                        (2093.0, ms(50)),
+                        (2637.0, ms(50)),
+                        (3136.0, ms(250)),
+                    ],
+                ]);
+            }
         }
     }
 
@@ -0,0 +1,312 @@
+//! Monte Carlo balance simulator: opens the same chest over and over from a
+//! fixed game state and reports the rates.
+//!
+//! ```text
+//! cargo run --release --bin sim -- sim.toml [--chests N] [--seed N] [--data-dir DIR]
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use color_eyre::{Result, eyre::eyre};
+use serde::Deserialize;
+
+use lootbox_game::data::pack::{self, DataPack};
+use lootbox_game::data::{rebirth_skills, relics, skills, transcendence_skills, upgrades};
+use lootbox_game::game::bignum::BigNum;
+use lootbox_game::game::chest::ChestType;
+use lootbox_game::game::engine::GameEngine;
+use lootbox_game::game::events::{GameEvent, LootSource};
+use lootbox_game::game::item::Rarity;
+use lootbox_game::game::offline::TICKS_PER_SEC;
+use lootbox_game::game::progression::{total_xp_for_level, xp_for_level};
+use lootbox_game::game::rng::GameRng;
+use lootbox_game::game::state::GameState;
+
+/// The game state to simulate, read from a TOML file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SimConfig {
+    #[serde(default = "default_level")]
+    level: u32,
+    #[serde(default = "default_chest")]
+    chest: ChestType,
+    #[serde(default)]
+    skills: Vec<String>,
+    #[serde(default)]
+    upgrades: BTreeMap<String, u32>,
+    #[serde(default)]
+    relics: Vec<String>,
+    #[serde(default)]
+    rebirth_skills: Vec<String>,
+    #[serde(default)]
+    rebirth_count: u32,
+    #[serde(default)]
+    transcendence_skills: Vec<String>,
+    #[serde(default = "default_chests")]
+    chests: u64,
+    #[serde(default)]
+    seed: u64,
+}
+
+fn default_level() -> u32 {
+    1
+}
+
+fn default_chest() -> ChestType {
+    ChestType::Wooden
+}
+
+fn default_chests() -> u64 {
+    1_000_000
+}
+
+impl SimConfig {
+    fn unknown_ids(&self) -> Vec<String> {
+        let mut unknown = Vec::new();
+        for id in &self.skills {
+            if skills::get_skill(id).is_none() {
+                unknown.push(format!("skill {id:?}"));
+            }
+        }
+        for id in self.upgrades.keys() {
+            if upgrades::get_upgrade(id).is_none() {
+                unknown.push(format!("upgrade {id:?}"));
+            }
+        }
+        for id in &self.relics {
+            if relics::get_relic(id).is_none() {
+                unknown.push(format!("relic {id:?}"));
+            }
+        }
+        for id in &self.rebirth_skills {
+            if rebirth_skills::get_rebirth_skill(id).is_none() {
+                unknown.push(format!("rebirth skill {id:?}"));
+            }
+        }
+        for id in &self.transcendence_skills {
+            if transcendence_skills::get_transcendence_skill(id).is_none() {
+                unknown.push(format!("transcendence skill {id:?}"));
+            }
+        }
+        unknown
+    }
+
+    fn build_state(&self) -> GameState {
+        let mut state = GameState::default();
+        state.player.level = self.level;
+        state.player.xp_to_next = xp_for_level(self.level);
+        state.skill_tree.learned = self.skills.iter().cloned().collect();
+        state.upgrades.levels = self.upgrades.clone().into_iter().collect();
+        for id in &self.relics {
+            state.relics.add_relic(id.clone());
+            state.relics.equipped.push(id.clone());
+        }
+        state.rebirth.rebirth_skills = self.rebirth_skills.iter().cloned().collect();
+        state.rebirth.rebirth_count = self.rebirth_count;
+        state.transcendence.skills = self.transcendence_skills.iter().cloned().collect();
+        state.current_chest_type = self.chest;
+        state.unlocked_chests = ChestType::ALL
+            .into_iter()
+            .filter(|ct| ct.index() <= self.chest.index())
+            .collect();
+        state.rng = GameRng::from_seed(self.seed);
+        state
+    }
+}
+
+/// Totals over a simulation run.
+#[derive(Default)]
+struct Tally {
+    chests: u64,
+    ticks: u64,
+    gp: BigNum,
+    xp: BigNum,
+    crits: u64,
+    relics: u64,
+    rarities: BTreeMap<usize, u64>,
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let Some(config_path) = args.first().filter(|a| !a.starts_with("--")) else {
+        return Err(eyre!(
+            "usage: sim <config.toml> [--chests N] [--seed N] [--data-dir DIR]"
+        ));
+    };
+    if let Some(dir) = flag_value(&args, "--data-dir") {
+        pack::install(DataPack::load_dir(Path::new(dir))?);
+    }
+
+    let text = std::fs::read_to_string(config_path)
+        .map_err(|e| eyre!("could not read {config_path}: {e}"))?;
+    let mut config: SimConfig =
+        toml::from_str(&text).map_err(|e| eyre!("{config_path}: {e}"))?;
+    if let Some(chests) = flag_value(&args, "--chests") {
+        config.chests = chests
+            .parse()
+            .map_err(|_| eyre!("--chests must be a whole number, got {chests:?}"))?;
+    }
+    if let Some(seed) = flag_value(&args, "--seed") {
+        config.seed = seed
+            .parse()
+            .map_err(|_| eyre!("--seed must be a whole number, got {seed:?}"))?;
+    }
+    let unknown = config.unknown_ids();
+    if !unknown.is_empty() {
+        return Err(eyre!("unknown ids in {config_path}: {}", unknown.join(", ")));
+    }
+
+    let tally = simulate(&config);
+    let level_reduction = GameEngine::new(config.build_state())
+        .modifiers()
+        .chest_level_reduction();
+    report(&config, &tally, level_reduction);
+    Ok(())
+}
+
+fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|a| a == flag)
+        .and_then(|i| args.get(i + 1))
+        .map(String::as_str)
+}
+
+fn simulate(config: &SimConfig) -> Tally {
+    let start = config.build_state();
+    let mut engine = GameEngine::new(start.clone());
+    let collect_delay = engine.modifiers().auto_collect_after().map_or(0, |n| n + 1);
+    let mut tally = Tally::default();
+
+    for _ in 0..config.chests {
+        let gp_before = engine.state.stats.total_gp_earned;
+        let xp_before = engine.state.stats.total_xp_earned;
+        let crits_before = engine.state.stats.crits_rolled;
+
+        let (ticks, events) = engine.open_chest_now();
+        tally.chests += 1;
+        tally.ticks += (ticks + collect_delay) as u64;
+        tally.gp += engine.state.stats.total_gp_earned - gp_before;
+        tally.xp += engine.state.stats.total_xp_earned - xp_before;
+        tally.crits += engine.state.stats.crits_rolled - crits_before;
+        for event in events {
+            match event {
+                GameEvent::ItemFound { item, source: LootSource::Chest, .. } => {
+                    *tally.rarities.entry(rarity_index(item.rarity)).or_default() += 1;
+                }
+                GameEvent::RelicFound { .. } => tally.relics += 1,
+                _ => {}
+            }
+        }
+
+        // Measure the configured state, not one that levels up and fills
+        // its relic collection as the run goes on
+        let state = &mut engine.state;
+        state.player.level = start.player.level;
+        state.player.xp = BigNum::ZERO;
+        state.player.xp_to_next = start.player.xp_to_next;
+        state.skill_tree.skill_points = 0;
+        state.relics.owned.clone_from(&start.relics.owned);
+        // Held affixes, completed sets and achievements would raise the stats
+        let held_affixes = state.inventory.items.iter().any(|i| !i.affixes.is_empty());
+        state.inventory.items.clear();
+        state.unlocked_chests.clone_from(&start.unlocked_chests);
+        let completed_set = state.collection.completed != start.collection.completed;
+        state.collection.clone_from(&start.collection);
+        let unlocked = state.achievements.unlocked != start.achievements.unlocked;
+        state.achievements.clone_from(&start.achievements);
+        if held_affixes || completed_set || unlocked {
+            engine.recalculate_player_stats();
+        }
+    }
+    tally
+}
+
+fn rarity_index(rarity: Rarity) -> usize {
+    Rarity::ALL.iter().position(|r| *r == rarity).unwrap_or(0)
+}
+
+fn report(config: &SimConfig, tally: &Tally, level_reduction: u32) {
+    let chests = tally.chests.max(1) as f64;
+    let secs = (tally.ticks as f64 / TICKS_PER_SEC as f64).max(f64::EPSILON);
+    let xp_per_sec = tally.xp / secs;
+
+    println!(
+        "{} chest, level {}, {} chests (seed {})",
+        config.chest.name(),
+        config.level,
+        tally.chests,
+        config.seed
+    );
+    println!("  Time per chest   {:.2}s", secs / chests);
+    println!("  GP/s             {}", short(tally.gp / secs));
+    println!("  XP/s             {}", short(xp_per_sec));
+    println!("  GP per chest     {}", short(tally.gp / chests));
+    println!("  XP per chest     {}", short(tally.xp / chests));
+    println!("  Crit rate        {:.2}%", tally.crits as f64 / chests * 100.0);
+    if tally.relics > 0 {
+        println!(
+            "  Relic drops      {:.3}% (1 in {:.0} chests)",
+            tally.relics as f64 / chests * 100.0,
+            chests / tally.relics as f64
+        );
+    } else {
+        println!("  Relic drops      none");
+    }
+    println!("  Rarity");
+    for (i, rarity) in Rarity::ALL.iter().enumerate() {
+        let count = tally.rarities.get(&i).copied().unwrap_or(0);
+        println!("    {:<10} {:>7.3}%", rarity.label(), count as f64 / chests * 100.0);
+    }
+
+    // Loot doesn't depend on level, so these rates hold all the way up
+    let next_chest = ChestType::ALL
+        .into_iter()
+        .find(|ct| ct.index() > config.chest.index());
+    match next_chest {
+        Some(ct) => {
+            let level = ct.required_level().saturating_sub(level_reduction);
+            println!(
+                "  Next chest       {} at level {}: {} (plus its key)",
+                ct.name(),
+                level,
+                time_to_level(config.level, level, xp_per_sec)
+            );
+        }
+        None => println!("  Next chest       none, this is the last"),
+    }
+    let rebirth_level = 25 + config.rebirth_count * 5;
+    println!(
+        "  Rebirth          level {}: {}",
+        rebirth_level,
+        time_to_level(config.level, rebirth_level, xp_per_sec)
+    );
+}
+
+fn time_to_level(from: u32, to: u32, xp_per_sec: BigNum) -> String {
+    if to <= from {
+        return "already there".to_string();
+    }
+    if xp_per_sec.is_zero() {
+        return "never (no XP)".to_string();
+    }
+    let needed = total_xp_for_level(to) - total_xp_for_level(from);
+    let secs = needed.to_f64() / xp_per_sec.to_f64();
+    match secs {
+        s if s < 60.0 => format!("{:.0}s", s),
+        s if s < 3600.0 => format!("{:.0}m {:.0}s", (s / 60.0).floor(), s % 60.0),
+        s if s < 86400.0 => format!("{:.0}h {:.0}m", (s / 3600.0).floor(), (s % 3600.0 / 60.0).floor()),
+        s => format!("{:.1} days", s / 86400.0),
+    }
+}
+
+fn short(n: BigNum) -> String {
+    let (mantissa, exponent) = n.scientific();
+    if exponent < 6 {
+        format!("{:.1}", n.to_f64())
+    } else {
+        format!("{:.3}e{}", mantissa, exponent)
+    }
+}
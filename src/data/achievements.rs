@@ -0,0 +1,285 @@
+use serde::{Deserialize, Serialize};
+
+use super::effects::{Effect, Stat};
+use super::pack::{self, leak};
+use crate::game::chest::ChestType;
+use crate::game::item::Rarity;
+use crate::game::state::GameState;
+
+/// A number an achievement can count up to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Counter {
+    ChestsOpened,
+    ItemsFound,
+    RaresFound,
+    EpicsFound,
+    LegendariesFound,
+    MythicsFound,
+    DivinesFound,
+    CritsRolled,
+    Rebirths,
+    /// The player's level in the current run.
+    Level,
+    SetsCompleted,
+    RelicsOwned,
+}
+
+impl Counter {
+    pub fn value(self, state: &GameState) -> u64 {
+        let stats = &state.stats;
+        match self {
+            Counter::ChestsOpened => stats.chests_opened,
+            Counter::ItemsFound => stats.items_found,
+            Counter::RaresFound => stats.rares_found,
+            Counter::EpicsFound => stats.epics_found,
+            Counter::LegendariesFound => stats.legendaries_found,
+            Counter::MythicsFound => stats.mythics_found,
+            Counter::DivinesFound => stats.divines_found,
+            Counter::CritsRolled => stats.crits_rolled,
+            Counter::Rebirths => state.rebirth.rebirth_count as u64,
+            Counter::Level => state.player.level as u64,
+            Counter::SetsCompleted => state.collection.completed.len() as u64,
+            Counter::RelicsOwned => state.relics.owned.len() as u64,
+        }
+    }
+}
+
+/// What has to happen to unlock an achievement.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum AchievementGoal {
+    /// A counter reaching `target`.
+    Count { counter: Counter, target: u64 },
+    /// An item of at least `rarity` dropping from `chest`.
+    DropFrom { chest: ChestType, rarity: Rarity },
+    /// Crafting an item of at least this rarity.
+    Craft(Rarity),
+    /// Fusing relics into a new one.
+    FuseRelics,
+    /// Leveling a relic to the top level.
+    MaxRelicLevel,
+    /// Rebirthing with no relics equipped.
+    RebirthWithoutRelics,
+}
+
+/// A milestone that stays unlocked across rebirths.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AchievementDef {
+    #[serde(deserialize_with = "leak::str")]
+    pub id: &'static str,
+    #[serde(deserialize_with = "leak::str")]
+    pub name: &'static str,
+    #[serde(deserialize_with = "leak::str")]
+    pub description: &'static str,
+    pub goal: AchievementGoal,
+    /// What the effects do, for display.
+    #[serde(default, deserialize_with = "leak::str")]
+    pub reward: &'static str,
+    /// Permanent bonus once unlocked.
+    #[serde(default, deserialize_with = "leak::slice")]
+    pub effects: &'static [Effect],
+    /// Title the player can show next to their level.
+    #[serde(default, deserialize_with = "leak::opt_str")]
+    pub title: Option<&'static str>,
+}
+
+impl AchievementDef {
+    /// How far along a counting goal is, as `(current, target)`. Other goals
+    /// happen all at once and have no progress to show.
+    pub fn progress(&self, state: &GameState) -> Option<(u64, u64)> {
+        match self.goal {
+            AchievementGoal::Count { counter, target } => {
+                Some((counter.value(state).min(target), target))
+            }
+            _ => None,
+        }
+    }
+}
+
+pub fn all_achievements() -> &'static [AchievementDef] {
+    &pack::current().achievements
+}
+
+pub fn get_achievement(id: &str) -> Option<&'static AchievementDef> {
+    all_achievements().iter().find(|a| a.id == id)
+}
+
+/// The achievements shipped with the game.
+pub fn builtin_achievements() -> Vec<AchievementDef> {
+    vec![
+        // === Chests ===
+        AchievementDef {
+            id: "first_steps",
+            name: "First Steps",
+            description: "Open 100 chests",
+            goal: AchievementGoal::Count { counter: Counter::ChestsOpened, target: 100 },
+            reward: "+1% GP",
+            effects: &[Effect::Stat(Stat::GpMult, 0.01)],
+            title: None,
+        },
+        AchievementDef {
+            id: "box_enthusiast",
+            name: "Box Enthusiast",
+            description: "Open 1,000 chests",
+            goal: AchievementGoal::Count { counter: Counter::ChestsOpened, target: 1_000 },
+            reward: "+2% Speed",
+            effects: &[Effect::Stat(Stat::Speed, 0.02)],
+            title: None,
+        },
+        AchievementDef {
+            id: "compulsive_opener",
+            name: "Compulsive Opener",
+            description: "Open 10,000 chests",
+            goal: AchievementGoal::Count { counter: Counter::ChestsOpened, target: 10_000 },
+            reward: "+3% GP",
+            effects: &[Effect::Stat(Stat::GpMult, 0.03)],
+            title: Some("the Insatiable"),
+        },
+        // === Rarities ===
+        AchievementDef {
+            id: "rare_taste",
+            name: "Rare Taste",
+            description: "Find 50 Rare items",
+            goal: AchievementGoal::Count { counter: Counter::RaresFound, target: 50 },
+            reward: "+1 Luck",
+            effects: &[Effect::Stat(Stat::Luck, 1.0)],
+            title: None,
+        },
+        AchievementDef {
+            id: "epic_hoarder",
+            name: "Epic Hoarder",
+            description: "Find 100 Epic items",
+            goal: AchievementGoal::Count { counter: Counter::EpicsFound, target: 100 },
+            reward: "+2 Luck",
+            effects: &[Effect::Stat(Stat::Luck, 2.0)],
+            title: None,
+        },
+        AchievementDef {
+            id: "living_legend",
+            name: "Living Legend",
+            description: "Find 25 Legendary items",
+            goal: AchievementGoal::Count { counter: Counter::LegendariesFound, target: 25 },
+            reward: "+3% XP",
+            effects: &[Effect::Stat(Stat::XpMult, 0.03)],
+            title: Some("the Legendary"),
+        },
+        AchievementDef {
+            id: "myth_maker",
+            name: "Myth Maker",
+            description: "Find a Mythic item",
+            goal: AchievementGoal::Count { counter: Counter::MythicsFound, target: 1 },
+            reward: "+2 Luck",
+            effects: &[Effect::Stat(Stat::Luck, 2.0)],
+            title: None,
+        },
+        AchievementDef {
+            id: "touched_by_gods",
+            name: "Touched by the Gods",
+            description: "Find a Divine item",
+            goal: AchievementGoal::Count { counter: Counter::DivinesFound, target: 1 },
+            reward: "+5% GP",
+            effects: &[Effect::Stat(Stat::GpMult, 0.05)],
+            title: Some("the Divine"),
+        },
+        AchievementDef {
+            id: "splinter_of_fate",
+            name: "Splinter of Fate",
+            description: "Find a Mythic from a Wooden chest",
+            goal: AchievementGoal::DropFrom { chest: ChestType::Wooden, rarity: Rarity::Mythic },
+            reward: "+3 Luck",
+            effects: &[Effect::Stat(Stat::Luck, 3.0)],
+            title: Some("the Improbable"),
+        },
+        AchievementDef {
+            id: "critical_mass",
+            name: "Critical Mass",
+            description: "Roll 1,000 crits",
+            goal: AchievementGoal::Count { counter: Counter::CritsRolled, target: 1_000 },
+            reward: "+1% crit chance",
+            effects: &[Effect::Stat(Stat::CritChance, 0.01)],
+            title: None,
+        },
+        // === Progress ===
+        AchievementDef {
+            id: "seasoned",
+            name: "Seasoned",
+            description: "Reach level 50",
+            goal: AchievementGoal::Count { counter: Counter::Level, target: 50 },
+            reward: "+2% XP",
+            effects: &[Effect::Stat(Stat::XpMult, 0.02)],
+            title: None,
+        },
+        AchievementDef {
+            id: "born_again",
+            name: "Born Again",
+            description: "Rebirth once",
+            goal: AchievementGoal::Count { counter: Counter::Rebirths, target: 1 },
+            reward: "+2% XP",
+            effects: &[Effect::Stat(Stat::XpMult, 0.02)],
+            title: None,
+        },
+        AchievementDef {
+            id: "eternal_return",
+            name: "Eternal Return",
+            description: "Rebirth 10 times",
+            goal: AchievementGoal::Count { counter: Counter::Rebirths, target: 10 },
+            reward: "+5% GP",
+            effects: &[Effect::Stat(Stat::GpMult, 0.05)],
+            title: Some("the Reborn"),
+        },
+        AchievementDef {
+            id: "ascetic",
+            name: "Ascetic",
+            description: "Rebirth with no relics equipped",
+            goal: AchievementGoal::RebirthWithoutRelics,
+            reward: "",
+            effects: &[],
+            title: Some("the Ascetic"),
+        },
+        // === Systems ===
+        AchievementDef {
+            id: "completionist",
+            name: "Completionist",
+            description: "Complete 5 item sets",
+            goal: AchievementGoal::Count { counter: Counter::SetsCompleted, target: 5 },
+            reward: "+5% GP from loot",
+            effects: &[Effect::LootGp(0.05)],
+            title: Some("the Collector"),
+        },
+        AchievementDef {
+            id: "relic_hunter",
+            name: "Relic Hunter",
+            description: "Own 10 relics at once",
+            goal: AchievementGoal::Count { counter: Counter::RelicsOwned, target: 10 },
+            reward: "+1 Luck",
+            effects: &[Effect::Stat(Stat::Luck, 1.0)],
+            title: None,
+        },
+        AchievementDef {
+            id: "polished_to_perfection",
+            name: "Polished to Perfection",
+            description: "Level a relic to the top level",
+            goal: AchievementGoal::MaxRelicLevel,
+            reward: "",
+            effects: &[],
+            title: Some("the Polished"),
+        },
+        AchievementDef {
+            id: "melting_pot",
+            name: "Melting Pot",
+            description: "Fuse relics into a new one",
+            goal: AchievementGoal::FuseRelics,
+            reward: "+1% craft chance",
+            effects: &[Effect::CraftChance(0.01)],
+            title: None,
+        },
+        AchievementDef {
+            id: "alchemist",
+            name: "Alchemist",
+            description: "Craft a Legendary or better",
+            goal: AchievementGoal::Craft(Rarity::Legendary),
+            reward: "+2% craft chance",
+            effects: &[Effect::CraftChance(0.02)],
+            title: Some("the Alchemist"),
+        },
+    ]
+}
@@ -0,0 +1,254 @@
+use serde::{Deserialize, Serialize};
+
+use super::effects::{Effect, Stat};
+use super::pack::{self, leak};
+use crate::game::chest::ChestType;
+use crate::game::item::Rarity;
+
+/// Where an affix goes in the item's name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AffixKind {
+    /// "Gleaming Gold Bar"
+    Prefix,
+    /// "Gold Bar of Haste"
+    Suffix,
+}
+
+/// A random modifier rolled onto a drop.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AffixDef {
+    #[serde(deserialize_with = "leak::str")]
+    pub id: &'static str,
+    #[serde(deserialize_with = "leak::str")]
+    pub name: &'static str,
+    pub kind: AffixKind,
+    #[serde(deserialize_with = "leak::str")]
+    pub description: &'static str,
+    /// Extra GP value on the item itself (`0.25` = +25%).
+    #[serde(default)]
+    pub gp_bonus: f64,
+    /// Extra XP value on the item itself.
+    #[serde(default)]
+    pub xp_bonus: f64,
+    /// Effects that apply while an item with this affix is in the
+    /// inventory. Holding several copies counts once.
+    #[serde(default, deserialize_with = "leak::slice")]
+    pub held: &'static [Effect],
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AffixEntry {
+    #[serde(deserialize_with = "leak::str")]
+    pub affix_id: &'static str,
+    pub weight: f64,
+}
+
+/// The affixes one chest type can roll, weakest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent, bound(deserialize = "'de: 'static"))]
+pub struct AffixPool {
+    pub entries: Vec<AffixEntry>,
+}
+
+impl AffixPool {
+    /// Entries of one kind with their weights. Like loot tables, luck boosts
+    /// the entries further down the pool.
+    pub fn weighted_entries(&self, kind: AffixKind, luck: f64) -> Vec<(&'static AffixDef, f64)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| {
+                let affix = get_affix(entry.affix_id).filter(|a| a.kind == kind)?;
+                Some((affix, entry.weight * (1.0 + i as f64 * luck * 0.02)))
+            })
+            .collect()
+    }
+}
+
+/// Chance for a drop of this rarity to roll a prefix and a suffix.
+pub fn affix_chances(rarity: Rarity) -> (f64, f64) {
+    match rarity {
+        Rarity::Common => (0.0, 0.0),
+        Rarity::Uncommon => (0.25, 0.0),
+        Rarity::Rare => (0.5, 0.25),
+        Rarity::Epic => (0.75, 0.5),
+        Rarity::Legendary => (1.0, 0.75),
+        Rarity::Mythic | Rarity::Divine => (1.0, 1.0),
+    }
+}
+
+pub fn all_affixes() -> &'static [AffixDef] {
+    &pack::current().affixes
+}
+
+pub fn get_affix(id: &str) -> Option<&'static AffixDef> {
+    all_affixes().iter().find(|a| a.id == id)
+}
+
+pub fn affix_pool_for(chest: ChestType) -> &'static AffixPool {
+    static EMPTY: AffixPool = AffixPool { entries: Vec::new() };
+    pack::current().affix_pools.get(&chest).unwrap_or(&EMPTY)
+}
+
+/// The affixes shipped with the game.
+pub fn builtin_affixes() -> Vec<AffixDef> {
+    vec![
+        // === Prefixes (6) ===
+        AffixDef {
+            id: "gleaming",
+            name: "Gleaming",
+            kind: AffixKind::Prefix,
+            description: "+25% GP value",
+            gp_bonus: 0.25,
+            xp_bonus: 0.0,
+            held: &[],
+        },
+        AffixDef {
+            id: "studious",
+            name: "Studious",
+            kind: AffixKind::Prefix,
+            description: "+25% XP value",
+            gp_bonus: 0.0,
+            xp_bonus: 0.25,
+            held: &[],
+        },
+        AffixDef {
+            id: "lucky",
+            name: "Lucky",
+            kind: AffixKind::Prefix,
+            description: "+2 Luck while held",
+            gp_bonus: 0.0,
+            xp_bonus: 0.0,
+            held: &[Effect::Stat(Stat::Luck, 2.0)],
+        },
+        AffixDef {
+            id: "cursed",
+            name: "Cursed",
+            kind: AffixKind::Prefix,
+            description: "-3 Luck, +3% crit chance while held",
+            gp_bonus: 0.0,
+            xp_bonus: 0.0,
+            held: &[Effect::Stat(Stat::Luck, -3.0), Effect::Stat(Stat::CritChance, 0.03)],
+        },
+        AffixDef {
+            id: "pristine",
+            name: "Pristine",
+            kind: AffixKind::Prefix,
+            description: "+75% GP value",
+            gp_bonus: 0.75,
+            xp_bonus: 0.0,
+            held: &[],
+        },
+        AffixDef {
+            id: "radiant",
+            name: "Radiant",
+            kind: AffixKind::Prefix,
+            description: "+150% GP and +75% XP value",
+            gp_bonus: 1.5,
+            xp_bonus: 0.75,
+            held: &[],
+        },
+        // === Suffixes (6) ===
+        AffixDef {
+            id: "of_wisdom",
+            name: "of Wisdom",
+            kind: AffixKind::Suffix,
+            description: "+40% XP value",
+            gp_bonus: 0.0,
+            xp_bonus: 0.4,
+            held: &[],
+        },
+        AffixDef {
+            id: "of_haste",
+            name: "of Haste",
+            kind: AffixKind::Suffix,
+            description: "+5% Speed while held",
+            gp_bonus: 0.0,
+            xp_bonus: 0.0,
+            held: &[Effect::Stat(Stat::Speed, 0.05)],
+        },
+        AffixDef {
+            id: "of_the_miser",
+            name: "of the Miser",
+            kind: AffixKind::Suffix,
+            description: "+50% GP value",
+            gp_bonus: 0.5,
+            xp_bonus: 0.0,
+            held: &[],
+        },
+        AffixDef {
+            id: "of_precision",
+            name: "of Precision",
+            kind: AffixKind::Suffix,
+            description: "+2% crit chance while held",
+            gp_bonus: 0.0,
+            xp_bonus: 0.0,
+            held: &[Effect::Stat(Stat::CritChance, 0.02)],
+        },
+        AffixDef {
+            id: "of_fortune",
+            name: "of Fortune",
+            kind: AffixKind::Suffix,
+            description: "+4 Luck while held",
+            gp_bonus: 0.0,
+            xp_bonus: 0.0,
+            held: &[Effect::Stat(Stat::Luck, 4.0)],
+        },
+        AffixDef {
+            id: "of_ages",
+            name: "of Ages",
+            kind: AffixKind::Suffix,
+            description: "+100% GP and XP value",
+            gp_bonus: 1.0,
+            xp_bonus: 1.0,
+            held: &[],
+        },
+    ]
+}
+
+/// The affix pool shipped with the game for a chest type. Better chests
+/// add stronger affixes and make the weak ones less likely.
+pub fn builtin_affix_pool(chest: ChestType) -> AffixPool {
+    let entries: &[(&'static str, f64)] = match chest {
+        ChestType::Wooden | ChestType::Iron => &[
+            ("gleaming", 10.0),
+            ("studious", 10.0),
+            ("of_wisdom", 10.0),
+            ("of_the_miser", 6.0),
+        ],
+        ChestType::Silver | ChestType::Gold => &[
+            ("gleaming", 10.0),
+            ("studious", 10.0),
+            ("lucky", 6.0),
+            ("cursed", 4.0),
+            ("of_wisdom", 10.0),
+            ("of_the_miser", 8.0),
+            ("of_haste", 5.0),
+            ("of_precision", 4.0),
+        ],
+        ChestType::Crystal
+        | ChestType::Shadow
+        | ChestType::Void
+        | ChestType::Celestial
+        | ChestType::Primordial => &[
+            ("gleaming", 6.0),
+            ("studious", 6.0),
+            ("lucky", 6.0),
+            ("cursed", 5.0),
+            ("pristine", 4.0),
+            ("radiant", 1.5),
+            ("of_wisdom", 6.0),
+            ("of_the_miser", 6.0),
+            ("of_haste", 5.0),
+            ("of_precision", 4.0),
+            ("of_fortune", 3.0),
+            ("of_ages", 1.5),
+        ],
+    };
+    AffixPool {
+        entries: entries
+            .iter()
+            .map(|&(affix_id, weight)| AffixEntry { affix_id, weight })
+            .collect(),
+    }
+}
@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+
+use super::effects::{Effect, Stat};
+use super::pack::{self, leak};
+use crate::game::chest::ChestType;
+
+/// What a challenge run has to reach before its time runs out.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ChallengeGoal {
+    Level(u32),
+    /// GP earned during the challenge run.
+    Gp(u64),
+}
+
+impl ChallengeGoal {
+    pub fn describe(&self) -> String {
+        match self {
+            ChallengeGoal::Level(level) => format!("Reach level {}", level),
+            ChallengeGoal::Gp(gp) => format!("Earn {} GP", gp),
+        }
+    }
+}
+
+/// A rebirth with extra rules. Finishing the goal in time grants a
+/// permanent reward, once.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChallengeDef {
+    #[serde(deserialize_with = "leak::str")]
+    pub id: &'static str,
+    #[serde(deserialize_with = "leak::str")]
+    pub name: &'static str,
+    #[serde(deserialize_with = "leak::str")]
+    pub description: &'static str,
+    /// Restrictions in play for the whole run.
+    #[serde(deserialize_with = "leak::slice")]
+    pub rules: &'static [Effect],
+    pub goal: ChallengeGoal,
+    pub time_limit_secs: u64,
+    /// What the effects do, for display.
+    #[serde(deserialize_with = "leak::str")]
+    pub reward: &'static str,
+    /// Permanent bonus once completed.
+    #[serde(deserialize_with = "leak::slice")]
+    pub effects: &'static [Effect],
+}
+
+pub fn all_challenges() -> &'static [ChallengeDef] {
+    &pack::current().challenges
+}
+
+pub fn get_challenge(id: &str) -> Option<&'static ChallengeDef> {
+    all_challenges().iter().find(|c| c.id == id)
+}
+
+/// The challenges shipped with the game.
+pub fn builtin_challenges() -> Vec<ChallengeDef> {
+    vec![
+        ChallengeDef {
+            id: "bare_hands",
+            name: "Bare Hands",
+            description: "No relics can be equipped",
+            rules: &[Effect::NoRelics],
+            goal: ChallengeGoal::Level(25),
+            time_limit_secs: 45 * 60,
+            reward: "+1 relic slot",
+            effects: &[Effect::RelicSlots(1)],
+        },
+        ChallengeDef {
+            id: "splinters",
+            name: "Splinters",
+            description: "Wooden chests only",
+            rules: &[Effect::OnlyChests(&[ChestType::Wooden])],
+            goal: ChallengeGoal::Level(20),
+            time_limit_secs: 45 * 60,
+            reward: "+5% GP",
+            effects: &[Effect::Stat(Stat::GpMult, 0.05)],
+        },
+        ChallengeDef {
+            id: "molasses",
+            name: "Molasses",
+            description: "Opening speed capped at 1.0",
+            rules: &[Effect::SpeedCap(1.0)],
+            goal: ChallengeGoal::Level(25),
+            time_limit_secs: 60 * 60,
+            reward: "+5% Speed",
+            effects: &[Effect::Stat(Stat::Speed, 0.05)],
+        },
+        ChallengeDef {
+            id: "dunce_cap",
+            name: "Dunce Cap",
+            description: "Skills cost double",
+            rules: &[Effect::SkillCostMult(2)],
+            goal: ChallengeGoal::Level(25),
+            time_limit_secs: 45 * 60,
+            reward: "+5% XP",
+            effects: &[Effect::Stat(Stat::XpMult, 0.05)],
+        },
+        ChallengeDef {
+            id: "dragons_hoard",
+            name: "Dragon's Hoard",
+            description: "No selling, not even by the loot filter or recycling",
+            rules: &[Effect::NoSelling],
+            goal: ChallengeGoal::Gp(250_000),
+            time_limit_secs: 60 * 60,
+            reward: "+2 Luck",
+            effects: &[Effect::Stat(Stat::Luck, 2.0)],
+        },
+        ChallengeDef {
+            id: "penny_pincher",
+            name: "Penny Pincher",
+            description: "No upgrades, not even by automation",
+            rules: &[Effect::NoUpgrades],
+            goal: ChallengeGoal::Level(20),
+            time_limit_secs: 45 * 60,
+            reward: "+2% Crit Chance",
+            effects: &[Effect::Stat(Stat::CritChance, 0.02)],
+        },
+        ChallengeDef {
+            id: "iron_will",
+            name: "Iron Will",
+            description: "No relics, no selling, Wooden chests only",
+            rules: &[
+                Effect::NoRelics,
+                Effect::NoSelling,
+                Effect::OnlyChests(&[ChestType::Wooden]),
+            ],
+            goal: ChallengeGoal::Level(20),
+            time_limit_secs: 60 * 60,
+            reward: "+10% Essence",
+            effects: &[Effect::EssenceGain(0.10)],
+        },
+    ]
+}
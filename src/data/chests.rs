@@ -1,11 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use super::pack::{self, leak};
 use crate::game::chest::ChestType;
 use crate::game::item::ItemId;
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LootEntry {
+    #[serde(deserialize_with = "leak::str")]
     pub item_id: ItemId,
     pub weight: f64,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct LootTable {
     pub entries: Vec<LootEntry>,
 }
@@ -24,7 +31,13 @@ impl LootTable {
     }
 }
 
-pub fn loot_table_for(chest: ChestType) -> LootTable {
+pub fn loot_table_for(chest: ChestType) -> &'static LootTable {
+    static EMPTY: LootTable = LootTable { entries: Vec::new() };
+    pack::current().loot_tables.get(&chest).unwrap_or(&EMPTY)
+}
+
+/// The loot table shipped with the game for a chest type.
+pub fn builtin_loot_table(chest: ChestType) -> LootTable {
     match chest {
         ChestType::Wooden => LootTable {
             entries: vec![
@@ -126,5 +139,33 @@ pub fn loot_table_for(chest: ChestType) -> LootTable {
                 LootEntry { item_id: "void_sovereign_crown", weight: 0.4 },
             ],
         },
+        ChestType::Celestial => LootTable {
+            entries: vec![
+                LootEntry { item_id: "astral_compass", weight: 10.0 },
+                LootEntry { item_id: "titan_bone", weight: 10.0 },
+                LootEntry { item_id: "crown_of_ages", weight: 12.0 },
+                LootEntry { item_id: "infinity_gem", weight: 10.0 },
+                LootEntry { item_id: "godslayer_blade", weight: 8.0 },
+                LootEntry { item_id: "tear_of_creation", weight: 4.0 },
+                LootEntry { item_id: "aeons_heart", weight: 3.0 },
+                LootEntry { item_id: "void_sovereign_crown", weight: 2.0 },
+                LootEntry { item_id: "celestial_halo", weight: 0.5 },
+                LootEntry { item_id: "starforged_sigil", weight: 0.3 },
+            ],
+        },
+        ChestType::Primordial => LootTable {
+            entries: vec![
+                LootEntry { item_id: "crown_of_ages", weight: 8.0 },
+                LootEntry { item_id: "infinity_gem", weight: 10.0 },
+                LootEntry { item_id: "godslayer_blade", weight: 10.0 },
+                LootEntry { item_id: "tear_of_creation", weight: 6.0 },
+                LootEntry { item_id: "aeons_heart", weight: 5.0 },
+                LootEntry { item_id: "void_sovereign_crown", weight: 4.0 },
+                LootEntry { item_id: "celestial_halo", weight: 1.5 },
+                LootEntry { item_id: "starforged_sigil", weight: 1.0 },
+                LootEntry { item_id: "primordial_ember", weight: 0.5 },
+                LootEntry { item_id: "genesis_seed", weight: 0.25 },
+            ],
+        },
     }
 }
@@ -0,0 +1,270 @@
+use serde::{Deserialize, Serialize};
+
+use super::pack::leak;
+use crate::game::chest::ChestType;
+use crate::game::events::Proc;
+use crate::game::item::Rarity;
+
+/// Player stat that an effect can raise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Stat {
+    Luck,
+    Speed,
+    GpMult,
+    XpMult,
+    CritChance,
+}
+
+/// Order in which the modifier pipeline applies effects.
+///
+/// - `Base`: what was found and its starting numbers (rarity, base GP, crit multiplier)
+/// - `Additive`: flat bonuses, summed
+/// - `Multiplicative`: factors, multiplied together
+/// - `Proc`: random rolls
+/// - `Final`: clamps and what happens to the item afterwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Base,
+    Additive,
+    Multiplicative,
+    Proc,
+    Final,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 5] = [
+        Stage::Base,
+        Stage::Additive,
+        Stage::Multiplicative,
+        Stage::Proc,
+        Stage::Final,
+    ];
+}
+
+/// What a skill, upgrade or rebirth skill does. Upgrade effects are scaled
+/// by the upgrade's level; fractional bonuses (`0.25`) mean "+25%".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Effect {
+    // === Base ===
+    /// Permanent bonus to a base stat.
+    BaseStat(Stat, f64),
+    /// Multiply every base stat by `1 + x`.
+    BaseStatsMult(f64),
+    /// +x to the fixed crit multiplier (ignored by chaotic crits).
+    BaseCritMult(f64),
+    /// +x base GP on items.
+    BaseGp(f64),
+    /// Chance to bump a found item up one rarity tier.
+    RarityUpgrade { chance: f64, chaotic: bool },
+    /// After this many Common finds in a row, the next find is Rare.
+    PityRare { after: u32 },
+
+    // === Additive ===
+    Stat(Stat, f64),
+    /// +x to the crit multiplier, including chaotic crits.
+    CritMult(f64),
+    /// Luck that only applies to loot table weighting.
+    RareLuck(f64),
+    MultiDrop(f64),
+    /// Chance for a bonus Common item.
+    Scavenge(f64),
+    /// Chance for an extra item whenever a multi-drop happens.
+    BonusChest(f64),
+    RelicDrop(f64),
+    RelicSlots(u32),
+    /// Unlocks a chest type; buying it needs `min_level`.
+    ChestKey { chest: ChestType, min_level: u32 },
+    /// Chests unlock this many levels earlier.
+    ChestLevelReduction(u32),
+    /// Unlocks selling at this fraction of item value (highest wins).
+    SellPct(f64),
+    /// Sell fraction for Rare+ items (highest wins).
+    RareSellPct(f64),
+    /// Selling grants this fraction of the GP as XP.
+    SellXp(f64),
+    /// The loot filter sells and salvages new drops.
+    LootFilter,
+    /// +x to every crafting recipe's success chance.
+    CraftChance(f64),
+    AutoOpen,
+    /// Upgrade automation rules run.
+    AutoBuy,
+    /// Collect a revealed chest on its own after this many ticks (lowest wins).
+    AutoCollect { after_ticks: u32 },
+    /// Earn GP while idle every this many ticks (lowest wins).
+    IdleIncome { every_ticks: u32 },
+    /// Progress continues for up to x hours while the game is closed.
+    OfflineHours(f64),
+    StartLevel(u32),
+    StartGp(u64),
+    StartChests(#[serde(deserialize_with = "leak::slice")] &'static [ChestType]),
+    /// +x to the exponent of the essence formula.
+    EssenceExponent(f64),
+
+    // === Multiplicative ===
+    LootGp(f64),
+    LootXp(f64),
+    RarityGp {
+        #[serde(deserialize_with = "leak::slice")]
+        rarities: &'static [Rarity],
+        bonus: f64,
+    },
+    RarityXp {
+        #[serde(deserialize_with = "leak::slice")]
+        rarities: &'static [Rarity],
+        bonus: f64,
+    },
+    CritXp(f64),
+    /// +x GP per chest tier above Wooden.
+    ChestTierGp(f64),
+    /// +x GP per Rare+ find in a row after the first.
+    RareStreakGp(f64),
+    /// +x GP per crit in a row after the first.
+    CritComboGp(f64),
+    /// +x GP per item sold this run.
+    SellStackGp(f64),
+    /// Every `every`th chest multiplies XP by `mult`.
+    XpSurge { every: u32, mult: f64 },
+    /// Each chest in a row opens `per_chest` faster, up to `max`.
+    Momentum { per_chest: f64, max: f64 },
+    /// Multi-drop, scavenge and relic drop chances.
+    DropRates(f64),
+    /// Relic drop chance before flat bonuses.
+    RelicDropMult(f64),
+    SellMult(f64),
+    EssenceGain(f64),
+
+    // === Proc ===
+    /// Chance to multiply GP and XP; announced if `proc` is set.
+    Jackpot {
+        proc: Option<Proc>,
+        chance: f64,
+        gp: f64,
+        xp: f64,
+        chaotic: bool,
+    },
+    /// Raise the chance of another effect's jackpot.
+    JackpotChance { proc: Proc, bonus: f64 },
+    /// Chaotic effects trigger `1 + x` times as often.
+    ChaosAmplifier(f64),
+    /// Loot values vary by up to ±x.
+    Variance(f64),
+    DoubleOrNothing { double: f64, nothing: f64 },
+    /// Each chest grants a random GP, XP or Speed buff for a while.
+    ChaosSurge { ticks: u32, bonus: f64 },
+    /// Crits roll their multiplier between `min` and `max`.
+    ChaoticCrits { min: f64, max: f64 },
+    /// Chance for a crit to multiply again, repeatedly.
+    CritCascade(f64),
+    /// Chance for a chest to open instantly.
+    InstantOpen(f64),
+    /// Chance for a sale to pay out `mult` times.
+    SellJackpot { proc: Proc, chance: f64, mult: f64 },
+
+    // === Final ===
+    /// Items of this rarity are auto-sold instead of kept.
+    Recycle(Rarity),
+    /// Extra GP for auto-sold items.
+    RecycleGp(f64),
+    /// No relics can be equipped.
+    NoRelics,
+    /// Only these chests can be unlocked.
+    OnlyChests(#[serde(deserialize_with = "leak::slice")] &'static [ChestType]),
+    /// Opening speed can't go above this (lowest wins).
+    SpeedCap(f64),
+    /// Skills cost x times as many points (highest wins).
+    SkillCostMult(u32),
+    /// Nothing can be sold, by hand, by the loot filter or by recycling.
+    NoSelling,
+    /// No upgrades can be bought, by hand or by automation.
+    NoUpgrades,
+}
+
+impl Effect {
+    pub fn stage(&self) -> Stage {
+        match self {
+            Effect::BaseStat(..)
+            | Effect::BaseStatsMult(_)
+            | Effect::BaseCritMult(_)
+            | Effect::BaseGp(_)
+            | Effect::RarityUpgrade { .. }
+            | Effect::PityRare { .. } => Stage::Base,
+            Effect::Stat(..)
+            | Effect::CritMult(_)
+            | Effect::RareLuck(_)
+            | Effect::MultiDrop(_)
+            | Effect::Scavenge(_)
+            | Effect::BonusChest(_)
+            | Effect::RelicDrop(_)
+            | Effect::RelicSlots(_)
+            | Effect::ChestKey { .. }
+            | Effect::ChestLevelReduction(_)
+            | Effect::SellPct(_)
+            | Effect::RareSellPct(_)
+            | Effect::SellXp(_)
+            | Effect::LootFilter
+            | Effect::CraftChance(_)
+            | Effect::AutoOpen
+            | Effect::AutoBuy
+            | Effect::AutoCollect { .. }
+            | Effect::IdleIncome { .. }
+            | Effect::OfflineHours(_)
+            | Effect::StartLevel(_)
+            | Effect::StartGp(_)
+            | Effect::StartChests(_)
+            | Effect::EssenceExponent(_) => Stage::Additive,
+            Effect::LootGp(_)
+            | Effect::LootXp(_)
+            | Effect::RarityGp { .. }
+            | Effect::RarityXp { .. }
+            | Effect::CritXp(_)
+            | Effect::ChestTierGp(_)
+            | Effect::RareStreakGp(_)
+            | Effect::CritComboGp(_)
+            | Effect::SellStackGp(_)
+            | Effect::XpSurge { .. }
+            | Effect::Momentum { .. }
+            | Effect::DropRates(_)
+            | Effect::RelicDropMult(_)
+            | Effect::SellMult(_)
+            | Effect::EssenceGain(_) => Stage::Multiplicative,
+            Effect::Jackpot { .. }
+            | Effect::JackpotChance { .. }
+            | Effect::ChaosAmplifier(_)
+            | Effect::Variance(_)
+            | Effect::DoubleOrNothing { .. }
+            | Effect::ChaosSurge { .. }
+            | Effect::ChaoticCrits { .. }
+            | Effect::CritCascade(_)
+            | Effect::InstantOpen(_)
+            | Effect::SellJackpot { .. } => Stage::Proc,
+            Effect::Recycle(_)
+            | Effect::RecycleGp(_)
+            | Effect::NoRelics
+            | Effect::OnlyChests(_)
+            | Effect::SpeedCap(_)
+            | Effect::SkillCostMult(_)
+            | Effect::NoSelling
+            | Effect::NoUpgrades => Stage::Final,
+        }
+    }
+}
+
+/// Rare and better, for effects that care about "Rare+" finds.
+pub const RARE_PLUS: &[Rarity] = &[
+    Rarity::Rare,
+    Rarity::Epic,
+    Rarity::Legendary,
+    Rarity::Mythic,
+    Rarity::Divine,
+];
+
+/// Uncommon and better.
+pub const UNCOMMON_PLUS: &[Rarity] = &[
+    Rarity::Uncommon,
+    Rarity::Rare,
+    Rarity::Epic,
+    Rarity::Legendary,
+    Rarity::Mythic,
+    Rarity::Divine,
+];
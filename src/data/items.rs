@@ -1,6 +1,12 @@
+use super::pack;
 use crate::game::item::{ItemDef, Rarity};
 
-pub fn all_items() -> Vec<ItemDef> {
+pub fn all_items() -> &'static [ItemDef] {
+    &pack::current().items
+}
+
+/// The items shipped with the game.
+pub fn builtin_items() -> Vec<ItemDef> {
     vec![
         // === Common (10) ===
         ItemDef { id: "rusty_coin", name: "Rusty Coin", rarity: Rarity::Common, base_gp: 10, base_xp: 5, description: "A tarnished copper coin." },
@@ -47,11 +53,15 @@ pub fn all_items() -> Vec<ItemDef> {
         ItemDef { id: "tear_of_creation", name: "Tear of Creation", rarity: Rarity::Mythic, base_gp: 5000, base_xp: 2500, description: "A droplet from the birth of reality." },
         ItemDef { id: "aeons_heart", name: "Aeon's Heart", rarity: Rarity::Mythic, base_gp: 6000, base_xp: 3000, description: "Pulses with the rhythm of time itself." },
         ItemDef { id: "void_sovereign_crown", name: "Void Sovereign's Crown", rarity: Rarity::Mythic, base_gp: 7500, base_xp: 3750, description: "Dominion over nothingness." },
+
+        // === Divine (4) ===
+        ItemDef { id: "celestial_halo", name: "Celestial Halo", rarity: Rarity::Divine, base_gp: 20000, base_xp: 8000, description: "Hums with a choir no one else can hear." },
+        ItemDef { id: "starforged_sigil", name: "Starforged Sigil", rarity: Rarity::Divine, base_gp: 25000, base_xp: 10000, description: "Hammered on the anvil of a dying sun." },
+        ItemDef { id: "primordial_ember", name: "Primordial Ember", rarity: Rarity::Divine, base_gp: 35000, base_xp: 14000, description: "The first fire, still burning." },
+        ItemDef { id: "genesis_seed", name: "Genesis Seed", rarity: Rarity::Divine, base_gp: 50000, base_xp: 20000, description: "A world waiting to be planted." },
     ]
 }
 
 pub fn get_item(id: &str) -> Option<&'static ItemDef> {
-    use std::sync::LazyLock;
-    static ITEMS: LazyLock<Vec<ItemDef>> = LazyLock::new(all_items);
-    ITEMS.iter().find(|i| i.id == id)
+    all_items().iter().find(|i| i.id == id)
 }
@@ -1,6 +1,14 @@
+pub mod achievements;
+pub mod affixes;
+pub mod challenges;
 pub mod chests;
+pub mod effects;
 pub mod items;
+pub mod pack;
 pub mod rebirth_skills;
+pub mod recipes;
 pub mod relics;
+pub mod sets;
 pub mod skills;
+pub mod transcendence_skills;
 pub mod upgrades;
@@ -0,0 +1,491 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+use super::achievements::{AchievementDef, builtin_achievements};
+use super::affixes::{AffixDef, AffixPool, builtin_affix_pool, builtin_affixes};
+use super::challenges::{ChallengeDef, builtin_challenges};
+use super::chests::{LootTable, builtin_loot_table};
+use super::items::builtin_items;
+use super::recipes::{RecipeDef, builtin_recipes};
+use super::rebirth_skills::{RebirthSkillDef, builtin_rebirth_skills};
+use super::relics::{RelicDef, builtin_relics};
+use super::sets::{ItemSetDef, SetMembers, builtin_item_sets};
+use super::skills::{SkillDef, builtin_skills};
+use super::transcendence_skills::{TranscendenceSkillDef, builtin_transcendence_skills};
+use super::upgrades::{UpgradeDef, builtin_upgrades};
+use crate::game::chest::ChestType;
+use crate::game::item::ItemDef;
+
+/// Every table of game content. The built-in tables are used unless another
+/// pack is [`install`]ed before the game first reads its data.
+///
+/// On disk a pack is a directory with one file per table (`items`,
+/// `loot_tables`, `relics`, `skills`, `upgrades`, `rebirth_skills`, `sets`,
+/// `affixes`, `affix_pools`, `recipes`, `achievements`, `challenges`,
+/// `transcendence_skills`), each either `<name>.json` or `<name>.toml` and
+/// holding the table under a top-level key of the same name. Missing files
+/// fall back to the built-in table.
+#[derive(Debug, Clone)]
+pub struct DataPack {
+    pub items: Vec<ItemDef>,
+    pub loot_tables: BTreeMap<ChestType, LootTable>,
+    pub relics: Vec<RelicDef>,
+    pub skills: Vec<SkillDef>,
+    pub upgrades: Vec<UpgradeDef>,
+    pub rebirth_skills: Vec<RebirthSkillDef>,
+    pub sets: Vec<ItemSetDef>,
+    pub affixes: Vec<AffixDef>,
+    pub affix_pools: BTreeMap<ChestType, AffixPool>,
+    pub recipes: Vec<RecipeDef>,
+    pub achievements: Vec<AchievementDef>,
+    pub challenges: Vec<ChallengeDef>,
+    pub transcendence_skills: Vec<TranscendenceSkillDef>,
+}
+
+static PACK: OnceLock<DataPack> = OnceLock::new();
+
+/// The pack the game is running with.
+pub fn current() -> &'static DataPack {
+    PACK.get_or_init(DataPack::builtin)
+}
+
+/// Use `pack` for the rest of the process. Returns false (and changes
+/// nothing) if game data has already been read.
+pub fn install(pack: DataPack) -> bool {
+    PACK.set(pack).is_ok()
+}
+
+impl DataPack {
+    pub fn builtin() -> Self {
+        Self {
+            items: builtin_items(),
+            loot_tables: builtin_loot_tables(),
+            relics: builtin_relics(),
+            skills: builtin_skills(),
+            upgrades: builtin_upgrades(),
+            rebirth_skills: builtin_rebirth_skills(),
+            sets: builtin_item_sets(),
+            affixes: builtin_affixes(),
+            affix_pools: builtin_affix_pools(),
+            recipes: builtin_recipes(),
+            achievements: builtin_achievements(),
+            challenges: builtin_challenges(),
+            transcendence_skills: builtin_transcendence_skills(),
+        }
+    }
+
+    /// Read a pack from `dir` and validate it.
+    pub fn load_dir(dir: &Path) -> Result<Self, PackError> {
+        let pack = Self {
+            items: read_table(dir, "items")?.unwrap_or_else(builtin_items),
+            loot_tables: read_table(dir, "loot_tables")?.unwrap_or_else(builtin_loot_tables),
+            relics: read_table(dir, "relics")?.unwrap_or_else(builtin_relics),
+            skills: read_table(dir, "skills")?.unwrap_or_else(builtin_skills),
+            upgrades: read_table(dir, "upgrades")?.unwrap_or_else(builtin_upgrades),
+            rebirth_skills: read_table(dir, "rebirth_skills")?
+                .unwrap_or_else(builtin_rebirth_skills),
+            sets: read_table(dir, "sets")?.unwrap_or_else(builtin_item_sets),
+            affixes: read_table(dir, "affixes")?.unwrap_or_else(builtin_affixes),
+            affix_pools: read_table(dir, "affix_pools")?.unwrap_or_else(builtin_affix_pools),
+            recipes: read_table(dir, "recipes")?.unwrap_or_else(builtin_recipes),
+            achievements: read_table(dir, "achievements")?.unwrap_or_else(builtin_achievements),
+            challenges: read_table(dir, "challenges")?.unwrap_or_else(builtin_challenges),
+            transcendence_skills: read_table(dir, "transcendence_skills")?
+                .unwrap_or_else(builtin_transcendence_skills),
+        };
+        let errors = pack.validate();
+        if errors.is_empty() {
+            Ok(pack)
+        } else {
+            Err(PackError::Invalid(errors))
+        }
+    }
+
+    /// Write every table to `dir` as JSON, as a starting point for a new pack.
+    pub fn write_dir(&self, dir: &Path) -> Result<(), PackError> {
+        fs::create_dir_all(dir).map_err(|source| PackError::Io {
+            path: dir.to_path_buf(),
+            source,
+        })?;
+        write_table(dir, "items", &self.items)?;
+        write_table(dir, "loot_tables", &self.loot_tables)?;
+        write_table(dir, "relics", &self.relics)?;
+        write_table(dir, "skills", &self.skills)?;
+        write_table(dir, "upgrades", &self.upgrades)?;
+        write_table(dir, "rebirth_skills", &self.rebirth_skills)?;
+        write_table(dir, "sets", &self.sets)?;
+        write_table(dir, "affixes", &self.affixes)?;
+        write_table(dir, "affix_pools", &self.affix_pools)?;
+        write_table(dir, "recipes", &self.recipes)?;
+        write_table(dir, "achievements", &self.achievements)?;
+        write_table(dir, "challenges", &self.challenges)?;
+        write_table(dir, "transcendence_skills", &self.transcendence_skills)?;
+        Ok(())
+    }
+
+    /// Cross-table checks: duplicate ids, loot entries and sets naming items
+    /// that do not exist, affix pools naming unknown affixes, chests without
+    /// a loot table, and unknown or cyclic prerequisites.
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+
+        check_duplicates("items", self.items.iter().map(|i| i.id), &mut errors);
+        check_duplicates("relics", self.relics.iter().map(|r| r.id), &mut errors);
+        check_duplicates("skills", self.skills.iter().map(|s| s.id), &mut errors);
+        check_duplicates("upgrades", self.upgrades.iter().map(|u| u.id), &mut errors);
+        check_duplicates(
+            "rebirth_skills",
+            self.rebirth_skills.iter().map(|s| s.id),
+            &mut errors,
+        );
+        check_duplicates("sets", self.sets.iter().map(|s| s.id), &mut errors);
+        check_duplicates("affixes", self.affixes.iter().map(|a| a.id), &mut errors);
+        check_duplicates("recipes", self.recipes.iter().map(|r| r.id), &mut errors);
+        check_duplicates("achievements", self.achievements.iter().map(|a| a.id), &mut errors);
+        check_duplicates("challenges", self.challenges.iter().map(|c| c.id), &mut errors);
+        check_duplicates(
+            "transcendence_skills",
+            self.transcendence_skills.iter().map(|s| s.id),
+            &mut errors,
+        );
+
+        let item_ids: HashSet<&str> = self.items.iter().map(|i| i.id).collect();
+        for chest in ChestType::ALL {
+            let Some(table) = self.loot_tables.get(&chest) else {
+                errors.push(ValidationError::MissingLootTable(chest));
+                continue;
+            };
+            for entry in &table.entries {
+                if !item_ids.contains(entry.item_id) {
+                    errors.push(ValidationError::UnknownItem {
+                        chest,
+                        item_id: entry.item_id.to_string(),
+                    });
+                }
+            }
+        }
+
+        for set in &self.sets {
+            if let SetMembers::Items(ids) = &set.members {
+                for &item_id in ids.iter() {
+                    if !item_ids.contains(item_id) {
+                        errors.push(ValidationError::UnknownSetItem {
+                            set: set.id.to_string(),
+                            item_id: item_id.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+
+        let affix_ids: HashSet<&str> = self.affixes.iter().map(|a| a.id).collect();
+        for (&chest, pool) in &self.affix_pools {
+            for entry in &pool.entries {
+                if !affix_ids.contains(entry.affix_id) {
+                    errors.push(ValidationError::UnknownAffix {
+                        chest,
+                        affix_id: entry.affix_id.to_string(),
+                    });
+                }
+            }
+        }
+
+        check_prerequisites(
+            "skills",
+            self.skills.iter().map(|s| (s.id, s.prerequisites)).collect(),
+            &mut errors,
+        );
+        check_prerequisites(
+            "rebirth_skills",
+            self.rebirth_skills
+                .iter()
+                .map(|s| (s.id, s.prerequisites))
+                .collect(),
+            &mut errors,
+        );
+        check_prerequisites(
+            "transcendence_skills",
+            self.transcendence_skills
+                .iter()
+                .map(|s| (s.id, s.prerequisites))
+                .collect(),
+            &mut errors,
+        );
+
+        errors
+    }
+}
+
+fn builtin_loot_tables() -> BTreeMap<ChestType, LootTable> {
+    ChestType::ALL
+        .iter()
+        .map(|&chest| (chest, builtin_loot_table(chest)))
+        .collect()
+}
+
+fn builtin_affix_pools() -> BTreeMap<ChestType, AffixPool> {
+    ChestType::ALL
+        .iter()
+        .map(|&chest| (chest, builtin_affix_pool(chest)))
+        .collect()
+}
+
+// Definitions hold `&'static str`s, so they only deserialize from `'static`
+// input; the file text is leaked along with the rest of the pack.
+fn read_table<T: Deserialize<'static>>(dir: &Path, name: &str) -> Result<Option<T>, PackError> {
+    let json_path = dir.join(format!("{name}.json"));
+    let toml_path = dir.join(format!("{name}.toml"));
+    let (path, parsed) = if json_path.exists() {
+        let text = read_file(&json_path)?;
+        let parsed = serde_json::from_str::<HashMap<String, T>>(text).map_err(|e| e.to_string());
+        (json_path, parsed)
+    } else if toml_path.exists() {
+        let text = read_file(&toml_path)?;
+        let parsed = toml::from_str::<HashMap<String, T>>(text).map_err(|e| e.to_string());
+        (toml_path, parsed)
+    } else {
+        return Ok(None);
+    };
+
+    let mut file = parsed.map_err(|message| PackError::Parse {
+        path: path.clone(),
+        message,
+    })?;
+    match file.remove(name) {
+        Some(table) => Ok(Some(table)),
+        None => Err(PackError::Parse {
+            path,
+            message: format!("expected a top-level `{name}` key"),
+        }),
+    }
+}
+
+fn read_file(path: &Path) -> Result<&'static str, PackError> {
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(text.leak()),
+        Err(source) => Err(PackError::Io {
+            path: path.to_path_buf(),
+            source,
+        }),
+    }
+}
+
+fn write_table<T: Serialize>(dir: &Path, name: &str, table: &T) -> Result<(), PackError> {
+    let path = dir.join(format!("{name}.json"));
+    let json = serde_json::to_string_pretty(&HashMap::from([(name, table)])).map_err(|e| {
+        PackError::Parse {
+            path: path.clone(),
+            message: e.to_string(),
+        }
+    })?;
+    fs::write(&path, json).map_err(|source| PackError::Io { path, source })
+}
+
+fn check_duplicates(
+    table: &'static str,
+    ids: impl Iterator<Item = &'static str>,
+    errors: &mut Vec<ValidationError>,
+) {
+    let mut seen = HashSet::new();
+    for id in ids {
+        if !seen.insert(id) {
+            errors.push(ValidationError::DuplicateId {
+                table,
+                id: id.to_string(),
+            });
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    InProgress,
+    Done,
+}
+
+fn check_prerequisites(
+    table: &'static str,
+    nodes: HashMap<&'static str, &'static [&'static str]>,
+    errors: &mut Vec<ValidationError>,
+) {
+    // Sorted so reports come out in the same order every run
+    let mut ids: Vec<&'static str> = nodes.keys().copied().collect();
+    ids.sort_unstable();
+
+    for &id in &ids {
+        for &prereq in nodes[id] {
+            if !nodes.contains_key(prereq) {
+                errors.push(ValidationError::UnknownPrerequisite {
+                    table,
+                    id: id.to_string(),
+                    prerequisite: prereq.to_string(),
+                });
+            }
+        }
+    }
+
+    let mut visits = HashMap::new();
+    for &id in &ids {
+        find_cycles(table, id, &nodes, &mut visits, &mut Vec::new(), errors);
+    }
+}
+
+fn find_cycles(
+    table: &'static str,
+    id: &'static str,
+    nodes: &HashMap<&'static str, &'static [&'static str]>,
+    visits: &mut HashMap<&'static str, Visit>,
+    path: &mut Vec<&'static str>,
+    errors: &mut Vec<ValidationError>,
+) {
+    match visits.get(id) {
+        Some(Visit::Done) => return,
+        Some(Visit::InProgress) => {
+            let start = path.iter().position(|&p| p == id).unwrap_or(0);
+            let mut cycle: Vec<String> = path[start..].iter().map(|p| p.to_string()).collect();
+            cycle.push(id.to_string());
+            errors.push(ValidationError::PrerequisiteCycle { table, cycle });
+            return;
+        }
+        None => {}
+    }
+    let Some(prereqs) = nodes.get(id) else {
+        return;
+    };
+
+    visits.insert(id, Visit::InProgress);
+    path.push(id);
+    for &prereq in prereqs.iter() {
+        find_cycles(table, prereq, nodes, visits, path, errors);
+    }
+    path.pop();
+    visits.insert(id, Visit::Done);
+}
+
+/// A problem that makes a data pack unusable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    DuplicateId { table: &'static str, id: String },
+    MissingLootTable(ChestType),
+    UnknownItem { chest: ChestType, item_id: String },
+    UnknownSetItem { set: String, item_id: String },
+    UnknownAffix { chest: ChestType, affix_id: String },
+    UnknownPrerequisite {
+        table: &'static str,
+        id: String,
+        prerequisite: String,
+    },
+    PrerequisiteCycle {
+        table: &'static str,
+        cycle: Vec<String>,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::DuplicateId { table, id } => {
+                write!(f, "{}: id {:?} is defined more than once", table, id)
+            }
+            ValidationError::MissingLootTable(chest) => {
+                write!(f, "loot_tables: no table for the {} chest", chest.name())
+            }
+            ValidationError::UnknownItem { chest, item_id } => write!(
+                f,
+                "loot_tables: {} chest drops unknown item_id {:?}",
+                chest.name(),
+                item_id
+            ),
+            ValidationError::UnknownSetItem { set, item_id } => {
+                write!(f, "sets: {:?} needs unknown item_id {:?}", set, item_id)
+            }
+            ValidationError::UnknownAffix { chest, affix_id } => write!(
+                f,
+                "affix_pools: {} chest rolls unknown affix_id {:?}",
+                chest.name(),
+                affix_id
+            ),
+            ValidationError::UnknownPrerequisite {
+                table,
+                id,
+                prerequisite,
+            } => write!(
+                f,
+                "{}: {:?} requires unknown prerequisite {:?}",
+                table, id, prerequisite
+            ),
+            ValidationError::PrerequisiteCycle { table, cycle } => {
+                write!(f, "{}: prerequisite cycle {}", table, cycle.join(" -> "))
+            }
+        }
+    }
+}
+
+/// Why a data pack could not be loaded.
+#[derive(Debug)]
+pub enum PackError {
+    Io { path: PathBuf, source: io::Error },
+    Parse { path: PathBuf, message: String },
+    Invalid(Vec<ValidationError>),
+}
+
+impl fmt::Display for PackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PackError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            PackError::Parse { path, message } => write!(f, "{}: {}", path.display(), message),
+            PackError::Invalid(errors) => {
+                write!(f, "data pack has {} problem(s):", errors.len())?;
+                for error in errors {
+                    write!(f, "\n  - {}", error)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for PackError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PackError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// `deserialize_with` helpers for the `&'static` fields in data definitions.
+///
+/// A pack is loaded once per process, so its strings and lists are leaked
+/// to live as long as the built-in tables do.
+pub mod leak {
+    use serde::{Deserialize, Deserializer};
+
+    pub fn str<'de, D: Deserializer<'de>>(d: D) -> Result<&'static str, D::Error> {
+        String::deserialize(d).map(|s| &*s.leak())
+    }
+
+    pub fn strs<'de, D: Deserializer<'de>>(d: D) -> Result<&'static [&'static str], D::Error> {
+        let strings = Vec::<String>::deserialize(d)?;
+        let leaked: Vec<&'static str> = strings.into_iter().map(|s| &*s.leak()).collect();
+        Ok(leaked.leak())
+    }
+
+    pub fn opt_str<'de, D: Deserializer<'de>>(d: D) -> Result<Option<&'static str>, D::Error> {
+        Option::<String>::deserialize(d).map(|s| s.map(|s| &*s.leak()))
+    }
+
+    pub fn slice<'de, D, T>(d: D) -> Result<&'static [T], D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        Vec::<T>::deserialize(d).map(|v| &*v.leak())
+    }
+}
@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+
+use super::pack::{self, leak};
+use crate::game::item::Rarity;
+
+/// Items a recipe uses up.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum RecipeInput {
+    /// `count` copies of one item id at this rarity.
+    Same { rarity: Rarity, count: u32 },
+    /// `count` items of this rarity, mixed freely.
+    Any { rarity: Rarity, count: u32 },
+}
+
+impl RecipeInput {
+    pub fn rarity(self) -> Rarity {
+        match self {
+            RecipeInput::Same { rarity, .. } | RecipeInput::Any { rarity, .. } => rarity,
+        }
+    }
+
+    pub fn count(self) -> u32 {
+        match self {
+            RecipeInput::Same { count, .. } | RecipeInput::Any { count, .. } => count,
+        }
+    }
+
+    /// "5x the same Common"
+    pub fn describe(self) -> String {
+        match self {
+            RecipeInput::Same { rarity, count } => {
+                format!("{}x the same {}", count, rarity.label())
+            }
+            RecipeInput::Any { rarity, count } => format!("{}x any {}", count, rarity.label()),
+        }
+    }
+}
+
+/// Turns inventory items into one random item of a better rarity. The
+/// inputs are used up whether or not it works.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecipeDef {
+    #[serde(deserialize_with = "leak::str")]
+    pub id: &'static str,
+    #[serde(deserialize_with = "leak::str")]
+    pub name: &'static str,
+    #[serde(deserialize_with = "leak::slice")]
+    pub inputs: &'static [RecipeInput],
+    /// Rarity of the random item crafted.
+    pub output: Rarity,
+    /// Success chance before luck and Alchemy skills.
+    pub base_chance: f64,
+}
+
+pub fn all_recipes() -> &'static [RecipeDef] {
+    &pack::current().recipes
+}
+
+/// The recipes shipped with the game.
+pub fn builtin_recipes() -> Vec<RecipeDef> {
+    vec![
+        RecipeDef {
+            id: "refine_common",
+            name: "Refine Commons",
+            inputs: &[RecipeInput::Same { rarity: Rarity::Common, count: 5 }],
+            output: Rarity::Uncommon,
+            base_chance: 1.0,
+        },
+        RecipeDef {
+            id: "refine_uncommon",
+            name: "Refine Uncommons",
+            inputs: &[RecipeInput::Same { rarity: Rarity::Uncommon, count: 5 }],
+            output: Rarity::Rare,
+            base_chance: 0.9,
+        },
+        RecipeDef {
+            id: "refine_rare",
+            name: "Refine Rares",
+            inputs: &[RecipeInput::Same { rarity: Rarity::Rare, count: 4 }],
+            output: Rarity::Epic,
+            base_chance: 0.75,
+        },
+        RecipeDef {
+            id: "epic_fusion",
+            name: "Epic Fusion",
+            inputs: &[RecipeInput::Any { rarity: Rarity::Epic, count: 4 }],
+            output: Rarity::Legendary,
+            base_chance: 0.5,
+        },
+        RecipeDef {
+            id: "mythic_transmutation",
+            name: "Mythic Transmutation",
+            inputs: &[
+                RecipeInput::Any { rarity: Rarity::Epic, count: 3 },
+                RecipeInput::Any { rarity: Rarity::Legendary, count: 1 },
+            ],
+            output: Rarity::Mythic,
+            base_chance: 0.25,
+        },
+    ]
+}
+
+pub fn get_recipe(id: &str) -> Option<&'static RecipeDef> {
+    all_recipes().iter().find(|r| r.id == id)
+}
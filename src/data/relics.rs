@@ -1,6 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use super::pack::{self, leak};
 use crate::game::item::Rarity;
+use crate::game::relic::{RelicState, level_mult};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum RelicEffect {
     FlatLuck(f64),
     PercentSpeed(f64),
@@ -13,11 +17,14 @@ pub enum RelicEffect {
     Compound(Vec<RelicEffect>),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RelicDef {
+    #[serde(deserialize_with = "leak::str")]
     pub id: &'static str,
+    #[serde(deserialize_with = "leak::str")]
     pub name: &'static str,
     pub rarity: Rarity,
+    #[serde(deserialize_with = "leak::str")]
     pub description: &'static str,
     pub effect: RelicEffect,
     pub min_chest_tier: usize, // index into ChestType::ALL
@@ -35,7 +42,22 @@ pub struct RelicStatTotals {
     pub relic_drop_pct: f64,
 }
 
-pub fn all_relics() -> Vec<RelicDef> {
+/// How many relics of one rarity fuse into a random relic of the next
+/// rarity. Mythic relics can't be fused.
+pub fn fusion_inputs(rarity: Rarity) -> Option<usize> {
+    match rarity {
+        Rarity::Uncommon | Rarity::Rare => Some(3),
+        Rarity::Epic | Rarity::Legendary => Some(2),
+        _ => None,
+    }
+}
+
+pub fn all_relics() -> &'static [RelicDef] {
+    &pack::current().relics
+}
+
+/// The relics shipped with the game.
+pub fn builtin_relics() -> Vec<RelicDef> {
     vec![
         // === Uncommon relics (4) ===
         RelicDef {
@@ -590,40 +612,87 @@ pub fn all_relics() -> Vec<RelicDef> {
             ]),
             min_chest_tier: 6,
         },
+        // === Divine relics (4) ===
+        RelicDef {
+            id: "seraph_wing",
+            name: "Seraph's Wing",
+            rarity: Rarity::Divine,
+            description: "+200% Speed, +100% XP",
+            effect: RelicEffect::Compound(vec![
+                RelicEffect::PercentSpeed(200.0),
+                RelicEffect::PercentXpMult(100.0),
+            ]),
+            min_chest_tier: 7, // Celestial+
+        },
+        RelicDef {
+            id: "astral_throne",
+            name: "Astral Throne",
+            rarity: Rarity::Divine,
+            description: "+75 Luck, +250% GP",
+            effect: RelicEffect::Compound(vec![
+                RelicEffect::FlatLuck(75.0),
+                RelicEffect::PercentGpMult(250.0),
+            ]),
+            min_chest_tier: 7,
+        },
+        RelicDef {
+            id: "first_flame",
+            name: "First Flame",
+            rarity: Rarity::Divine,
+            description: "+30% Crit, +6.0x Crit Mult, +25% Multi-Drop",
+            effect: RelicEffect::Compound(vec![
+                RelicEffect::FlatCrit(0.30),
+                RelicEffect::PercentCritMult(6.0),
+                RelicEffect::FlatMultiDrop(0.25),
+            ]),
+            min_chest_tier: 8, // Primordial
+        },
+        RelicDef {
+            id: "world_egg",
+            name: "World Egg",
+            rarity: Rarity::Divine,
+            description: "+100 Luck, +200% GP, +200% XP, +50% Relic Drop",
+            effect: RelicEffect::Compound(vec![
+                RelicEffect::FlatLuck(100.0),
+                RelicEffect::PercentGpMult(200.0),
+                RelicEffect::PercentXpMult(200.0),
+                RelicEffect::PercentRelicDrop(50.0),
+            ]),
+            min_chest_tier: 8,
+        },
     ]
 }
 
 pub fn get_relic(id: &str) -> Option<&'static RelicDef> {
-    use std::sync::LazyLock;
-    static RELICS: LazyLock<Vec<RelicDef>> = LazyLock::new(all_relics);
-    RELICS.iter().find(|r| r.id == id)
+    all_relics().iter().find(|r| r.id == id)
 }
 
-pub fn relic_stat_totals(equipped: &[String]) -> RelicStatTotals {
+/// Combined effects of the equipped relics, each scaled by its level.
+pub fn relic_stat_totals(relics: &RelicState) -> RelicStatTotals {
     let mut totals = RelicStatTotals::default();
 
-    for id in equipped {
+    for id in &relics.equipped {
         if let Some(relic) = get_relic(id) {
-            accumulate_effect(&relic.effect, &mut totals);
+            accumulate_effect(&relic.effect, level_mult(relics.level(id)), &mut totals);
         }
     }
 
     totals
 }
 
-fn accumulate_effect(effect: &RelicEffect, totals: &mut RelicStatTotals) {
+fn accumulate_effect(effect: &RelicEffect, scale: f64, totals: &mut RelicStatTotals) {
     match effect {
-        RelicEffect::FlatLuck(v) => totals.luck += v,
-        RelicEffect::PercentSpeed(v) => totals.speed_pct += v,
-        RelicEffect::PercentGpMult(v) => totals.gp_pct += v,
-        RelicEffect::PercentXpMult(v) => totals.xp_pct += v,
-        RelicEffect::FlatCrit(v) => totals.crit += v,
-        RelicEffect::PercentCritMult(v) => totals.crit_mult += v,
-        RelicEffect::FlatMultiDrop(v) => totals.multi_drop += v,
-        RelicEffect::PercentRelicDrop(v) => totals.relic_drop_pct += v,
+        RelicEffect::FlatLuck(v) => totals.luck += v * scale,
+        RelicEffect::PercentSpeed(v) => totals.speed_pct += v * scale,
+        RelicEffect::PercentGpMult(v) => totals.gp_pct += v * scale,
+        RelicEffect::PercentXpMult(v) => totals.xp_pct += v * scale,
+        RelicEffect::FlatCrit(v) => totals.crit += v * scale,
+        RelicEffect::PercentCritMult(v) => totals.crit_mult += v * scale,
+        RelicEffect::FlatMultiDrop(v) => totals.multi_drop += v * scale,
+        RelicEffect::PercentRelicDrop(v) => totals.relic_drop_pct += v * scale,
         RelicEffect::Compound(effects) => {
             for e in effects {
-                accumulate_effect(e, totals);
+                accumulate_effect(e, scale, totals);
             }
         }
     }
@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+
+use super::chests::loot_table_for;
+use super::effects::{Effect, Stat};
+use super::pack::{self, leak};
+use crate::game::chest::ChestType;
+
+/// Which items make up a set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SetMembers {
+    /// A hand-picked list of item ids.
+    Items(#[serde(deserialize_with = "leak::strs")] &'static [&'static str]),
+    /// Everything a chest can drop.
+    Chest(ChestType),
+}
+
+/// A group of items that grants a permanent bonus once every piece has been
+/// held in the inventory at the same time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemSetDef {
+    #[serde(deserialize_with = "leak::str")]
+    pub id: &'static str,
+    #[serde(deserialize_with = "leak::str")]
+    pub name: &'static str,
+    #[serde(deserialize_with = "leak::str")]
+    pub description: &'static str,
+    pub members: SetMembers,
+    #[serde(default, deserialize_with = "leak::slice")]
+    pub effects: &'static [Effect],
+}
+
+impl ItemSetDef {
+    /// The item ids needed to complete the set, without repeats.
+    pub fn item_ids(&self) -> Vec<&'static str> {
+        match &self.members {
+            SetMembers::Items(ids) => ids.to_vec(),
+            SetMembers::Chest(chest) => {
+                let mut ids: Vec<&'static str> = Vec::new();
+                for entry in &loot_table_for(*chest).entries {
+                    if !ids.contains(&entry.item_id) {
+                        ids.push(entry.item_id);
+                    }
+                }
+                ids
+            }
+        }
+    }
+
+    pub fn contains(&self, item_id: &str) -> bool {
+        self.item_ids().contains(&item_id)
+    }
+}
+
+pub fn all_item_sets() -> &'static [ItemSetDef] {
+    &pack::current().sets
+}
+
+/// The item sets shipped with the game.
+pub fn builtin_item_sets() -> Vec<ItemSetDef> {
+    vec![
+        // === Named sets (5) ===
+        ItemSetDef {
+            id: "royal_regalia",
+            name: "Royal Regalia",
+            description: "+15% GP from loot",
+            members: SetMembers::Items(&["crown_of_ages", "sapphire_ring", "gold_bar"]),
+            effects: &[Effect::LootGp(0.15)],
+        },
+        ItemSetDef {
+            id: "wanderers_kit",
+            name: "Wanderer's Kit",
+            description: "+10% Speed",
+            members: SetMembers::Items(&["rusty_coin", "torn_cloth", "bent_nail", "feather"]),
+            effects: &[Effect::Stat(Stat::Speed, 0.10)],
+        },
+        ItemSetDef {
+            id: "arcane_study",
+            name: "Arcane Study",
+            description: "+15% XP from loot",
+            members: SetMembers::Items(&[
+                "quartz_crystal",
+                "carved_rune",
+                "enchanted_scroll",
+                "astral_compass",
+            ]),
+            effects: &[Effect::LootXp(0.15)],
+        },
+        ItemSetDef {
+            id: "dragonslayer",
+            name: "Dragonslayer",
+            description: "+3% crit chance",
+            members: SetMembers::Items(&["bronze_dagger", "dragon_scale", "godslayer_blade"]),
+            effects: &[Effect::Stat(Stat::CritChance, 0.03)],
+        },
+        ItemSetDef {
+            id: "celestial_treasury",
+            name: "Celestial Treasury",
+            description: "+5 Luck",
+            members: SetMembers::Items(&["moon_pearl", "phoenix_feather", "infinity_gem"]),
+            effects: &[Effect::Stat(Stat::Luck, 5.0)],
+        },
+        // === Chest collections (9) ===
+        ItemSetDef {
+            id: "wooden_collection",
+            name: "Wooden Collection",
+            description: "+5% GP",
+            members: SetMembers::Chest(ChestType::Wooden),
+            effects: &[Effect::Stat(Stat::GpMult, 0.05)],
+        },
+        ItemSetDef {
+            id: "iron_collection",
+            name: "Iron Collection",
+            description: "+5% XP",
+            members: SetMembers::Chest(ChestType::Iron),
+            effects: &[Effect::Stat(Stat::XpMult, 0.05)],
+        },
+        ItemSetDef {
+            id: "silver_collection",
+            name: "Silver Collection",
+            description: "+3 Luck",
+            members: SetMembers::Chest(ChestType::Silver),
+            effects: &[Effect::Stat(Stat::Luck, 3.0)],
+        },
+        ItemSetDef {
+            id: "gold_collection",
+            name: "Gold Collection",
+            description: "+10% GP",
+            members: SetMembers::Chest(ChestType::Gold),
+            effects: &[Effect::Stat(Stat::GpMult, 0.10)],
+        },
+        ItemSetDef {
+            id: "crystal_collection",
+            name: "Crystal Collection",
+            description: "+2% crit chance",
+            members: SetMembers::Chest(ChestType::Crystal),
+            effects: &[Effect::Stat(Stat::CritChance, 0.02)],
+        },
+        ItemSetDef {
+            id: "shadow_collection",
+            name: "Shadow Collection",
+            description: "+15% XP",
+            members: SetMembers::Chest(ChestType::Shadow),
+            effects: &[Effect::Stat(Stat::XpMult, 0.15)],
+        },
+        ItemSetDef {
+            id: "void_collection",
+            name: "Void Collection",
+            description: "+25% GP from loot",
+            members: SetMembers::Chest(ChestType::Void),
+            effects: &[Effect::LootGp(0.25)],
+        },
+        ItemSetDef {
+            id: "celestial_collection",
+            name: "Celestial Collection",
+            description: "+10 Luck",
+            members: SetMembers::Chest(ChestType::Celestial),
+            effects: &[Effect::Stat(Stat::Luck, 10.0)],
+        },
+        ItemSetDef {
+            id: "primordial_collection",
+            name: "Primordial Collection",
+            description: "+50% GP from loot",
+            members: SetMembers::Chest(ChestType::Primordial),
+            effects: &[Effect::LootGp(0.5)],
+        },
+    ]
+}
+
+pub fn get_item_set(id: &str) -> Option<&'static ItemSetDef> {
+    all_item_sets().iter().find(|s| s.id == id)
+}
@@ -1,5 +1,10 @@
 use serde::{Deserialize, Serialize};
 
+use super::effects::{Effect, Stat, UNCOMMON_PLUS};
+use super::pack::{self, leak};
+use crate::game::events::Proc;
+use crate::game::item::Rarity;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SkillBranch {
     Fortune,
@@ -43,451 +48,535 @@ impl SkillBranch {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SkillDef {
+    #[serde(deserialize_with = "leak::str")]
     pub id: &'static str,
+    #[serde(deserialize_with = "leak::str")]
     pub name: &'static str,
+    #[serde(deserialize_with = "leak::str")]
     pub description: &'static str,
     pub branch: SkillBranch,
+    #[serde(default, deserialize_with = "leak::strs")]
     pub prerequisites: &'static [&'static str],
     pub cost: u32, // Skill points required
+    #[serde(default, deserialize_with = "leak::slice")]
+    pub effects: &'static [Effect],
 }
 
 pub fn all_skills() -> &'static [SkillDef] {
-    use std::sync::LazyLock;
-    static SKILLS: LazyLock<Vec<SkillDef>> = LazyLock::new(|| {
-        vec![
-            // === Fortune Branch (10) ===
-            // Left path: Luck & Procs
-            SkillDef {
-                id: "lucky_charm",
-                name: "Lucky Charm",
-                description: "+5 luck, +10% GP",
-                branch: SkillBranch::Fortune,
-                prerequisites: &[],
-                cost: 1,
-            },
-            SkillDef {
-                id: "golden_touch",
-                name: "Golden Touch",
-                description: "25% chance for double GP from a chest",
-                branch: SkillBranch::Fortune,
-                prerequisites: &["lucky_charm"],
-                cost: 1,
-            },
-            SkillDef {
-                id: "windfall",
-                name: "Windfall",
-                description: "3% chance for 10x GP on any chest",
-                branch: SkillBranch::Fortune,
-                prerequisites: &["golden_touch"],
-                cost: 2,
-            },
-            SkillDef {
-                id: "fortune_favors",
-                name: "Fortune Favors",
-                description: "+15 luck, +25% GP",
-                branch: SkillBranch::Fortune,
-                prerequisites: &["windfall"],
-                cost: 3,
-            },
-            SkillDef {
-                id: "lucky_streak",
-                name: "Lucky Streak",
-                description: "Consecutive rare+ finds give +20% GP each",
-                branch: SkillBranch::Fortune,
-                prerequisites: &["fortune_favors"],
-                cost: 3,
-            },
-            // Right path: Raw GP Power
-            SkillDef {
-                id: "treasure_sense",
-                name: "Treasure Sense",
-                description: "+50% GP from all sources",
-                branch: SkillBranch::Fortune,
-                prerequisites: &["lucky_charm"],
-                cost: 1,
-            },
-            SkillDef {
-                id: "double_or_nothing",
-                name: "Double or Nothing",
-                description: "30% double loot, 5% nothing",
-                branch: SkillBranch::Fortune,
-                prerequisites: &["treasure_sense"],
-                cost: 2,
-            },
-            SkillDef {
-                id: "high_roller",
-                name: "High Roller",
-                description: "+15% GP per chest tier above Wooden",
-                branch: SkillBranch::Fortune,
-                prerequisites: &["double_or_nothing"],
-                cost: 2,
-            },
-            SkillDef {
-                id: "gold_rush",
-                name: "Gold Rush",
-                description: "10% chance for bonus GP equal to item value",
-                branch: SkillBranch::Fortune,
-                prerequisites: &["high_roller"],
-                cost: 3,
-            },
-            // Capstone
-            SkillDef {
-                id: "golden_rain",
-                name: "Golden Rain",
-                description: "Capstone: +80% GP, Windfall chance doubled to 6%",
-                branch: SkillBranch::Fortune,
-                prerequisites: &["lucky_streak", "gold_rush"],
-                cost: 5,
-            },
-            // === Speed Branch (10) ===
-            SkillDef {
-                id: "swift_hands",
-                name: "Swift Hands",
-                description: "+30% chest speed",
-                branch: SkillBranch::Speed,
-                prerequisites: &[],
-                cost: 1,
-            },
-            SkillDef {
-                id: "nimble_fingers",
-                name: "Nimble Fingers",
-                description: "+25% chest speed (stacks)",
-                branch: SkillBranch::Speed,
-                prerequisites: &["swift_hands"],
-                cost: 1,
-            },
-            SkillDef {
-                id: "burst_open",
-                name: "Burst Open",
-                description: "15% chance to instantly open a chest",
-                branch: SkillBranch::Speed,
-                prerequisites: &["nimble_fingers"],
-                cost: 2,
-            },
-            SkillDef {
-                id: "quick_collect",
-                name: "Quick Collect",
-                description: "Auto-collect loot after 0.8 seconds",
-                branch: SkillBranch::Speed,
-                prerequisites: &["burst_open"],
-                cost: 2,
-            },
-            SkillDef {
-                id: "auto_opener",
-                name: "Auto Opener",
-                description: "Chests open and collect automatically",
-                branch: SkillBranch::Speed,
-                prerequisites: &["swift_hands"],
-                cost: 1,
-            },
-            SkillDef {
-                id: "idle_income",
-                name: "Idle Income",
-                description: "Earn GP/sec while idle (scales with level & GP mult)",
-                branch: SkillBranch::Speed,
-                prerequisites: &["auto_opener"],
-                cost: 2,
-            },
-            SkillDef {
-                id: "perpetual_motion",
-                name: "Perpetual Motion",
-                description: "Auto-collect delay halved (1s instead of 2s)",
-                branch: SkillBranch::Speed,
-                prerequisites: &["idle_income"],
-                cost: 2,
-            },
-            // Speed extended
-            SkillDef {
-                id: "time_warp",
-                name: "Time Warp",
-                description: "+50% chest speed",
-                branch: SkillBranch::Speed,
-                prerequisites: &["quick_collect"],
-                cost: 3,
-            },
-            SkillDef {
-                id: "momentum",
-                name: "Momentum",
-                description: "Each consecutive chest opens 3% faster (max 60%)",
-                branch: SkillBranch::Speed,
-                prerequisites: &["time_warp"],
-                cost: 3,
-            },
-            SkillDef {
-                id: "temporal_mastery",
-                name: "Temporal Mastery",
-                description: "Capstone: +75% speed, idle income x4",
-                branch: SkillBranch::Speed,
-                prerequisites: &["momentum", "perpetual_motion"],
-                cost: 5,
-            },
-            // === Mastery Branch (10) ===
-            SkillDef {
-                id: "critical_eye",
-                name: "Critical Eye",
-                description: "+5% crit chance",
-                branch: SkillBranch::Mastery,
-                prerequisites: &[],
-                cost: 1,
-            },
-            SkillDef {
-                id: "crit_cascade",
-                name: "Crit Cascade",
-                description: "Crits have 25% chance to trigger another",
-                branch: SkillBranch::Mastery,
-                prerequisites: &["critical_eye"],
-                cost: 1,
-            },
-            SkillDef {
-                id: "multi_drop",
-                name: "Multi-Drop",
-                description: "10% chance to find 2 items from one chest",
-                branch: SkillBranch::Mastery,
-                prerequisites: &["crit_cascade"],
-                cost: 2,
-            },
-            SkillDef {
-                id: "legendary_aura",
-                name: "Legendary Aura",
-                description: "Legendary items give 3x XP",
-                branch: SkillBranch::Mastery,
-                prerequisites: &["multi_drop"],
-                cost: 2,
-            },
-            SkillDef {
-                id: "overcharge",
-                name: "Overcharge",
-                description: "Crit multiplier: 2x \u{2192} 3x",
-                branch: SkillBranch::Mastery,
-                prerequisites: &["critical_eye"],
-                cost: 1,
-            },
-            SkillDef {
-                id: "xp_surge",
-                name: "XP Surge",
-                description: "Every 5th chest gives 5x XP",
-                branch: SkillBranch::Mastery,
-                prerequisites: &["overcharge"],
-                cost: 2,
-            },
-            SkillDef {
-                id: "midas_touch",
-                name: "Midas Touch",
-                description: "+100% base GP on items",
-                branch: SkillBranch::Mastery,
-                prerequisites: &["xp_surge"],
-                cost: 2,
-            },
-            // Mastery extended
-            SkillDef {
-                id: "precision_strike",
-                name: "Precision Strike",
-                description: "+10% crit chance, crits give +50% XP",
-                branch: SkillBranch::Mastery,
-                prerequisites: &["legendary_aura"],
-                cost: 3,
-            },
-            SkillDef {
-                id: "deep_knowledge",
-                name: "Deep Knowledge",
-                description: "+100% XP from all sources",
-                branch: SkillBranch::Mastery,
-                prerequisites: &["precision_strike"],
-                cost: 3,
-            },
-            SkillDef {
-                id: "grand_mastery",
-                name: "Grand Mastery",
-                description: "Capstone: crit mult +2x, +25% all multipliers",
-                branch: SkillBranch::Mastery,
-                prerequisites: &["deep_knowledge", "midas_touch"],
-                cost: 5,
-            },
-            // === Discovery Branch (6) ===
-            SkillDef {
-                id: "scavenger",
-                name: "Scavenger",
-                description: "5% chance for a bonus Common item",
-                branch: SkillBranch::Discovery,
-                prerequisites: &[],
-                cost: 1,
-            },
-            SkillDef {
-                id: "relic_hunter",
-                name: "Relic Hunter",
-                description: "Double relic drop chance",
-                branch: SkillBranch::Discovery,
-                prerequisites: &["scavenger"],
-                cost: 2,
-            },
-            SkillDef {
-                id: "recycler",
-                name: "Recycler",
-                description: "Auto-sell Common items for GP",
-                branch: SkillBranch::Discovery,
-                prerequisites: &["relic_hunter"],
-                cost: 2,
-            },
-            // Discovery extended
-            SkillDef {
-                id: "cartographer",
-                name: "Cartographer",
-                description: "Unlock chests 3 levels earlier",
-                branch: SkillBranch::Discovery,
-                prerequisites: &["recycler"],
-                cost: 3,
-            },
-            SkillDef {
-                id: "deep_salvage",
-                name: "Deep Salvage",
-                description: "Recycled items give 3x GP, +10% relic drop",
-                branch: SkillBranch::Discovery,
-                prerequisites: &["cartographer"],
-                cost: 3,
-            },
-            SkillDef {
-                id: "world_explorer",
-                name: "World Explorer",
-                description: "Capstone: +50% all drop rates, +1 relic slot",
-                branch: SkillBranch::Discovery,
-                prerequisites: &["deep_salvage", "recycler"],
-                cost: 5,
-            },
-            // === Alchemy Branch (8) ===
-            SkillDef {
-                id: "transmute_basics",
-                name: "Transmute Basics",
-                description: "Sell items from inventory for 50% GP value",
-                branch: SkillBranch::Alchemy,
-                prerequisites: &[],
-                cost: 1,
-            },
-            SkillDef {
-                id: "gold_synthesis",
-                name: "Gold Synthesis",
-                description: "Sell value increased to 75%",
-                branch: SkillBranch::Alchemy,
-                prerequisites: &["transmute_basics"],
-                cost: 1,
-            },
-            SkillDef {
-                id: "essence_distill",
-                name: "Essence Distill",
-                description: "Selling items grants +10% XP of GP value",
-                branch: SkillBranch::Alchemy,
-                prerequisites: &["gold_synthesis"],
-                cost: 2,
-            },
-            SkillDef {
-                id: "philosophers_stone",
-                name: "Philosopher's Stone",
-                description: "Sell value 100%, rare+ items give 150%",
-                branch: SkillBranch::Alchemy,
-                prerequisites: &["essence_distill"],
-                cost: 2,
-            },
-            SkillDef {
-                id: "material_insight",
-                name: "Material Insight",
-                description: "+20% GP from Uncommon+ items",
-                branch: SkillBranch::Alchemy,
-                prerequisites: &["transmute_basics"],
-                cost: 3,
-            },
-            SkillDef {
-                id: "catalyst_brew",
-                name: "Catalyst Brew",
-                description: "Each sell stacks +1% GP bonus (resets on rebirth)",
-                branch: SkillBranch::Alchemy,
-                prerequisites: &["material_insight"],
-                cost: 3,
-            },
-            SkillDef {
-                id: "elixir_of_fortune",
-                name: "Elixir of Fortune",
-                description: "+5 luck, selling has 10% chance to double GP",
-                branch: SkillBranch::Alchemy,
-                prerequisites: &["catalyst_brew"],
-                cost: 2,
-            },
-            SkillDef {
-                id: "magnum_opus",
-                name: "Magnum Opus",
-                description: "Capstone: all sell bonuses doubled, +50% GP",
-                branch: SkillBranch::Alchemy,
-                prerequisites: &["philosophers_stone", "elixir_of_fortune"],
-                cost: 5,
-            },
-            // === Chaos Branch (8) ===
-            SkillDef {
-                id: "entropy",
-                name: "Entropy",
-                description: "Loot values vary \u{00b1}30% randomly",
-                branch: SkillBranch::Chaos,
-                prerequisites: &[],
-                cost: 1,
-            },
-            SkillDef {
-                id: "wild_magic",
-                name: "Wild Magic",
-                description: "5% chance for item to upgrade 1 rarity tier",
-                branch: SkillBranch::Chaos,
-                prerequisites: &["entropy"],
-                cost: 1,
-            },
-            SkillDef {
-                id: "chaos_crit",
-                name: "Chaos Crit",
-                description: "Crits deal 1x-5x randomly instead of fixed",
-                branch: SkillBranch::Chaos,
-                prerequisites: &["wild_magic"],
-                cost: 2,
-            },
-            SkillDef {
-                id: "reality_tear",
-                name: "Reality Tear",
-                description: "1% chance for 20x GP on any chest",
-                branch: SkillBranch::Chaos,
-                prerequisites: &["chaos_crit"],
-                cost: 2,
-            },
-            SkillDef {
-                id: "gambler_spirit",
-                name: "Gambler's Spirit",
-                description: "After 3 bad rolls, next is guaranteed Rare+",
-                branch: SkillBranch::Chaos,
-                prerequisites: &["entropy"],
-                cost: 2,
-            },
-            SkillDef {
-                id: "chaos_surge",
-                name: "Chaos Surge",
-                description: "Random buff each chest: +50% GP, XP, or Speed for 10s",
-                branch: SkillBranch::Chaos,
-                prerequisites: &["gambler_spirit"],
-                cost: 3,
-            },
-            SkillDef {
-                id: "pandemonium",
-                name: "Pandemonium",
-                description: "All chaos effects trigger 2x more often",
-                branch: SkillBranch::Chaos,
-                prerequisites: &["chaos_surge"],
-                cost: 3,
-            },
-            SkillDef {
-                id: "singularity",
-                name: "Singularity",
-                description: "Capstone: 3% chance to triple all loot values",
-                branch: SkillBranch::Chaos,
-                prerequisites: &["reality_tear", "pandemonium"],
-                cost: 5,
-            },
-        ]
-    });
-    SKILLS.as_slice()
+    &pack::current().skills
+}
+
+/// The skills shipped with the game.
+pub fn builtin_skills() -> Vec<SkillDef> {
+    vec![
+        // === Fortune Branch (10) ===
+        // Left path: Luck & Procs
+        SkillDef {
+            id: "lucky_charm",
+            name: "Lucky Charm",
+            description: "+5 luck, +10% GP",
+            branch: SkillBranch::Fortune,
+            prerequisites: &[],
+            cost: 1,
+            effects: &[Effect::Stat(Stat::Luck, 5.0), Effect::Stat(Stat::GpMult, 0.10)],
+        },
+        SkillDef {
+            id: "golden_touch",
+            name: "Golden Touch",
+            description: "25% chance for double GP from a chest",
+            branch: SkillBranch::Fortune,
+            prerequisites: &["lucky_charm"],
+            cost: 1,
+            effects: &[
+                Effect::Jackpot { proc: None, chance: 0.25, gp: 2.0, xp: 1.0, chaotic: false },
+            ],
+        },
+        SkillDef {
+            id: "windfall",
+            name: "Windfall",
+            description: "3% chance for 10x GP on any chest",
+            branch: SkillBranch::Fortune,
+            prerequisites: &["golden_touch"],
+            cost: 2,
+            effects: &[
+                Effect::Jackpot { proc: Some(Proc::Windfall), chance: 0.03, gp: 10.0, xp: 1.0, chaotic: false },
+            ],
+        },
+        SkillDef {
+            id: "fortune_favors",
+            name: "Fortune Favors",
+            description: "+15 luck, +25% GP",
+            branch: SkillBranch::Fortune,
+            prerequisites: &["windfall"],
+            cost: 3,
+            effects: &[Effect::Stat(Stat::Luck, 15.0), Effect::LootGp(0.25)],
+        },
+        SkillDef {
+            id: "lucky_streak",
+            name: "Lucky Streak",
+            description: "Consecutive rare+ finds give +20% GP each",
+            branch: SkillBranch::Fortune,
+            prerequisites: &["fortune_favors"],
+            cost: 3,
+            effects: &[Effect::RareStreakGp(0.20)],
+        },
+        // Right path: Raw GP Power
+        SkillDef {
+            id: "treasure_sense",
+            name: "Treasure Sense",
+            description: "+50% GP from all sources",
+            branch: SkillBranch::Fortune,
+            prerequisites: &["lucky_charm"],
+            cost: 1,
+            effects: &[Effect::Stat(Stat::GpMult, 0.50)],
+        },
+        SkillDef {
+            id: "double_or_nothing",
+            name: "Double or Nothing",
+            description: "30% double loot, 5% nothing",
+            branch: SkillBranch::Fortune,
+            prerequisites: &["treasure_sense"],
+            cost: 2,
+            effects: &[Effect::DoubleOrNothing { double: 0.30, nothing: 0.05 }],
+        },
+        SkillDef {
+            id: "high_roller",
+            name: "High Roller",
+            description: "+15% GP per chest tier above Wooden",
+            branch: SkillBranch::Fortune,
+            prerequisites: &["double_or_nothing"],
+            cost: 2,
+            effects: &[Effect::ChestTierGp(0.15)],
+        },
+        SkillDef {
+            id: "gold_rush",
+            name: "Gold Rush",
+            description: "10% chance for bonus GP equal to item value",
+            branch: SkillBranch::Fortune,
+            prerequisites: &["high_roller"],
+            cost: 3,
+            effects: &[
+                Effect::Jackpot { proc: Some(Proc::GoldRush), chance: 0.10, gp: 2.0, xp: 1.0, chaotic: false },
+            ],
+        },
+        // Capstone
+        SkillDef {
+            id: "golden_rain",
+            name: "Golden Rain",
+            description: "Capstone: +80% GP, Windfall chance doubled to 6%",
+            branch: SkillBranch::Fortune,
+            prerequisites: &["lucky_streak", "gold_rush"],
+            cost: 5,
+            effects: &[
+                Effect::LootGp(0.80),
+                Effect::JackpotChance { proc: Proc::Windfall, bonus: 0.03 },
+            ],
+        },
+        // === Speed Branch (10) ===
+        SkillDef {
+            id: "swift_hands",
+            name: "Swift Hands",
+            description: "+30% chest speed",
+            branch: SkillBranch::Speed,
+            prerequisites: &[],
+            cost: 1,
+            effects: &[Effect::Stat(Stat::Speed, 0.30)],
+        },
+        SkillDef {
+            id: "nimble_fingers",
+            name: "Nimble Fingers",
+            description: "+25% chest speed (stacks)",
+            branch: SkillBranch::Speed,
+            prerequisites: &["swift_hands"],
+            cost: 1,
+            effects: &[Effect::Stat(Stat::Speed, 0.25)],
+        },
+        SkillDef {
+            id: "burst_open",
+            name: "Burst Open",
+            description: "15% chance to instantly open a chest",
+            branch: SkillBranch::Speed,
+            prerequisites: &["nimble_fingers"],
+            cost: 2,
+            effects: &[Effect::InstantOpen(0.15)],
+        },
+        SkillDef {
+            id: "quick_collect",
+            name: "Quick Collect",
+            description: "Auto-collect loot after 0.8 seconds",
+            branch: SkillBranch::Speed,
+            prerequisites: &["burst_open"],
+            cost: 2,
+            effects: &[Effect::AutoCollect { after_ticks: 30 }],
+        },
+        SkillDef {
+            id: "auto_opener",
+            name: "Auto Opener",
+            description: "Chests open and collect automatically",
+            branch: SkillBranch::Speed,
+            prerequisites: &["swift_hands"],
+            cost: 1,
+            effects: &[Effect::AutoOpen],
+        },
+        SkillDef {
+            id: "idle_income",
+            name: "Idle Income",
+            description: "Earn GP/sec while idle (scales with level & GP mult)",
+            branch: SkillBranch::Speed,
+            prerequisites: &["auto_opener"],
+            cost: 2,
+            effects: &[Effect::IdleIncome { every_ticks: 15 }],
+        },
+        SkillDef {
+            id: "perpetual_motion",
+            name: "Perpetual Motion",
+            description: "Auto-collect delay halved (1s instead of 2s)",
+            branch: SkillBranch::Speed,
+            prerequisites: &["idle_income"],
+            cost: 2,
+            effects: &[Effect::AutoCollect { after_ticks: 30 }],
+        },
+        // Speed extended
+        SkillDef {
+            id: "time_warp",
+            name: "Time Warp",
+            description: "+50% chest speed",
+            branch: SkillBranch::Speed,
+            prerequisites: &["quick_collect"],
+            cost: 3,
+            effects: &[Effect::Stat(Stat::Speed, 0.50)],
+        },
+        SkillDef {
+            id: "momentum",
+            name: "Momentum",
+            description: "Each consecutive chest opens 3% faster (max 60%)",
+            branch: SkillBranch::Speed,
+            prerequisites: &["time_warp"],
+            cost: 3,
+            effects: &[Effect::Momentum { per_chest: 0.03, max: 0.60 }],
+        },
+        SkillDef {
+            id: "temporal_mastery",
+            name: "Temporal Mastery",
+            description: "Capstone: +75% speed, idle income x4",
+            branch: SkillBranch::Speed,
+            prerequisites: &["momentum", "perpetual_motion"],
+            cost: 5,
+            effects: &[Effect::Stat(Stat::Speed, 0.75), Effect::IdleIncome { every_ticks: 4 }],
+        },
+        // === Mastery Branch (10) ===
+        SkillDef {
+            id: "critical_eye",
+            name: "Critical Eye",
+            description: "+5% crit chance",
+            branch: SkillBranch::Mastery,
+            prerequisites: &[],
+            cost: 1,
+            effects: &[Effect::Stat(Stat::CritChance, 0.05)],
+        },
+        SkillDef {
+            id: "crit_cascade",
+            name: "Crit Cascade",
+            description: "Crits have 25% chance to trigger another",
+            branch: SkillBranch::Mastery,
+            prerequisites: &["critical_eye"],
+            cost: 1,
+            effects: &[Effect::CritCascade(0.30)],
+        },
+        SkillDef {
+            id: "multi_drop",
+            name: "Multi-Drop",
+            description: "10% chance to find 2 items from one chest",
+            branch: SkillBranch::Mastery,
+            prerequisites: &["crit_cascade"],
+            cost: 2,
+            effects: &[Effect::MultiDrop(0.15)],
+        },
+        SkillDef {
+            id: "legendary_aura",
+            name: "Legendary Aura",
+            description: "Legendary items give 3x XP",
+            branch: SkillBranch::Mastery,
+            prerequisites: &["multi_drop"],
+            cost: 2,
+            effects: &[Effect::RarityXp { rarities: &[Rarity::Legendary], bonus: 2.0 }],
+        },
+        SkillDef {
+            id: "overcharge",
+            name: "Overcharge",
+            description: "Crit multiplier: 2x \u{2192} 3x",
+            branch: SkillBranch::Mastery,
+            prerequisites: &["critical_eye"],
+            cost: 1,
+            effects: &[Effect::BaseCritMult(1.0)],
+        },
+        SkillDef {
+            id: "xp_surge",
+            name: "XP Surge",
+            description: "Every 5th chest gives 5x XP",
+            branch: SkillBranch::Mastery,
+            prerequisites: &["overcharge"],
+            cost: 2,
+            effects: &[Effect::XpSurge { every: 5, mult: 5.0 }],
+        },
+        SkillDef {
+            id: "midas_touch",
+            name: "Midas Touch",
+            description: "+100% base GP on items",
+            branch: SkillBranch::Mastery,
+            prerequisites: &["xp_surge"],
+            cost: 2,
+            effects: &[Effect::BaseGp(1.0)],
+        },
+        // Mastery extended
+        SkillDef {
+            id: "precision_strike",
+            name: "Precision Strike",
+            description: "+10% crit chance, crits give +50% XP",
+            branch: SkillBranch::Mastery,
+            prerequisites: &["legendary_aura"],
+            cost: 3,
+            effects: &[Effect::Stat(Stat::CritChance, 0.12), Effect::CritXp(0.50)],
+        },
+        SkillDef {
+            id: "deep_knowledge",
+            name: "Deep Knowledge",
+            description: "+100% XP from all sources",
+            branch: SkillBranch::Mastery,
+            prerequisites: &["precision_strike"],
+            cost: 3,
+            effects: &[Effect::LootXp(1.0)],
+        },
+        SkillDef {
+            id: "grand_mastery",
+            name: "Grand Mastery",
+            description: "Capstone: crit mult +2x, +25% all multipliers",
+            branch: SkillBranch::Mastery,
+            prerequisites: &["deep_knowledge", "midas_touch"],
+            cost: 5,
+            effects: &[Effect::BaseCritMult(3.0), Effect::LootGp(0.40), Effect::LootXp(0.40)],
+        },
+        // === Discovery Branch (6) ===
+        SkillDef {
+            id: "scavenger",
+            name: "Scavenger",
+            description: "5% chance for a bonus Common item",
+            branch: SkillBranch::Discovery,
+            prerequisites: &[],
+            cost: 1,
+            effects: &[Effect::Scavenge(0.05)],
+        },
+        SkillDef {
+            id: "relic_hunter",
+            name: "Relic Hunter",
+            description: "Double relic drop chance",
+            branch: SkillBranch::Discovery,
+            prerequisites: &["scavenger"],
+            cost: 2,
+            effects: &[Effect::RelicDropMult(1.0)],
+        },
+        SkillDef {
+            id: "recycler",
+            name: "Recycler",
+            description: "Auto-sell Common items for GP",
+            branch: SkillBranch::Discovery,
+            prerequisites: &["relic_hunter"],
+            cost: 2,
+            effects: &[Effect::Recycle(Rarity::Common)],
+        },
+        // Discovery extended
+        SkillDef {
+            id: "cartographer",
+            name: "Cartographer",
+            description: "Unlock chests 3 levels earlier",
+            branch: SkillBranch::Discovery,
+            prerequisites: &["recycler"],
+            cost: 3,
+            effects: &[Effect::ChestLevelReduction(3)],
+        },
+        SkillDef {
+            id: "deep_salvage",
+            name: "Deep Salvage",
+            description: "Recycled items give 3x GP, +10% relic drop",
+            branch: SkillBranch::Discovery,
+            prerequisites: &["cartographer"],
+            cost: 3,
+            effects: &[Effect::RecycleGp(2.0), Effect::RelicDrop(0.10)],
+        },
+        SkillDef {
+            id: "world_explorer",
+            name: "World Explorer",
+            description: "Capstone: +50% all drop rates, +1 relic slot",
+            branch: SkillBranch::Discovery,
+            prerequisites: &["deep_salvage", "recycler"],
+            cost: 5,
+            effects: &[Effect::DropRates(0.50), Effect::RelicSlots(1)],
+        },
+        // === Alchemy Branch (9) ===
+        SkillDef {
+            id: "transmute_basics",
+            name: "Transmute Basics",
+            description: "Sell items from inventory for 50% GP value, +5% craft success",
+            branch: SkillBranch::Alchemy,
+            prerequisites: &[],
+            cost: 1,
+            effects: &[Effect::SellPct(0.50), Effect::CraftChance(0.05)],
+        },
+        SkillDef {
+            id: "gold_synthesis",
+            name: "Gold Synthesis",
+            description: "Sell value increased to 75%, +5% craft success",
+            branch: SkillBranch::Alchemy,
+            prerequisites: &["transmute_basics"],
+            cost: 1,
+            effects: &[Effect::SellPct(0.75), Effect::CraftChance(0.05)],
+        },
+        SkillDef {
+            id: "alchemical_sieve",
+            name: "Alchemical Sieve",
+            description: "Filter new drops: keep, sell or salvage by rarity, item or value",
+            branch: SkillBranch::Alchemy,
+            prerequisites: &["gold_synthesis"],
+            cost: 1,
+            effects: &[Effect::LootFilter],
+        },
+        SkillDef {
+            id: "essence_distill",
+            name: "Essence Distill",
+            description: "Selling items grants +10% XP of GP value",
+            branch: SkillBranch::Alchemy,
+            prerequisites: &["gold_synthesis"],
+            cost: 2,
+            effects: &[Effect::SellXp(0.10)],
+        },
+        SkillDef {
+            id: "philosophers_stone",
+            name: "Philosopher's Stone",
+            description: "Sell value 100%, rare+ items give 150%, +10% craft success",
+            branch: SkillBranch::Alchemy,
+            prerequisites: &["essence_distill"],
+            cost: 2,
+            effects: &[Effect::SellPct(1.0), Effect::RareSellPct(1.5), Effect::CraftChance(0.10)],
+        },
+        SkillDef {
+            id: "material_insight",
+            name: "Material Insight",
+            description: "+20% GP from Uncommon+ items",
+            branch: SkillBranch::Alchemy,
+            prerequisites: &["transmute_basics"],
+            cost: 3,
+            effects: &[Effect::RarityGp { rarities: UNCOMMON_PLUS, bonus: 0.20 }],
+        },
+        SkillDef {
+            id: "catalyst_brew",
+            name: "Catalyst Brew",
+            description: "Each sell stacks +1% GP bonus (resets on rebirth)",
+            branch: SkillBranch::Alchemy,
+            prerequisites: &["material_insight"],
+            cost: 3,
+            effects: &[Effect::SellStackGp(0.01)],
+        },
+        SkillDef {
+            id: "elixir_of_fortune",
+            name: "Elixir of Fortune",
+            description: "+5 luck, selling has 10% chance to double GP",
+            branch: SkillBranch::Alchemy,
+            prerequisites: &["catalyst_brew"],
+            cost: 2,
+            effects: &[
+                Effect::Stat(Stat::Luck, 5.0),
+                Effect::SellJackpot { proc: Proc::ElixirOfFortune, chance: 0.10, mult: 2.0 },
+            ],
+        },
+        SkillDef {
+            id: "magnum_opus",
+            name: "Magnum Opus",
+            description: "Capstone: all sell bonuses doubled, +50% GP",
+            branch: SkillBranch::Alchemy,
+            prerequisites: &["philosophers_stone", "elixir_of_fortune"],
+            cost: 5,
+            effects: &[Effect::SellMult(1.0), Effect::LootGp(0.50)],
+        },
+        // === Chaos Branch (8) ===
+        SkillDef {
+            id: "entropy",
+            name: "Entropy",
+            description: "Loot values vary \u{00b1}30% randomly",
+            branch: SkillBranch::Chaos,
+            prerequisites: &[],
+            cost: 1,
+            effects: &[Effect::Variance(0.30)],
+        },
+        SkillDef {
+            id: "wild_magic",
+            name: "Wild Magic",
+            description: "5% chance for item to upgrade 1 rarity tier",
+            branch: SkillBranch::Chaos,
+            prerequisites: &["entropy"],
+            cost: 1,
+            effects: &[Effect::RarityUpgrade { chance: 0.05, chaotic: true }],
+        },
+        SkillDef {
+            id: "chaos_crit",
+            name: "Chaos Crit",
+            description: "Crits deal 1x-5x randomly instead of fixed",
+            branch: SkillBranch::Chaos,
+            prerequisites: &["wild_magic"],
+            cost: 2,
+            effects: &[Effect::ChaoticCrits { min: 1.0, max: 5.0 }],
+        },
+        SkillDef {
+            id: "reality_tear",
+            name: "Reality Tear",
+            description: "1% chance for 20x GP on any chest",
+            branch: SkillBranch::Chaos,
+            prerequisites: &["chaos_crit"],
+            cost: 2,
+            effects: &[
+                Effect::Jackpot { proc: Some(Proc::RealityTear), chance: 0.01, gp: 20.0, xp: 1.0, chaotic: true },
+            ],
+        },
+        SkillDef {
+            id: "gambler_spirit",
+            name: "Gambler's Spirit",
+            description: "After 3 bad rolls, next is guaranteed Rare+",
+            branch: SkillBranch::Chaos,
+            prerequisites: &["entropy"],
+            cost: 2,
+            effects: &[Effect::PityRare { after: 3 }],
+        },
+        SkillDef {
+            id: "chaos_surge",
+            name: "Chaos Surge",
+            description: "Random buff each chest: +50% GP, XP, or Speed for 10s",
+            branch: SkillBranch::Chaos,
+            prerequisites: &["gambler_spirit"],
+            cost: 3,
+            effects: &[Effect::ChaosSurge { ticks: 300, bonus: 0.50 }],
+        },
+        SkillDef {
+            id: "pandemonium",
+            name: "Pandemonium",
+            description: "All chaos effects trigger 2x more often",
+            branch: SkillBranch::Chaos,
+            prerequisites: &["chaos_surge"],
+            cost: 3,
+            effects: &[Effect::ChaosAmplifier(1.0)],
+        },
+        SkillDef {
+            id: "singularity",
+            name: "Singularity",
+            description: "Capstone: 3% chance to triple all loot values",
+            branch: SkillBranch::Chaos,
+            prerequisites: &["reality_tear", "pandemonium"],
+            cost: 5,
+            effects: &[
+                Effect::Jackpot { proc: Some(Proc::Singularity), chance: 0.03, gp: 3.0, xp: 3.0, chaotic: true },
+            ],
+        },
+    ]
 }
 
 pub fn get_skill(id: &str) -> Option<&'static SkillDef> {
@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+
+use super::effects::{Effect, Stat};
+use super::pack::{self, leak};
+use crate::game::chest::ChestType;
+
+/// A skill bought with Aether. Survives rebirth and transcendence.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscendenceSkillDef {
+    #[serde(deserialize_with = "leak::str")]
+    pub id: &'static str,
+    #[serde(deserialize_with = "leak::str")]
+    pub name: &'static str,
+    #[serde(deserialize_with = "leak::str")]
+    pub description: &'static str,
+    pub aether_cost: u64,
+    #[serde(default, deserialize_with = "leak::strs")]
+    pub prerequisites: &'static [&'static str],
+    #[serde(default, deserialize_with = "leak::slice")]
+    pub effects: &'static [Effect],
+}
+
+pub fn all_transcendence_skills() -> &'static [TranscendenceSkillDef] {
+    &pack::current().transcendence_skills
+}
+
+pub fn get_transcendence_skill(id: &str) -> Option<&'static TranscendenceSkillDef> {
+    all_transcendence_skills().iter().find(|s| s.id == id)
+}
+
+/// The transcendence skills shipped with the game.
+pub fn builtin_transcendence_skills() -> Vec<TranscendenceSkillDef> {
+    vec![
+        // === Chests ===
+        TranscendenceSkillDef {
+            id: "ts_crystal_dawn",
+            name: "Crystal Dawn",
+            description: "Start every run with Crystal chests",
+            aether_cost: 1,
+            prerequisites: &[],
+            effects: &[Effect::StartChests(&[ChestType::Crystal])],
+        },
+        TranscendenceSkillDef {
+            id: "ts_shadow_dawn",
+            name: "Shadow Dawn",
+            description: "Start every run with Shadow chests",
+            aether_cost: 3,
+            prerequisites: &["ts_crystal_dawn"],
+            effects: &[Effect::StartChests(&[ChestType::Shadow])],
+        },
+        TranscendenceSkillDef {
+            id: "ts_void_dawn",
+            name: "Void Dawn",
+            description: "Start every run with Void chests",
+            aether_cost: 8,
+            prerequisites: &["ts_shadow_dawn"],
+            effects: &[Effect::StartChests(&[ChestType::Void])],
+        },
+        TranscendenceSkillDef {
+            id: "ts_celestial_key",
+            name: "Celestial Key",
+            description: "Unlock Celestial chests (requires level 60)",
+            aether_cost: 12,
+            prerequisites: &["ts_void_dawn"],
+            effects: &[Effect::ChestKey { chest: ChestType::Celestial, min_level: 60 }],
+        },
+        TranscendenceSkillDef {
+            id: "ts_primordial_key",
+            name: "Primordial Key",
+            description: "Unlock Primordial chests (requires level 75)",
+            aether_cost: 30,
+            prerequisites: &["ts_celestial_key"],
+            effects: &[Effect::ChestKey { chest: ChestType::Primordial, min_level: 75 }],
+        },
+        // === Relics ===
+        TranscendenceSkillDef {
+            id: "ts_relic_harness",
+            name: "Relic Harness",
+            description: "+1 relic slot",
+            aether_cost: 2,
+            prerequisites: &[],
+            effects: &[Effect::RelicSlots(1)],
+        },
+        TranscendenceSkillDef {
+            id: "ts_relic_mantle",
+            name: "Relic Mantle",
+            description: "+1 relic slot",
+            aether_cost: 6,
+            prerequisites: &["ts_relic_harness"],
+            effects: &[Effect::RelicSlots(1)],
+        },
+        // === Essence ===
+        TranscendenceSkillDef {
+            id: "ts_deep_wells",
+            name: "Deep Wells",
+            description: "Essence formula exponent +0.02",
+            aether_cost: 2,
+            prerequisites: &[],
+            effects: &[Effect::EssenceExponent(0.02)],
+        },
+        TranscendenceSkillDef {
+            id: "ts_bottomless_wells",
+            name: "Bottomless Wells",
+            description: "Essence formula exponent +0.03",
+            aether_cost: 6,
+            prerequisites: &["ts_deep_wells"],
+            effects: &[Effect::EssenceExponent(0.03)],
+        },
+        // === Stats ===
+        TranscendenceSkillDef {
+            id: "ts_eternal_fortune",
+            name: "Eternal Fortune",
+            description: "+10 base luck",
+            aether_cost: 1,
+            prerequisites: &[],
+            effects: &[Effect::BaseStat(Stat::Luck, 10.0)],
+        },
+        TranscendenceSkillDef {
+            id: "ts_eternal_glory",
+            name: "Eternal Glory",
+            description: "All base stats +25%",
+            aether_cost: 5,
+            prerequisites: &["ts_eternal_fortune"],
+            effects: &[Effect::BaseStatsMult(0.25)],
+        },
+    ]
+}
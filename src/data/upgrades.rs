@@ -1,15 +1,27 @@
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+use super::effects::{Effect, Stat};
+use super::pack::{self, leak};
+use crate::game::chest::ChestType;
+use crate::game::item::Rarity;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpgradeDef {
+    #[serde(deserialize_with = "leak::str")]
     pub id: &'static str,
+    #[serde(deserialize_with = "leak::str")]
     pub name: &'static str,
+    #[serde(deserialize_with = "leak::str")]
     pub description: &'static str,
     pub max_level: u32,
     pub base_cost: u64,
     pub cost_scaling: f64,
     pub category: UpgradeCategory,
+    #[serde(default, deserialize_with = "leak::slice")]
+    pub effects: &'static [Effect],
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum UpgradeCategory {
     Speed,
     Luck,
@@ -20,6 +32,15 @@ pub enum UpgradeCategory {
 }
 
 impl UpgradeCategory {
+    pub const ALL: [UpgradeCategory; 6] = [
+        UpgradeCategory::Speed,
+        UpgradeCategory::Luck,
+        UpgradeCategory::Wealth,
+        UpgradeCategory::Mastery,
+        UpgradeCategory::Discovery,
+        UpgradeCategory::Unlock,
+    ];
+
     pub fn label(self) -> &'static str {
         match self {
             UpgradeCategory::Speed => "Speed",
@@ -42,7 +63,12 @@ impl UpgradeDef {
     }
 }
 
-pub fn all_upgrades() -> Vec<UpgradeDef> {
+pub fn all_upgrades() -> &'static [UpgradeDef] {
+    &pack::current().upgrades
+}
+
+/// The upgrades shipped with the game.
+pub fn builtin_upgrades() -> Vec<UpgradeDef> {
     vec![
         // === Unlock (6) ===
         UpgradeDef {
@@ -53,6 +79,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 250,
             cost_scaling: 1.0,
             category: UpgradeCategory::Unlock,
+            effects: &[Effect::ChestKey { chest: ChestType::Iron, min_level: 5 }],
         },
         UpgradeDef {
             id: "silver_key",
@@ -62,6 +89,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 2000,
             cost_scaling: 1.0,
             category: UpgradeCategory::Unlock,
+            effects: &[Effect::ChestKey { chest: ChestType::Silver, min_level: 10 }],
         },
         UpgradeDef {
             id: "gold_key",
@@ -71,6 +99,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 25000,
             cost_scaling: 1.0,
             category: UpgradeCategory::Unlock,
+            effects: &[Effect::ChestKey { chest: ChestType::Gold, min_level: 20 }],
         },
         UpgradeDef {
             id: "crystal_key",
@@ -80,6 +109,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 250000,
             cost_scaling: 1.0,
             category: UpgradeCategory::Unlock,
+            effects: &[Effect::ChestKey { chest: ChestType::Crystal, min_level: 30 }],
         },
         UpgradeDef {
             id: "shadow_key",
@@ -89,6 +119,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 2500000,
             cost_scaling: 1.0,
             category: UpgradeCategory::Unlock,
+            effects: &[Effect::ChestKey { chest: ChestType::Shadow, min_level: 40 }],
         },
         UpgradeDef {
             id: "void_key",
@@ -98,8 +129,9 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 25000000,
             cost_scaling: 1.0,
             category: UpgradeCategory::Unlock,
+            effects: &[Effect::ChestKey { chest: ChestType::Void, min_level: 50 }],
         },
-        // === Speed (7) ===
+        // === Speed (8) ===
         UpgradeDef {
             id: "swift_hands",
             name: "Swift Hands",
@@ -108,6 +140,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 150,
             cost_scaling: 2.0,
             category: UpgradeCategory::Speed,
+            effects: &[Effect::Stat(Stat::Speed, 0.10)],
         },
         UpgradeDef {
             id: "nimble_fingers",
@@ -117,6 +150,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 80,
             cost_scaling: 1.8,
             category: UpgradeCategory::Speed,
+            effects: &[Effect::Stat(Stat::Speed, 0.05)],
         },
         UpgradeDef {
             id: "overdrive",
@@ -126,6 +160,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 2000,
             cost_scaling: 2.8,
             category: UpgradeCategory::Speed,
+            effects: &[Effect::Stat(Stat::Speed, 0.15)],
         },
         UpgradeDef {
             id: "perpetual_gear",
@@ -135,6 +170,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 800,
             cost_scaling: 2.2,
             category: UpgradeCategory::Speed,
+            effects: &[Effect::Stat(Stat::Speed, 0.10)],
         },
         UpgradeDef {
             id: "quicksilver_touch",
@@ -144,6 +180,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 500,
             cost_scaling: 2.0,
             category: UpgradeCategory::Speed,
+            effects: &[Effect::Stat(Stat::Speed, 0.08)],
         },
         UpgradeDef {
             id: "haste_rune",
@@ -153,6 +190,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 8000,
             cost_scaling: 3.5,
             category: UpgradeCategory::Speed,
+            effects: &[Effect::Stat(Stat::Speed, 0.20)],
         },
         UpgradeDef {
             id: "chrono_accelerator",
@@ -162,6 +200,17 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 3000,
             cost_scaling: 2.5,
             category: UpgradeCategory::Speed,
+            effects: &[Effect::Stat(Stat::Speed, 0.12)],
+        },
+        UpgradeDef {
+            id: "night_shift",
+            name: "Night Shift",
+            description: "Keep playing while closed: +1 hour of offline progress per level",
+            max_level: 8,
+            base_cost: 1500,
+            cost_scaling: 2.2,
+            category: UpgradeCategory::Speed,
+            effects: &[Effect::OfflineHours(1.0)],
         },
         // === Luck (8) ===
         UpgradeDef {
@@ -172,6 +221,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 120,
             cost_scaling: 1.9,
             category: UpgradeCategory::Luck,
+            effects: &[Effect::Stat(Stat::Luck, 1.0)],
         },
         UpgradeDef {
             id: "four_leaf",
@@ -181,6 +231,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 300,
             cost_scaling: 2.2,
             category: UpgradeCategory::Luck,
+            effects: &[Effect::Stat(Stat::Luck, 2.0)],
         },
         UpgradeDef {
             id: "critical_eye",
@@ -190,6 +241,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 250,
             cost_scaling: 2.0,
             category: UpgradeCategory::Luck,
+            effects: &[Effect::Stat(Stat::CritChance, 0.02)],
         },
         UpgradeDef {
             id: "fortune_wheel",
@@ -199,6 +251,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 800,
             cost_scaling: 2.3,
             category: UpgradeCategory::Luck,
+            effects: &[Effect::Stat(Stat::Luck, 3.0)],
         },
         UpgradeDef {
             id: "horseshoe",
@@ -208,6 +261,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 1500,
             cost_scaling: 2.8,
             category: UpgradeCategory::Luck,
+            effects: &[Effect::RarityUpgrade { chance: 0.01, chaotic: false }],
         },
         UpgradeDef {
             id: "rabbits_paw",
@@ -217,6 +271,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 2500,
             cost_scaling: 2.8,
             category: UpgradeCategory::Luck,
+            effects: &[Effect::Stat(Stat::Luck, 4.0)],
         },
         UpgradeDef {
             id: "lucky_dice",
@@ -226,6 +281,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 2000,
             cost_scaling: 2.8,
             category: UpgradeCategory::Luck,
+            effects: &[Effect::Stat(Stat::CritChance, 0.03)],
         },
         UpgradeDef {
             id: "stars_alignment",
@@ -235,6 +291,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 10000,
             cost_scaling: 3.5,
             category: UpgradeCategory::Luck,
+            effects: &[Effect::Stat(Stat::Luck, 5.0)],
         },
         // === Wealth (8) ===
         UpgradeDef {
@@ -245,6 +302,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 200,
             cost_scaling: 1.9,
             category: UpgradeCategory::Wealth,
+            effects: &[Effect::Stat(Stat::GpMult, 0.10)],
         },
         UpgradeDef {
             id: "xp_boost",
@@ -254,6 +312,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 200,
             cost_scaling: 1.9,
             category: UpgradeCategory::Wealth,
+            effects: &[Effect::Stat(Stat::XpMult, 0.10)],
         },
         UpgradeDef {
             id: "treasure_sense",
@@ -263,6 +322,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 600,
             cost_scaling: 2.3,
             category: UpgradeCategory::Wealth,
+            effects: &[Effect::Stat(Stat::GpMult, 0.20)],
         },
         UpgradeDef {
             id: "golden_magnet",
@@ -272,6 +332,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 1000,
             cost_scaling: 2.2,
             category: UpgradeCategory::Wealth,
+            effects: &[Effect::Stat(Stat::GpMult, 0.15)],
         },
         UpgradeDef {
             id: "wisdom_tome",
@@ -281,6 +342,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 1000,
             cost_scaling: 2.2,
             category: UpgradeCategory::Wealth,
+            effects: &[Effect::Stat(Stat::XpMult, 0.15)],
         },
         UpgradeDef {
             id: "alchemist_stone",
@@ -290,6 +352,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 4000,
             cost_scaling: 2.8,
             category: UpgradeCategory::Wealth,
+            effects: &[Effect::Stat(Stat::GpMult, 0.25)],
         },
         UpgradeDef {
             id: "scholars_cap",
@@ -299,6 +362,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 4000,
             cost_scaling: 2.8,
             category: UpgradeCategory::Wealth,
+            effects: &[Effect::Stat(Stat::XpMult, 0.25)],
         },
         UpgradeDef {
             id: "dragon_hoard_map",
@@ -308,6 +372,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 15000,
             cost_scaling: 3.5,
             category: UpgradeCategory::Wealth,
+            effects: &[Effect::Stat(Stat::GpMult, 0.30)],
         },
         // === Mastery (8) ===
         UpgradeDef {
@@ -318,6 +383,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 2000,
             cost_scaling: 2.8,
             category: UpgradeCategory::Mastery,
+            effects: &[Effect::Stat(Stat::CritChance, 0.03)],
         },
         UpgradeDef {
             id: "crit_power",
@@ -327,6 +393,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 1200,
             cost_scaling: 2.3,
             category: UpgradeCategory::Mastery,
+            effects: &[Effect::CritMult(0.2)],
         },
         UpgradeDef {
             id: "combo_counter",
@@ -336,6 +403,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 3000,
             cost_scaling: 3.0,
             category: UpgradeCategory::Mastery,
+            effects: &[Effect::CritComboGp(0.05)],
         },
         UpgradeDef {
             id: "xp_amplifier",
@@ -345,6 +413,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 1800,
             cost_scaling: 2.8,
             category: UpgradeCategory::Mastery,
+            effects: &[Effect::Stat(Stat::XpMult, 0.20)],
         },
         UpgradeDef {
             id: "legendary_focus",
@@ -354,6 +423,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 2500,
             cost_scaling: 2.5,
             category: UpgradeCategory::Mastery,
+            effects: &[Effect::RarityGp { rarities: &[Rarity::Legendary], bonus: 0.10 }],
         },
         UpgradeDef {
             id: "executioners_edge",
@@ -363,6 +433,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 6000,
             cost_scaling: 3.0,
             category: UpgradeCategory::Mastery,
+            effects: &[Effect::CritMult(0.3)],
         },
         UpgradeDef {
             id: "precision_mastery",
@@ -372,6 +443,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 10000,
             cost_scaling: 3.5,
             category: UpgradeCategory::Mastery,
+            effects: &[Effect::Stat(Stat::CritChance, 0.04)],
         },
         UpgradeDef {
             id: "knowledge_nexus",
@@ -381,6 +453,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 12000,
             cost_scaling: 3.5,
             category: UpgradeCategory::Mastery,
+            effects: &[Effect::Stat(Stat::XpMult, 0.30)],
         },
         // === Discovery (8) ===
         UpgradeDef {
@@ -391,6 +464,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 4000,
             cost_scaling: 3.0,
             category: UpgradeCategory::Discovery,
+            effects: &[Effect::RelicDrop(0.05)],
         },
         UpgradeDef {
             id: "deep_pockets",
@@ -400,6 +474,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 20000,
             cost_scaling: 4.0,
             category: UpgradeCategory::Discovery,
+            effects: &[Effect::RelicSlots(1)],
         },
         UpgradeDef {
             id: "bonus_loot",
@@ -409,6 +484,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 2000,
             cost_scaling: 2.8,
             category: UpgradeCategory::Discovery,
+            effects: &[Effect::MultiDrop(0.03)],
         },
         UpgradeDef {
             id: "chest_radar",
@@ -418,6 +494,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 3000,
             cost_scaling: 2.8,
             category: UpgradeCategory::Discovery,
+            effects: &[Effect::BonusChest(0.10)],
         },
         UpgradeDef {
             id: "void_sight",
@@ -427,6 +504,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 5000,
             cost_scaling: 3.0,
             category: UpgradeCategory::Discovery,
+            effects: &[Effect::RareLuck(15.0)],
         },
         UpgradeDef {
             id: "treasure_hunter",
@@ -436,6 +514,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 12000,
             cost_scaling: 3.5,
             category: UpgradeCategory::Discovery,
+            effects: &[Effect::RelicDrop(0.08)],
         },
         UpgradeDef {
             id: "lucky_find",
@@ -445,6 +524,7 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 8000,
             cost_scaling: 3.0,
             category: UpgradeCategory::Discovery,
+            effects: &[Effect::MultiDrop(0.05)],
         },
         UpgradeDef {
             id: "artifact_sense",
@@ -454,12 +534,11 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
             base_cost: 25000,
             cost_scaling: 4.0,
             category: UpgradeCategory::Discovery,
+            effects: &[Effect::RelicDrop(0.10)],
         },
     ]
 }
 
 pub fn get_upgrade(id: &str) -> Option<&'static UpgradeDef> {
-    use std::sync::LazyLock;
-    static UPGRADES: LazyLock<Vec<UpgradeDef>> = LazyLock::new(all_upgrades);
-    UPGRADES.iter().find(|u| u.id == id)
+    all_upgrades().iter().find(|u| u.id == id)
 }
@@ -24,12 +24,11 @@ impl EventHandler {
     pub fn next(&mut self) -> Result<Event> {
         loop {
             // Check for input without blocking
-            if event::poll(Duration::from_millis(0))? {
-                if let event::Event::Key(key) = event::read()? {
-                    if key.kind == event::KeyEventKind::Press {
-                        return Ok(Event::Key(key));
-                    }
-                }
+            if event::poll(Duration::from_millis(0))?
+                && let event::Event::Key(key) = event::read()?
+                && key.kind == event::KeyEventKind::Press
+            {
+                return Ok(Event::Key(key));
             }
 
             // Check if it's time for a tick
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Achievements unlocked so far. Kept through rebirths.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AchievementState {
+    pub unlocked: HashSet<String>,
+    /// Title shown next to the player's level, from an unlocked achievement.
+    pub title: Option<String>,
+}
+
+impl AchievementState {
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.unlocked.contains(id)
+    }
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use crate::data::upgrades::UpgradeCategory;
+
+/// How many rules a player can write.
+pub const MAX_RULES: usize = 12;
+
+/// What a rule buys among the upgrades it targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RuleAction {
+    /// The cheapest one.
+    Cheapest,
+    /// The cheapest one that stays within `within` levels of the lowest.
+    Balance { within: u32 },
+}
+
+/// One line of the player's buying script.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AutoRule {
+    pub enabled: bool,
+    pub action: RuleAction,
+    /// Only upgrades in this category; every upgrade if `None`.
+    pub category: Option<UpgradeCategory>,
+    /// Only buy when the cost is at most this percent of current GP.
+    pub max_cost_pct: u32,
+}
+
+impl Default for AutoRule {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            action: RuleAction::Cheapest,
+            category: None,
+            max_cost_pct: 10,
+        }
+    }
+}
+
+impl AutoRule {
+    pub fn targets(&self, category: UpgradeCategory) -> bool {
+        self.category.is_none_or(|c| c == category)
+    }
+
+    /// "Buy cheapest Speed upgrade when cost <= 10% of GP"
+    pub fn describe(&self) -> String {
+        let target = self.category.map_or("any", |c| c.label());
+        match self.action {
+            RuleAction::Cheapest => format!(
+                "Buy cheapest {} upgrade when cost <= {}% of GP",
+                target, self.max_cost_pct
+            ),
+            RuleAction::Balance { within } => format!(
+                "Keep {} upgrades within {} level{}, cost <= {}% of GP",
+                target,
+                within,
+                if within == 1 { "" } else { "s" },
+                self.max_cost_pct
+            ),
+        }
+    }
+}
+
+/// Upgrade-buying rules, checked in order every tick. They survive rebirth;
+/// the Quartermaster rebirth skill has to be learned for them to run.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AutomationState {
+    /// Master switch, so the rules can be paused without deleting them.
+    pub enabled: bool,
+    pub rules: Vec<AutoRule>,
+}
@@ -0,0 +1,357 @@
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+
+/// Below this the exponent is 0 and the mantissa is the value itself, so
+/// whole numbers up to here stay exact.
+const EXACT_LIMIT: f64 = 1e15;
+
+/// Powers of ten below [`EXACT_LIMIT`]'s.
+const DIGITS: i64 = 14;
+
+/// A non-negative amount of GP, XP or essence that can grow past `u64`:
+/// `mantissa * 10^exponent`.
+///
+/// Small values keep `exponent == 0`. Past [`EXACT_LIMIT`] the mantissa is
+/// kept between 1e14 and 1e15 (15 significant digits) and the exponent grows
+/// instead. Subtraction stops at zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BigNum {
+    mantissa: f64,
+    exponent: u32,
+}
+
+impl BigNum {
+    pub const ZERO: BigNum = BigNum {
+        mantissa: 0.0,
+        exponent: 0,
+    };
+
+    /// Largest representable value; infinities and overflow end up here.
+    pub const MAX: BigNum = BigNum {
+        mantissa: EXACT_LIMIT - 1.0,
+        exponent: u32::MAX,
+    };
+
+    /// `value` itself; negative and NaN values become zero.
+    pub fn new(value: f64) -> Self {
+        if value.is_nan() || value <= 0.0 {
+            return Self::ZERO;
+        }
+        if value.is_infinite() {
+            return Self::MAX;
+        }
+        Self::normalized(value, 0)
+    }
+
+    /// `mantissa * 10^exponent`, for values past what `f64` can hold.
+    pub fn from_parts(mantissa: f64, exponent: i64) -> Self {
+        if mantissa.is_nan() || mantissa <= 0.0 {
+            return Self::ZERO;
+        }
+        if exponent <= 0 {
+            return Self::new(mantissa / 10f64.powi((-exponent).min(400) as i32));
+        }
+        let digits = mantissa.log10().floor() as i64;
+        let shift = DIGITS - digits;
+        match u32::try_from(exponent - shift) {
+            Ok(e) if e > 0 => Self::normalized(mantissa * 10f64.powi(shift as i32), e),
+            Ok(_) => Self::new(mantissa * 10f64.powi(exponent as i32)),
+            Err(_) if exponent - shift < 0 => Self::new(mantissa * 10f64.powi(exponent as i32)),
+            Err(_) => Self::MAX,
+        }
+    }
+
+    /// Bring `mantissa * 10^exponent` back into canonical form.
+    fn normalized(mut mantissa: f64, mut exponent: u32) -> Self {
+        if mantissa.is_nan() || mantissa <= 0.0 {
+            return Self::ZERO;
+        }
+        if mantissa.is_infinite() {
+            return Self::MAX;
+        }
+        if mantissa >= EXACT_LIMIT {
+            let shift = (mantissa.log10().floor() as i64 - DIGITS).max(1) as u32;
+            let Some(e) = exponent.checked_add(shift) else {
+                return Self::MAX;
+            };
+            mantissa /= 10f64.powi(shift as i32);
+            exponent = e;
+            // Rounding in log10 can leave it one digit off
+            if mantissa >= EXACT_LIMIT {
+                mantissa /= 10.0;
+                exponent = exponent.saturating_add(1);
+            }
+        } else if exponent > 0 && mantissa < EXACT_LIMIT / 10.0 {
+            let room = DIGITS - mantissa.log10().floor() as i64;
+            let shift = (room.max(1) as u32).min(exponent);
+            mantissa *= 10f64.powi(shift as i32);
+            exponent -= shift;
+        }
+        Self { mantissa, exponent }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mantissa == 0.0
+    }
+
+    /// The value as an `f64`, infinite if it is too big.
+    pub fn to_f64(&self) -> f64 {
+        if self.exponent == 0 {
+            self.mantissa
+        } else {
+            self.mantissa * 10f64.powi(self.exponent.min(400) as i32)
+        }
+    }
+
+    /// Base-10 logarithm; `-inf` for zero.
+    pub fn log10(&self) -> f64 {
+        self.mantissa.log10() + self.exponent as f64
+    }
+
+    /// Raise to the power `p`, working in logarithms so the result can be
+    /// huge.
+    pub fn powf(&self, p: f64) -> Self {
+        if self.is_zero() {
+            return Self::ZERO;
+        }
+        let lg = self.log10() * p;
+        let whole = lg.floor();
+        Self::from_parts(10f64.powf(lg - whole), whole as i64)
+    }
+
+    /// Drop any fraction. Values past the exact range have none.
+    pub fn floor(self) -> Self {
+        if self.exponent == 0 {
+            Self::new(self.mantissa.floor())
+        } else {
+            self
+        }
+    }
+
+    pub fn max(self, other: Self) -> Self {
+        if other > self { other } else { self }
+    }
+
+    pub fn min(self, other: Self) -> Self {
+        if other < self { other } else { self }
+    }
+
+    /// Significant digits and power of ten, `(1.23, 45)` for 1.23e45.
+    pub fn scientific(&self) -> (f64, i64) {
+        if self.is_zero() {
+            return (0.0, 0);
+        }
+        let digits = self.mantissa.log10().floor();
+        let mut mantissa = self.mantissa / 10f64.powi(digits as i32);
+        let mut exponent = digits as i64 + self.exponent as i64;
+        if mantissa >= 10.0 {
+            mantissa /= 10.0;
+            exponent += 1;
+        }
+        (mantissa, exponent)
+    }
+}
+
+impl From<u64> for BigNum {
+    fn from(value: u64) -> Self {
+        Self::new(value as f64)
+    }
+}
+
+impl From<u32> for BigNum {
+    fn from(value: u32) -> Self {
+        Self::new(value as f64)
+    }
+}
+
+impl PartialOrd for BigNum {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        // In canonical form a larger exponent always means a larger value
+        match self.exponent.cmp(&other.exponent) {
+            Ordering::Equal => self.mantissa.partial_cmp(&other.mantissa),
+            ord => Some(ord),
+        }
+    }
+}
+
+impl PartialEq<u64> for BigNum {
+    fn eq(&self, other: &u64) -> bool {
+        *self == BigNum::from(*other)
+    }
+}
+
+impl PartialOrd<u64> for BigNum {
+    fn partial_cmp(&self, other: &u64) -> Option<Ordering> {
+        self.partial_cmp(&BigNum::from(*other))
+    }
+}
+
+impl Add for BigNum {
+    type Output = BigNum;
+
+    fn add(self, rhs: BigNum) -> BigNum {
+        let (big, small) = if self.exponent >= rhs.exponent {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let gap = big.exponent - small.exponent;
+        if gap > 20 {
+            return big;
+        }
+        Self::normalized(big.mantissa + small.mantissa / 10f64.powi(gap as i32), big.exponent)
+    }
+}
+
+impl AddAssign for BigNum {
+    fn add_assign(&mut self, rhs: BigNum) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for BigNum {
+    type Output = BigNum;
+
+    /// Saturates at zero.
+    fn sub(self, rhs: BigNum) -> BigNum {
+        if rhs >= self {
+            return Self::ZERO;
+        }
+        let gap = self.exponent - rhs.exponent;
+        if gap > 20 {
+            return self;
+        }
+        Self::normalized(self.mantissa - rhs.mantissa / 10f64.powi(gap as i32), self.exponent)
+    }
+}
+
+impl SubAssign for BigNum {
+    fn sub_assign(&mut self, rhs: BigNum) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<f64> for BigNum {
+    type Output = BigNum;
+
+    fn mul(self, rhs: f64) -> BigNum {
+        if rhs.is_nan() || rhs <= 0.0 || self.is_zero() {
+            return Self::ZERO;
+        }
+        let product = self.mantissa * rhs;
+        if product.is_finite() {
+            Self::normalized(product, self.exponent)
+        } else {
+            // Split a huge factor into its own mantissa and exponent
+            let whole = rhs.log10().floor();
+            let factor = Self::from_parts(rhs / 10f64.powf(whole), whole as i64);
+            self * factor
+        }
+    }
+}
+
+impl Mul for BigNum {
+    type Output = BigNum;
+
+    fn mul(self, rhs: BigNum) -> BigNum {
+        match self.exponent.checked_add(rhs.exponent) {
+            Some(e) => Self::normalized(self.mantissa * rhs.mantissa, e),
+            None => Self::MAX,
+        }
+    }
+}
+
+impl MulAssign<f64> for BigNum {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<f64> for BigNum {
+    type Output = BigNum;
+
+    fn div(self, rhs: f64) -> BigNum {
+        if rhs.is_nan() || rhs <= 0.0 {
+            return Self::ZERO;
+        }
+        Self::normalized(self.mantissa / rhs, self.exponent)
+    }
+}
+
+impl Sum for BigNum {
+    fn sum<I: Iterator<Item = BigNum>>(iter: I) -> BigNum {
+        iter.fold(BigNum::ZERO, Add::add)
+    }
+}
+
+/// Whole numbers in full (`12345`), then scientific notation (`1.23e45`).
+impl fmt::Display for BigNum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.exponent == 0 {
+            write!(f, "{}", self.mantissa.floor())
+        } else {
+            let (mut mantissa, mut exponent) = self.scientific();
+            // Round first, so 9.999e45 reads 1.00e46 rather than 10.00e45
+            if (mantissa * 100.0).round() >= 1000.0 {
+                mantissa /= 10.0;
+                exponent += 1;
+            }
+            write!(f, "{:.2}e{}", mantissa, exponent)
+        }
+    }
+}
+
+/// Written as a plain JSON number while it fits in a `u64`, as older saves
+/// have it, and as a `"1.234e45"` string beyond that.
+impl Serialize for BigNum {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.exponent == 0 && self.mantissa.fract() == 0.0 {
+            serializer.serialize_u64(self.mantissa as u64)
+        } else if self.exponent == 0 {
+            serializer.serialize_f64(self.mantissa)
+        } else {
+            serializer.serialize_str(&format!("{}e{}", self.mantissa, self.exponent))
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BigNum {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(BigNumVisitor)
+    }
+}
+
+struct BigNumVisitor;
+
+impl Visitor<'_> for BigNumVisitor {
+    type Value = BigNum;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a non-negative number or a \"<mantissa>e<exponent>\" string")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<BigNum, E> {
+        Ok(BigNum::from(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<BigNum, E> {
+        Ok(BigNum::new(v as f64))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<BigNum, E> {
+        Ok(BigNum::new(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<BigNum, E> {
+        // Only big values are written as strings, always with an exponent
+        v.split_once(['e', 'E'])
+            .and_then(|(mantissa, exponent)| {
+                Some(BigNum::from_parts(mantissa.parse().ok()?, exponent.parse().ok()?))
+            })
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+
+use crate::data::items::get_item;
+
+use super::bignum::BigNum;
+use super::chest::ChestType;
+use super::events::Proc;
+use super::item::Rarity;
+use super::state::GameState;
+
+/// Bounties offered at once.
+pub const BOUNTY_SLOTS: usize = 3;
+
+/// Chests opened between rotations of the unfinished bounties.
+pub const REFRESH_CHESTS: u64 = 250;
+
+/// What a bounty asks for.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Objective {
+    /// Find `count` items of `rarity` or better from `chest`.
+    FindRarity { chest: ChestType, rarity: Rarity, count: u32 },
+    /// Find `count` copies of one item from `chest`.
+    FindItem { chest: ChestType, item_id: String, count: u32 },
+    OpenChests { chest: ChestType, count: u32 },
+    /// Earn this much GP in the current run.
+    EarnGpInRun(BigNum),
+    TriggerProc { proc: Proc, count: u32 },
+}
+
+impl Objective {
+    pub fn describe(&self) -> String {
+        match self {
+            Objective::FindRarity { chest, rarity, count } => format!(
+                "Find {} {}+ items from {} chests",
+                count,
+                rarity.label(),
+                chest.name()
+            ),
+            Objective::FindItem { chest, item_id, count } => format!(
+                "Find {} {} from {} chests",
+                count,
+                get_item(item_id).map_or(item_id.as_str(), |i| i.name),
+                chest.name()
+            ),
+            Objective::OpenChests { chest, count } => {
+                format!("Open {} {} chests", count, chest.name())
+            }
+            Objective::EarnGpInRun(gp) => format!("Earn {} GP in one run", gp),
+            Objective::TriggerProc { proc, count } => {
+                format!("Trigger {} {} times", proc.name(), count)
+            }
+        }
+    }
+}
+
+/// What finishing a bounty pays out.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BountyReward {
+    Gp(BigNum),
+    SkillPoints(u32),
+    Essence(BigNum),
+    /// One relic drop, as if from the best chest unlocked (Silver at least).
+    RelicRoll,
+}
+
+impl BountyReward {
+    pub fn describe(&self) -> String {
+        match self {
+            BountyReward::Gp(gp) => format!("{} GP", gp),
+            BountyReward::SkillPoints(1) => "1 skill point".to_string(),
+            BountyReward::SkillPoints(n) => format!("{} skill points", n),
+            BountyReward::Essence(essence) => format!("{} Essence", essence),
+            BountyReward::RelicRoll => "a relic roll".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bounty {
+    pub objective: Objective,
+    pub reward: BountyReward,
+    /// Count towards the objective. Unused for GP, which reads the run total.
+    pub progress: u32,
+    pub done: bool,
+}
+
+impl Bounty {
+    pub fn new(objective: Objective, reward: BountyReward) -> Self {
+        Self {
+            objective,
+            reward,
+            progress: 0,
+            done: false,
+        }
+    }
+
+    /// `(current, target)` towards the objective, for progress bars.
+    pub fn progress(&self, state: &GameState) -> (f64, f64) {
+        let target = match &self.objective {
+            Objective::FindRarity { count, .. }
+            | Objective::FindItem { count, .. }
+            | Objective::OpenChests { count, .. }
+            | Objective::TriggerProc { count, .. } => *count as f64,
+            Objective::EarnGpInRun(gp) => {
+                let target = gp.to_f64();
+                let current = if self.done {
+                    target
+                } else {
+                    state.rebirth.gp_earned_this_run.to_f64()
+                };
+                return (current.min(target), target);
+            }
+        };
+        ((self.progress as f64).min(target), target)
+    }
+}
+
+/// The bounties on offer. Kept in the save so progress survives a restart.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BountyBoard {
+    pub bounties: Vec<Bounty>,
+    /// `LifetimeStats::chests_opened` when the board last rotated.
+    pub refreshed_at: u64,
+}
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use super::item::ItemInstance;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum ChestType {
     Wooden,
     Iron,
@@ -11,10 +11,12 @@ pub enum ChestType {
     Crystal,
     Shadow,
     Void,
+    Celestial,
+    Primordial,
 }
 
 impl ChestType {
-    pub const ALL: [ChestType; 7] = [
+    pub const ALL: [ChestType; 9] = [
         ChestType::Wooden,
         ChestType::Iron,
         ChestType::Silver,
@@ -22,18 +24,13 @@ impl ChestType {
         ChestType::Crystal,
         ChestType::Shadow,
         ChestType::Void,
+        ChestType::Celestial,
+        ChestType::Primordial,
     ];
 
+    /// Position in `ALL`, so adding a tier only means listing it there.
     pub fn index(self) -> usize {
-        match self {
-            ChestType::Wooden => 0,
-            ChestType::Iron => 1,
-            ChestType::Silver => 2,
-            ChestType::Gold => 3,
-            ChestType::Crystal => 4,
-            ChestType::Shadow => 5,
-            ChestType::Void => 6,
-        }
+        Self::ALL.iter().position(|&ct| ct == self).unwrap_or(0)
     }
 
     pub fn name(self) -> &'static str {
@@ -45,18 +42,22 @@ impl ChestType {
             ChestType::Crystal => "Crystal",
             ChestType::Shadow => "Shadow",
             ChestType::Void => "Void",
+            ChestType::Celestial => "Celestial",
+            ChestType::Primordial => "Primordial",
         }
     }
 
     pub fn base_ticks(self) -> u32 {
         match self {
-            ChestType::Wooden => 60,    // ~2 seconds
-            ChestType::Iron => 120,     // ~4 seconds
-            ChestType::Silver => 210,   // ~7 seconds
-            ChestType::Gold => 360,     // ~12 seconds
-            ChestType::Crystal => 600,  // ~20 seconds
-            ChestType::Shadow => 900,   // ~30 seconds
-            ChestType::Void => 1500,    // ~50 seconds
+            ChestType::Wooden => 60,       // ~2 seconds
+            ChestType::Iron => 120,        // ~4 seconds
+            ChestType::Silver => 210,      // ~7 seconds
+            ChestType::Gold => 360,        // ~12 seconds
+            ChestType::Crystal => 600,     // ~20 seconds
+            ChestType::Shadow => 900,      // ~30 seconds
+            ChestType::Void => 1500,       // ~50 seconds
+            ChestType::Celestial => 2400,  // ~80 seconds
+            ChestType::Primordial => 3600, // ~2 minutes
         }
     }
 
@@ -69,6 +70,8 @@ impl ChestType {
             ChestType::Crystal => 5.0,
             ChestType::Shadow => 10.0,
             ChestType::Void => 20.0,
+            ChestType::Celestial => 40.0,
+            ChestType::Primordial => 80.0,
         }
     }
 
@@ -81,6 +84,8 @@ impl ChestType {
             ChestType::Crystal => 30,
             ChestType::Shadow => 40,
             ChestType::Void => 50,
+            ChestType::Celestial => 60,
+            ChestType::Primordial => 75,
         }
     }
 
@@ -93,6 +98,8 @@ impl ChestType {
             ChestType::Crystal => ratatui::style::Color::Cyan,
             ChestType::Shadow => ratatui::style::Color::Magenta,
             ChestType::Void => ratatui::style::Color::Rgb(128, 0, 255),
+            ChestType::Celestial => ratatui::style::Color::Rgb(150, 220, 255),
+            ChestType::Primordial => ratatui::style::Color::Rgb(255, 130, 40),
         }
     }
 }
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Every item ever found and every set ever completed. Kept through rebirth,
+/// and a completed set's bonus stays even after its pieces are sold.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Collection {
+    /// Ids of items that have dropped at least once.
+    pub found: HashSet<String>,
+    /// Ids of completed item sets.
+    pub completed: HashSet<String>,
+}
+
+impl Collection {
+    pub fn has_found(&self, item_id: &str) -> bool {
+        self.found.contains(item_id)
+    }
+
+    pub fn is_complete(&self, set_id: &str) -> bool {
+        self.completed.contains(set_id)
+    }
+}
@@ -0,0 +1,55 @@
+use std::collections::BTreeMap;
+
+use crate::data::recipes::{RecipeDef, RecipeInput};
+
+use super::inventory::Inventory;
+
+/// Which inventory stacks a craft would use: `(stack index, count)`.
+///
+/// The cheapest items that fit are picked, so valuable and affixed copies are
+/// kept. `None` if the inventory doesn't hold enough.
+pub fn plan(recipe: &RecipeDef, inventory: &Inventory) -> Option<Vec<(usize, u32)>> {
+    let mut left: Vec<u32> = inventory.items.iter().map(|i| i.count).collect();
+    let mut used: BTreeMap<usize, u32> = BTreeMap::new();
+
+    for &input in recipe.inputs {
+        // Stacks of the right rarity, cheapest per item first
+        let mut stacks: Vec<usize> = (0..inventory.items.len())
+            .filter(|&i| inventory.items[i].rarity == input.rarity() && left[i] > 0)
+            .collect();
+        stacks.sort_by(|&a, &b| {
+            inventory.items[a]
+                .gp_value
+                .partial_cmp(&inventory.items[b].gp_value)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        if let RecipeInput::Same { count, .. } = input {
+            // The cheapest item id there are enough copies of
+            let id = stacks.iter().map(|&i| &inventory.items[i].id).find(|id| {
+                stacks
+                    .iter()
+                    .filter(|&&i| inventory.items[i].id == **id)
+                    .map(|&i| left[i])
+                    .sum::<u32>()
+                    >= count
+            })?;
+            stacks.retain(|&i| inventory.items[i].id == *id);
+        }
+
+        let mut needed = input.count();
+        for i in stacks {
+            if needed == 0 {
+                break;
+            }
+            let take = needed.min(left[i]);
+            left[i] -= take;
+            *used.entry(i).or_default() += take;
+            needed -= take;
+        }
+        if needed > 0 {
+            return None;
+        }
+    }
+    Some(used.into_iter().collect())
+}
@@ -0,0 +1,64 @@
+use crate::data::achievements::{AchievementGoal, all_achievements, get_achievement};
+use crate::game::events::{GameEvent, Rejection};
+use crate::game::relic::MAX_RELIC_LEVEL;
+
+use super::GameEngine;
+
+impl GameEngine {
+    /// Unlock every achievement whose goal the state or the pending events
+    /// now meet.
+    pub(super) fn check_achievements(&mut self) {
+        let mut unlocked_any = false;
+        for achievement in all_achievements() {
+            if self.state.achievements.is_unlocked(achievement.id) || !self.goal_met(achievement.goal) {
+                continue;
+            }
+            self.state.achievements.unlocked.insert(achievement.id.to_string());
+            self.emit(GameEvent::AchievementUnlocked {
+                id: achievement.id.to_string(),
+            });
+            unlocked_any = true;
+        }
+        if unlocked_any {
+            self.recalculate_player_stats();
+        }
+    }
+
+    fn goal_met(&self, goal: AchievementGoal) -> bool {
+        let happened = |f: &dyn Fn(&GameEvent) -> bool| self.events.iter().any(f);
+        match goal {
+            AchievementGoal::Count { counter, target } => counter.value(&self.state) >= target,
+            AchievementGoal::DropFrom { chest, rarity } => happened(&|e| {
+                matches!(e, GameEvent::ItemFound { item, chest: c, .. } if *c == chest && item.rarity >= rarity)
+            }),
+            AchievementGoal::Craft(rarity) => happened(&|e| {
+                matches!(e, GameEvent::Crafted { item: Some(item), .. } if item.rarity >= rarity)
+            }),
+            AchievementGoal::FuseRelics => happened(&|e| matches!(e, GameEvent::RelicsFused { .. })),
+            AchievementGoal::MaxRelicLevel => {
+                self.state.relics.levels.values().any(|&level| level >= MAX_RELIC_LEVEL)
+            }
+            AchievementGoal::RebirthWithoutRelics => {
+                happened(&|e| matches!(e, GameEvent::Rebirth { relics_equipped: 0, .. }))
+            }
+        }
+    }
+
+    pub(super) fn set_title(&mut self, id: Option<String>) {
+        let title = match id {
+            Some(id) => {
+                let title = get_achievement(&id)
+                    .filter(|a| self.state.achievements.is_unlocked(a.id))
+                    .and_then(|a| a.title);
+                let Some(title) = title else {
+                    self.reject(Rejection::UnknownId);
+                    return;
+                };
+                Some(title.to_string())
+            }
+            None => None,
+        };
+        self.state.achievements.title = title.clone();
+        self.emit(GameEvent::TitleChanged { title });
+    }
+}
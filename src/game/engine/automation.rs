@@ -0,0 +1,88 @@
+use crate::data::upgrades::{UpgradeDef, all_upgrades};
+use crate::game::automation::{MAX_RULES, RuleAction};
+use crate::game::events::{Command, GameEvent, Rejection};
+use crate::game::modifiers::Modifiers;
+
+use super::GameEngine;
+
+impl GameEngine {
+    /// Let the first automation rule that has something to buy buy it. At
+    /// most one upgrade per tick, so the rules see each purchase.
+    pub(super) fn run_automation(&mut self, mods: &Modifiers) {
+        if !self.state.automation.enabled || !mods.auto_buy() {
+            return;
+        }
+        for rule in &self.state.automation.rules {
+            if !rule.enabled {
+                continue;
+            }
+            let budget = self.state.player.gp * (rule.max_cost_pct as f64 / 100.0);
+            // Everything the rule could buy right now, ignoring GP
+            let candidates: Vec<(&'static UpgradeDef, u32, u64)> = all_upgrades()
+                .iter()
+                .filter(|upg| rule.targets(upg.category))
+                .filter_map(|upg| {
+                    let cost = self.upgrade_cost(mods, upg).ok()?;
+                    Some((upg, self.state.upgrades.get_level(upg.id), cost))
+                })
+                .collect();
+            let cap = match rule.action {
+                RuleAction::Cheapest => u32::MAX,
+                RuleAction::Balance { within } => candidates
+                    .iter()
+                    .map(|&(_, level, _)| level.saturating_add(within))
+                    .min()
+                    .unwrap_or(0),
+            };
+            let pick = candidates
+                .into_iter()
+                .filter(|&(_, level, cost)| level < cap && budget >= cost)
+                .min_by_key(|&(_, _, cost)| cost);
+            if let Some((upg, _, cost)) = pick {
+                let level = self.purchase_upgrade(upg, cost);
+                self.emit(GameEvent::AutoBought {
+                    id: upg.id.to_string(),
+                    level,
+                });
+                return;
+            }
+        }
+    }
+
+    pub(super) fn toggle_automation(&mut self) {
+        if !self.modifiers().auto_buy() {
+            self.reject(Rejection::AutomationLocked);
+            return;
+        }
+        let automation = &mut self.state.automation;
+        automation.enabled = !automation.enabled;
+        let enabled = automation.enabled;
+        self.emit(GameEvent::AutomationToggled { enabled });
+    }
+
+    /// Add, change, remove or reorder automation rules.
+    pub(super) fn edit_rules(&mut self, command: Command) {
+        if !self.modifiers().auto_buy() {
+            self.reject(Rejection::AutomationLocked);
+            return;
+        }
+        let rules = &mut self.state.automation.rules;
+        match command {
+            Command::AddRule(rule) if rules.len() < MAX_RULES => rules.push(rule),
+            Command::AddRule(_) => self.reject(Rejection::TooManyRules),
+            Command::SetRule(index, rule) if index < rules.len() => rules[index] = rule,
+            Command::RemoveRule(index) if index < rules.len() => {
+                rules.remove(index);
+            }
+            Command::MoveRule { index, up } => {
+                let other = if up { index.checked_sub(1) } else { Some(index + 1) };
+                if let Some(other) = other.filter(|&o| o < rules.len())
+                    && index < rules.len()
+                {
+                    rules.swap(index, other);
+                }
+            }
+            _ => self.reject(Rejection::UnknownId),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use rand::Rng;
+
+use crate::data::chests::loot_table_for;
+use crate::data::effects::Effect;
+use crate::data::items::get_item;
+use crate::game::bignum::BigNum;
+use crate::game::events::{GameEvent, LootSource, Proc};
+use crate::game::item::Rarity;
+use crate::game::modifiers::Modifiers;
+
+use super::GameEngine;
+
+impl GameEngine {
+    pub(super) fn roll_bonus_item(&mut self, mods: &Modifiers) {
+        // Roll a second item from the same loot table
+        if let Some(item_def) = self.pick_item(self.state.player.luck) {
+            let chest_reward = self.state.current_chest_type.reward_multiplier();
+            let gp_value = (BigNum::from(item_def.base_gp)
+                * item_def.rarity.gp_multiplier()
+                * self.state.player.gp_multiplier
+                * chest_reward).floor();
+            let xp_value = (BigNum::from(item_def.base_xp)
+                * item_def.rarity.xp_multiplier()
+                * self.state.player.xp_multiplier
+                * chest_reward).floor();
+            let instance = self.make_instance(item_def, item_def.rarity, gp_value, xp_value, false);
+
+            self.state.player.gp += instance.gp_value;
+            self.state.stats.total_gp_earned += instance.gp_value;
+            self.state.stats.items_found += 1;
+            self.state.rebirth.gp_earned_this_run += instance.gp_value;
+            self.award_xp(instance.xp_value);
+
+            self.announce_drop(&instance, LootSource::MultiDrop);
+            self.store_drop(mods, instance);
+        }
+
+        // Bonus chest: one more item on top of the multi-drop
+        let radar_chance = mods.sum(|e| match e {
+            Effect::BonusChest(v) => Some(*v),
+            _ => None,
+        });
+        if radar_chance > 0.0 && self.state.rng.random::<f64>() < radar_chance {
+            self.emit(GameEvent::Proc(Proc::ChestRadar));
+            // Give a bonus item from the same table rather than recursing into roll_loot
+            if let Some(item_def) = self.pick_item(self.state.player.luck) {
+                let chest_reward = self.state.current_chest_type.reward_multiplier();
+                let gp_value = (BigNum::from(item_def.base_gp) * chest_reward).floor();
+                let xp_value = (BigNum::from(item_def.base_xp) * chest_reward).floor();
+                let instance =
+                    self.make_instance(item_def, item_def.rarity, gp_value, xp_value, false);
+                self.announce_drop(&instance, LootSource::ChestRadar);
+                self.store_drop(mods, instance);
+            }
+        }
+    }
+
+    pub(super) fn roll_scavenger_item(&mut self, mods: &Modifiers) {
+        // Roll a random Common item from current chest's loot table
+        let table = loot_table_for(self.state.current_chest_type);
+        // Find first Common entry
+        for entry in &table.entries {
+            if let Some(item_def) = get_item(entry.item_id)
+                && item_def.rarity == Rarity::Common
+            {
+                let chest_reward = self.state.current_chest_type.reward_multiplier();
+                let gp_value = (BigNum::from(item_def.base_gp)
+                    * item_def.rarity.gp_multiplier()
+                    * self.state.player.gp_multiplier
+                    * chest_reward).floor();
+                let xp_value = (BigNum::from(item_def.base_xp)
+                    * item_def.rarity.xp_multiplier()
+                    * self.state.player.xp_multiplier
+                    * chest_reward).floor();
+
+                self.state.player.gp += gp_value;
+                self.state.stats.total_gp_earned += gp_value;
+                self.state.stats.items_found += 1;
+                self.state.rebirth.gp_earned_this_run += gp_value;
+                self.award_xp(xp_value);
+
+                // Commons never roll affixes, so the value is already final
+                let instance =
+                    self.make_instance(item_def, item_def.rarity, gp_value, xp_value, false);
+
+                self.announce_drop(&instance, LootSource::Scavenger);
+                if !mods.recycles(item_def.rarity) {
+                    self.store_drop(mods, instance);
+                }
+                return;
+            }
+        }
+    }
+}
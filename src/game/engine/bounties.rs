@@ -0,0 +1,223 @@
+use rand::Rng;
+
+use crate::data::chests::loot_table_for;
+use crate::data::effects::Effect;
+use crate::data::items::get_item;
+use crate::game::bignum::BigNum;
+use crate::game::bounty::{BOUNTY_SLOTS, Bounty, BountyReward, Objective, REFRESH_CHESTS};
+use crate::game::chest::ChestType;
+use crate::game::events::{GameEvent, LootSource, Proc, Rejection};
+use crate::game::item::Rarity;
+
+use super::GameEngine;
+
+/// Rarities making up less than this share of a chest's drops are too rare
+/// to ask for.
+const MIN_RARITY_SHARE: f64 = 0.02;
+
+/// Items making up less than this share of a chest's drops are too rare to
+/// ask for.
+const MIN_ITEM_SHARE: f64 = 0.05;
+
+/// A rarity bounty asks for about what this many chests would drop.
+const RARITY_BOUNTY_CHESTS: f64 = 60.0;
+
+/// An item bounty asks for about what this many chests would drop.
+const ITEM_BOUNTY_CHESTS: f64 = 40.0;
+
+/// Relic roll rewards are rolled as if from this chest at least, the first
+/// that drops relics.
+const RELIC_ROLL_MIN_CHEST: ChestType = ChestType::Silver;
+
+impl GameEngine {
+    /// Count the pending events towards the bounties, and swap out the
+    /// unfinished ones every [`REFRESH_CHESTS`] chests.
+    pub(super) fn advance_bounties(&mut self) {
+        let gp_this_run = self.state.rebirth.gp_earned_this_run;
+        let events = &self.events;
+        let count = |f: &dyn Fn(&GameEvent) -> bool| events.iter().filter(|e| f(e)).count() as u32;
+
+        let mut completed = Vec::new();
+        for (index, bounty) in self.state.bounties.bounties.iter_mut().enumerate() {
+            if bounty.done {
+                continue;
+            }
+            let (gained, target) = match &bounty.objective {
+                Objective::FindRarity { chest: c, rarity, count: target } => (
+                    count(&|e| {
+                        matches!(e, GameEvent::ItemFound { item, chest, .. }
+                            if chest == c && item.rarity >= *rarity)
+                    }),
+                    *target,
+                ),
+                Objective::FindItem { chest: c, item_id, count: target } => (
+                    count(&|e| {
+                        matches!(e, GameEvent::ItemFound { item, chest, .. }
+                            if chest == c && item.id == *item_id)
+                    }),
+                    *target,
+                ),
+                Objective::OpenChests { chest: c, count: target } => (
+                    count(&|e| {
+                        matches!(e, GameEvent::ItemFound { source: LootSource::Chest, chest, .. }
+                            if chest == c)
+                    }),
+                    *target,
+                ),
+                Objective::TriggerProc { proc, count: target } => {
+                    (count(&|e| matches!(e, GameEvent::Proc(p) if p == proc)), *target)
+                }
+                Objective::EarnGpInRun(gp) => (u32::from(gp_this_run >= *gp), 1),
+            };
+            bounty.progress = (bounty.progress + gained).min(target);
+            if bounty.progress >= target {
+                bounty.done = true;
+                completed.push(index);
+            }
+        }
+        for index in completed {
+            self.emit(GameEvent::BountyCompleted { index });
+        }
+
+        let opened = self.state.stats.chests_opened;
+        if opened >= self.state.bounties.refreshed_at + REFRESH_CHESTS {
+            self.state.bounties.refreshed_at = opened;
+            self.replace_unfinished_bounties();
+            self.emit(GameEvent::BountiesRefreshed);
+        }
+    }
+
+    /// Swap every unfinished bounty for a new one, in place so finished
+    /// bounties keep their slot until claimed.
+    pub(super) fn replace_unfinished_bounties(&mut self) {
+        for index in 0..self.state.bounties.bounties.len() {
+            if !self.state.bounties.bounties[index].done {
+                self.state.bounties.bounties[index] = self.new_bounty();
+            }
+        }
+    }
+
+    pub(super) fn fill_bounties(&mut self) {
+        while self.state.bounties.bounties.len() < BOUNTY_SLOTS {
+            let bounty = self.new_bounty();
+            self.state.bounties.bounties.push(bounty);
+        }
+    }
+
+    /// A random bounty the player can finish from where they are: it only
+    /// names unlocked chests, items and rarities those chests really drop,
+    /// and procs the player already has.
+    fn new_bounty(&mut self) -> Bounty {
+        // Wooden is always there to fall back on, even if nothing is unlocked
+        let chest = match self.state.unlocked_chests.len() {
+            0 => ChestType::Wooden,
+            n => self.state.unlocked_chests[self.state.rng.random_range(0..n)],
+        };
+        let table = loot_table_for(chest);
+        let total: f64 = table.entries.iter().map(|e| e.weight).sum::<f64>().max(f64::EPSILON);
+        let share_of = |rarity: Rarity| {
+            table
+                .entries
+                .iter()
+                .filter(|e| get_item(e.item_id).is_some_and(|i| i.rarity >= rarity))
+                .map(|e| e.weight)
+                .sum::<f64>()
+                / total
+        };
+        let rarities: Vec<(Rarity, f64)> = Rarity::ALL[1..]
+            .iter()
+            .map(|&r| (r, share_of(r)))
+            .filter(|&(_, share)| share >= MIN_RARITY_SHARE)
+            .collect();
+        let items: Vec<(&'static str, f64)> = table
+            .entries
+            .iter()
+            .map(|e| (e.item_id, e.weight / total))
+            .filter(|&(_, share)| share >= MIN_ITEM_SHARE)
+            .collect();
+        let mut procs = Vec::new();
+        for (effect, _) in self.modifiers().iter() {
+            let proc = match *effect {
+                Effect::Jackpot { proc: Some(p), .. } | Effect::SellJackpot { proc: p, .. } => p,
+                Effect::BonusChest(_) => Proc::ChestRadar,
+                _ => continue,
+            };
+            if !procs.contains(&proc) {
+                procs.push(proc);
+            }
+        }
+
+        let rng = &mut self.state.rng;
+        let objective = match rng.random_range(0..5) {
+            0 if !rarities.is_empty() => {
+                let (rarity, share) = rarities[rng.random_range(0..rarities.len())];
+                let count = (share * RARITY_BOUNTY_CHESTS).round().clamp(2.0, 10.0) as u32;
+                Objective::FindRarity { chest, rarity, count }
+            }
+            1 if !items.is_empty() => {
+                let (item_id, share) = items[rng.random_range(0..items.len())];
+                let count = (share * ITEM_BOUNTY_CHESTS).round().clamp(1.0, 5.0) as u32;
+                Objective::FindItem { chest, item_id: item_id.to_string(), count }
+            }
+            2 => {
+                let gp = (self.state.rebirth.gp_earned_this_run * 2.0)
+                    .max(BigNum::from(1000 * self.state.player.level as u64));
+                Objective::EarnGpInRun(gp.floor())
+            }
+            3 if !procs.is_empty() => Objective::TriggerProc {
+                proc: procs[rng.random_range(0..procs.len())],
+                count: rng.random_range(2..=3),
+            },
+            _ => Objective::OpenChests { chest, count: 10 * rng.random_range(2..=5) },
+        };
+
+        let level = self.state.player.level as u64;
+        let reward = match self.state.rng.random_range(0..4) {
+            0 => BountyReward::SkillPoints(1),
+            1 if self.state.rebirth.rebirth_count > 0 => {
+                BountyReward::Essence((self.essence_reward() * 0.25).floor().max(BigNum::from(1u64)))
+            }
+            2 => BountyReward::RelicRoll,
+            _ => BountyReward::Gp(
+                (self.state.rebirth.gp_earned_this_run * 0.1)
+                    .max(BigNum::from(100 * level * level))
+                    .floor(),
+            ),
+        };
+        Bounty::new(objective, reward)
+    }
+
+    pub(super) fn claim_bounty(&mut self, index: usize) {
+        let Some(bounty) = self.state.bounties.bounties.get(index) else {
+            self.reject(Rejection::UnknownId);
+            return;
+        };
+        if !bounty.done {
+            self.reject(Rejection::BountyNotDone);
+            return;
+        }
+
+        let reward = self.state.bounties.bounties.remove(index).reward;
+        self.emit(GameEvent::BountyClaimed {
+            reward: reward.clone(),
+        });
+        match reward {
+            BountyReward::Gp(gp) => {
+                self.state.player.gp += gp;
+                self.state.stats.total_gp_earned += gp;
+                self.state.rebirth.gp_earned_this_run += gp;
+            }
+            BountyReward::SkillPoints(n) => self.state.skill_tree.skill_points += n,
+            BountyReward::Essence(essence) => {
+                self.state.rebirth.essence += essence;
+                self.state.rebirth.total_essence_earned += essence;
+            }
+            BountyReward::RelicRoll => {
+                let best = self.state.unlocked_chests.iter().map(|c| c.index()).max().unwrap_or(0);
+                self.grant_random_relic(best.max(RELIC_ROLL_MIN_CHEST.index()));
+            }
+        }
+        let bounty = self.new_bounty();
+        self.state.bounties.bounties.insert(index, bounty);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::data::challenges::{ChallengeGoal, get_challenge};
+use crate::game::bignum::BigNum;
+use crate::game::events::{GameEvent, Rejection};
+use crate::game::offline::TICKS_PER_SEC;
+
+use super::GameEngine;
+
+impl GameEngine {
+    pub(super) fn start_challenge(&mut self, id: &str) {
+        let Some(challenge) = get_challenge(id) else {
+            self.reject(Rejection::UnknownId);
+            return;
+        };
+        if self.state.rebirth.challenge.is_some() {
+            self.reject(Rejection::ChallengeRunning);
+            return;
+        }
+        let level = self.state.player.level;
+        if !self.state.rebirth.can_rebirth(level) {
+            self.reject(Rejection::RebirthLevelTooLow {
+                required: self.state.rebirth.min_level_for_rebirth(),
+                current: level,
+            });
+            return;
+        }
+        self.perform_rebirth(Some(challenge.id));
+        self.emit(GameEvent::ChallengeStarted {
+            id: challenge.id.to_string(),
+        });
+    }
+
+    pub(super) fn abandon_challenge(&mut self) {
+        if self.state.rebirth.challenge.is_none() {
+            self.reject(Rejection::NoChallenge);
+            return;
+        }
+        self.end_challenge(false);
+    }
+
+    pub(super) fn run_challenge_clock(&mut self, ticks: u32) {
+        if let Some(challenge) = &mut self.state.rebirth.challenge {
+            challenge.ticks += ticks as u64;
+        }
+    }
+
+    /// Complete the running challenge once its goal is met, or fail it
+    /// when its time is up.
+    pub(super) fn check_challenge(&mut self) {
+        let Some(active) = &self.state.rebirth.challenge else {
+            return;
+        };
+        let Some(challenge) = get_challenge(&active.id) else {
+            // Dropped from the data pack; nothing left to play for
+            self.state.rebirth.challenge = None;
+            return;
+        };
+        let met = match challenge.goal {
+            ChallengeGoal::Level(level) => self.state.player.level >= level,
+            ChallengeGoal::Gp(gp) => self.state.rebirth.gp_earned_this_run >= BigNum::from(gp),
+        };
+        if met {
+            self.end_challenge(true);
+        } else if active.ticks >= challenge.time_limit_secs * TICKS_PER_SEC {
+            self.end_challenge(false);
+        }
+    }
+
+    /// Lift the running challenge's rules, recording it as completed if it
+    /// succeeded.
+    pub(super) fn end_challenge(&mut self, completed: bool) {
+        let Some(active) = self.state.rebirth.challenge.take() else {
+            return;
+        };
+        if completed {
+            self.state.rebirth.challenges_completed.insert(active.id.clone());
+            self.emit(GameEvent::ChallengeCompleted {
+                id: active.id,
+                secs: active.ticks / TICKS_PER_SEC,
+            });
+        } else {
+            self.emit(GameEvent::ChallengeFailed { id: active.id });
+        }
+        self.recalculate_player_stats();
+        // Head starts the challenge held back are this run's to keep
+        let mods = self.modifiers();
+        for ct in self.unlock_start_chests(&mods) {
+            self.emit(GameEvent::ChestUnlocked(ct));
+        }
+        self.check_chest_unlocks();
+    }
+}
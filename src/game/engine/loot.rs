@@ -0,0 +1,400 @@
+use rand::Rng;
+
+use crate::data::affixes::{AffixDef, AffixKind, affix_chances, affix_pool_for};
+use crate::data::chests::loot_table_for;
+use crate::data::effects::{Effect, RARE_PLUS, Stage};
+use crate::data::items::get_item;
+use crate::data::relics::relic_stat_totals;
+use crate::game::bignum::BigNum;
+use crate::game::events::{GameEvent, LootSource};
+use crate::game::item::{ItemDef, ItemInstance, Rarity, affixed_name};
+use crate::game::modifiers::Modifiers;
+
+use super::GameEngine;
+
+/// One chest's find on its way through the modifier pipeline.
+struct LootRoll {
+    rarity: Rarity,
+    is_crit: bool,
+    base_gp: f64,
+    base_xp: f64,
+    /// Fixed crit multiplier, replaced by chaotic crits.
+    crit_base: f64,
+    /// Added to every crit multiplier, chaotic or not.
+    crit_bonus: f64,
+    crit_mult: f64,
+    /// Extra multiplier from cascading crits.
+    cascade: f64,
+    /// Kept as `BigNum` so a large multiplier stack keeps growing instead
+    /// of overflowing `f64`.
+    gp: BigNum,
+    xp: BigNum,
+    recycled: bool,
+    recycle_gp: f64,
+}
+
+impl GameEngine {
+    pub(super) fn roll_loot(&mut self, mods: &Modifiers) {
+
+        // Rare luck only weights the loot table
+        let rare_luck = mods.sum(|e| match e {
+            Effect::RareLuck(v) => Some(*v),
+            _ => None,
+        });
+        let Some(item_def) = self.pick_item(self.state.player.luck + rare_luck) else {
+            return;
+        };
+
+        let relic_totals = relic_stat_totals(&self.state.relics);
+        let mut roll = LootRoll {
+            rarity: item_def.rarity,
+            is_crit: false,
+            base_gp: item_def.base_gp as f64,
+            base_xp: item_def.base_xp as f64,
+            crit_base: 2.5,
+            crit_bonus: relic_totals.crit_mult,
+            crit_mult: 1.0,
+            cascade: 1.0,
+            gp: BigNum::ZERO,
+            xp: BigNum::ZERO,
+            recycled: false,
+            recycle_gp: 0.0,
+        };
+
+        for stage in Stage::ALL {
+            for (effect, n) in mods.stage(stage) {
+                self.apply_loot_effect(mods, &mut roll, effect, n as f64);
+            }
+            self.settle_loot_stage(stage, &mut roll);
+        }
+
+        let item_rarity = roll.rarity;
+        let instance = self.make_instance(
+            item_def,
+            item_rarity,
+            roll.gp.floor().max(BigNum::from(1u64)),
+            roll.xp.floor(),
+            roll.is_crit,
+        );
+        let gp_value = instance.gp_value;
+        let xp_value = instance.xp_value;
+
+        // Update stats
+        self.state.stats.chests_opened += 1;
+        self.state.stats.items_found += 1;
+        self.state.stats.total_gp_earned += gp_value;
+        self.state.stats.total_xp_earned += xp_value;
+        self.state.rebirth.gp_earned_this_run += gp_value;
+        if gp_value > self.state.stats.highest_single_gp {
+            self.state.stats.highest_single_gp = gp_value;
+        }
+        match item_rarity {
+            Rarity::Rare => self.state.stats.rares_found += 1,
+            Rarity::Epic => self.state.stats.epics_found += 1,
+            Rarity::Legendary => self.state.stats.legendaries_found += 1,
+            Rarity::Mythic => self.state.stats.mythics_found += 1,
+            Rarity::Divine => self.state.stats.divines_found += 1,
+            _ => {}
+        }
+
+        // Update highest level ever
+        if self.state.player.level > self.state.rebirth.highest_level_ever {
+            self.state.rebirth.highest_level_ever = self.state.player.level;
+        }
+
+        // Award GP and XP
+        self.state.player.gp += gp_value;
+        self.award_xp(xp_value);
+
+        // Check relic drop
+        self.try_relic_drop(mods, item_rarity);
+
+        // Recycled items pay out their bonus instead of going to the inventory
+        if roll.recycled {
+            let bonus = (gp_value * roll.recycle_gp).floor();
+            self.state.player.gp += bonus;
+            self.state.stats.total_gp_earned += bonus;
+            self.state.rebirth.gp_earned_this_run += bonus;
+        }
+
+        self.announce_drop(&instance, LootSource::Chest);
+
+        // Store in chest progress for display
+        self.state.chest_progress.last_item = Some(instance.clone());
+
+        if !roll.recycled {
+            self.store_drop(mods, instance);
+        }
+
+        let drop_rates = mods.drop_rate_mult();
+
+        // Multi-drop: second item from the same table
+        let multi_chance = (mods.sum(|e| match e {
+            Effect::MultiDrop(v) => Some(*v),
+            _ => None,
+        }) + relic_totals.multi_drop)
+            * drop_rates;
+        if multi_chance > 0.0 && self.state.rng.random::<f64>() < multi_chance {
+            self.roll_bonus_item(mods);
+        }
+
+        // Scavenge: bonus Common item
+        let scav_chance = mods.sum(|e| match e {
+            Effect::Scavenge(v) => Some(*v),
+            _ => None,
+        }) * drop_rates;
+        if scav_chance > 0.0 && self.state.rng.random::<f64>() < scav_chance {
+            self.roll_scavenger_item(mods);
+        }
+    }
+
+    /// Apply one effect to a loot roll. Effects that don't touch loot are ignored.
+    fn apply_loot_effect(&mut self, mods: &Modifiers, roll: &mut LootRoll, effect: &Effect, n: f64) {
+        let counters = &mut self.state.counters;
+        match *effect {
+            // === Base ===
+            Effect::RarityUpgrade { chance, chaotic } => {
+                let chance = chance * n * if chaotic { mods.chaos_mult() } else { 1.0 };
+                if self.state.rng.random::<f64>() < chance {
+                    roll.rarity = roll.rarity.next_tier();
+                }
+            }
+            Effect::PityRare { after } => {
+                if roll.rarity == Rarity::Common {
+                    counters.empty_streak += 1;
+                } else {
+                    counters.empty_streak = 0;
+                }
+                let threshold = (after as f64 / mods.chaos_mult()).ceil() as u32;
+                if counters.empty_streak >= threshold {
+                    if matches!(roll.rarity, Rarity::Common | Rarity::Uncommon) {
+                        roll.rarity = Rarity::Rare;
+                    }
+                    counters.empty_streak = 0;
+                }
+            }
+            Effect::BaseGp(v) => roll.base_gp *= 1.0 + v * n,
+            Effect::BaseCritMult(v) => roll.crit_base += v * n,
+
+            // === Additive ===
+            Effect::CritMult(v) => roll.crit_bonus += v * n,
+
+            // === Multiplicative ===
+            Effect::LootGp(v) => roll.gp *= 1.0 + v * n,
+            Effect::LootXp(v) => roll.xp *= 1.0 + v * n,
+            Effect::RarityGp { rarities, bonus } if rarities.contains(&roll.rarity) => {
+                roll.gp *= 1.0 + bonus * n;
+            }
+            Effect::RarityXp { rarities, bonus } if rarities.contains(&roll.rarity) => {
+                roll.xp *= 1.0 + bonus * n;
+            }
+            Effect::CritXp(v) if roll.is_crit => roll.xp *= 1.0 + v * n,
+            Effect::ChestTierGp(v) => {
+                let tier = self.state.current_chest_type.index() as f64;
+                roll.gp *= 1.0 + tier * v * n;
+            }
+            Effect::RareStreakGp(v) if counters.rare_streak_count > 1 => {
+                roll.gp *= 1.0 + (counters.rare_streak_count - 1) as f64 * v * n;
+            }
+            Effect::CritComboGp(v) if roll.is_crit && counters.consecutive_crits > 1 => {
+                roll.gp *= 1.0 + (counters.consecutive_crits - 1) as f64 * v * n;
+            }
+            Effect::SellStackGp(v) => roll.gp *= 1.0 + counters.catalyst_stacks * v * n,
+            Effect::XpSurge { every, mult } if counters.chests_since_xp_surge >= every => {
+                counters.chests_since_xp_surge = 0;
+                roll.xp *= mult;
+            }
+
+            // === Proc ===
+            Effect::Jackpot {
+                proc,
+                chance,
+                gp,
+                xp,
+                chaotic,
+            } => {
+                let mut chance = chance * n * if chaotic { mods.chaos_mult() } else { 1.0 };
+                if let Some(p) = proc {
+                    chance += mods.sum(|e| match e {
+                        Effect::JackpotChance { proc, bonus } if *proc == p => Some(*bonus),
+                        _ => None,
+                    });
+                }
+                if self.state.rng.random::<f64>() < chance {
+                    roll.gp *= gp;
+                    roll.xp *= xp;
+                    if let Some(p) = proc {
+                        self.emit(GameEvent::Proc(p));
+                    }
+                }
+            }
+            Effect::Variance(v) => {
+                let variance = 1.0 - v + self.state.rng.random::<f64>() * 2.0 * v;
+                roll.gp *= variance;
+                roll.xp *= variance;
+            }
+            Effect::DoubleOrNothing { double, nothing } => {
+                let don_roll = self.state.rng.random::<f64>();
+                if don_roll < double {
+                    roll.gp *= 2.0;
+                    roll.xp *= 2.0;
+                } else if don_roll < double + nothing {
+                    roll.gp = BigNum::ZERO;
+                    roll.xp = BigNum::ZERO;
+                }
+            }
+            Effect::ChaosSurge { ticks, bonus } => {
+                let buff_type = (self.state.rng.random::<f64>() * 3.0) as u8;
+                let counters = &mut self.state.counters;
+                counters.chaos_buff_type = Some(buff_type);
+                counters.chaos_buff_ticks = ticks;
+                match buff_type {
+                    0 => roll.gp *= 1.0 + bonus,
+                    1 => roll.xp *= 1.0 + bonus,
+                    _ => {} // speed buff applied in start_opening
+                }
+            }
+            Effect::ChaoticCrits { min, max } if roll.is_crit => {
+                roll.crit_mult = min + self.state.rng.random::<f64>() * (max - min) + roll.crit_bonus;
+            }
+            Effect::CritCascade(chance) if roll.is_crit => {
+                while self.state.rng.random::<f64>() < chance * n {
+                    roll.cascade *= roll.crit_base + roll.crit_bonus;
+                }
+            }
+
+            // === Final ===
+            Effect::Recycle(_) if mods.recycles(roll.rarity) => roll.recycled = true,
+            Effect::RecycleGp(v) => roll.recycle_gp += v * n,
+
+            _ => {}
+        }
+    }
+
+    /// Work done between pipeline stages, once every effect in `stage` has run.
+    fn settle_loot_stage(&mut self, stage: Stage, roll: &mut LootRoll) {
+        match stage {
+            Stage::Base => {
+                // Rarity is final now: update streaks and roll the crit
+                let counters = &mut self.state.counters;
+                if RARE_PLUS.contains(&roll.rarity) {
+                    counters.rare_streak_count += 1;
+                } else {
+                    counters.rare_streak_count = 0;
+                }
+                counters.chests_since_xp_surge += 1;
+                counters.consecutive_chests += 1;
+
+                roll.is_crit = self.state.rng.random::<f64>() < self.state.player.crit_chance;
+                if roll.is_crit {
+                    self.state.stats.crits_rolled += 1;
+                    self.state.counters.consecutive_crits += 1;
+                } else {
+                    self.state.counters.consecutive_crits = 0;
+                }
+
+                let chest_reward = self.state.current_chest_type.reward_multiplier();
+                roll.gp = BigNum::new(roll.base_gp)
+                    * roll.rarity.gp_multiplier()
+                    * self.state.player.gp_multiplier
+                    * chest_reward;
+                roll.xp = BigNum::new(roll.base_xp)
+                    * roll.rarity.xp_multiplier()
+                    * self.state.player.xp_multiplier
+                    * chest_reward;
+            }
+            Stage::Additive => {
+                if roll.is_crit {
+                    roll.crit_mult = roll.crit_base + roll.crit_bonus;
+                }
+            }
+            Stage::Multiplicative => {}
+            Stage::Proc => {
+                roll.gp = roll.gp * roll.crit_mult * roll.cascade;
+                roll.xp = roll.xp * roll.crit_mult * roll.cascade;
+            }
+            Stage::Final => {}
+        }
+    }
+
+    /// Weighted pick from the current chest's loot table.
+    pub(super) fn pick_item(&mut self, luck: f64) -> Option<&'static ItemDef> {
+        let table = loot_table_for(self.state.current_chest_type);
+        let weighted = table.weighted_entries(luck);
+        let total_weight: f64 = weighted.iter().map(|(_, w)| w).sum();
+        let mut roll: f64 = self.state.rng.random::<f64>() * total_weight;
+
+        let mut chosen_idx = 0;
+        for (idx, weight) in &weighted {
+            roll -= weight;
+            if roll <= 0.0 {
+                chosen_idx = *idx;
+                break;
+            }
+        }
+
+        table
+            .entries
+            .get(chosen_idx)
+            .and_then(|entry| get_item(entry.item_id))
+    }
+
+    /// A new drop, with its affixes rolled and their bonuses in its value.
+    pub(super) fn make_instance(
+        &mut self,
+        item_def: &ItemDef,
+        rarity: Rarity,
+        gp_value: BigNum,
+        xp_value: BigNum,
+        is_crit: bool,
+    ) -> ItemInstance {
+        let affixes = self.roll_affixes(rarity);
+        let gp_bonus: f64 = affixes.iter().map(|a| a.gp_bonus).sum();
+        let xp_bonus: f64 = affixes.iter().map(|a| a.xp_bonus).sum();
+        ItemInstance {
+            id: item_def.id.to_string(),
+            name: affixed_name(item_def.name, &affixes),
+            rarity,
+            gp_value: (gp_value * (1.0 + gp_bonus)).floor(),
+            xp_value: (xp_value * (1.0 + xp_bonus)).floor(),
+            is_crit,
+            count: 1,
+            affixes: affixes.iter().map(|a| a.id.to_string()).collect(),
+        }
+    }
+
+    /// Roll a prefix and a suffix from the current chest's pool, each with a
+    /// chance set by rarity.
+    fn roll_affixes(&mut self, rarity: Rarity) -> Vec<&'static AffixDef> {
+        let (prefix_chance, suffix_chance) = affix_chances(rarity);
+        let pool = affix_pool_for(self.state.current_chest_type);
+        let luck = self.state.player.luck;
+        let mut affixes = Vec::new();
+        for (kind, chance) in [(AffixKind::Prefix, prefix_chance), (AffixKind::Suffix, suffix_chance)] {
+            if chance <= 0.0 || self.state.rng.random::<f64>() >= chance {
+                continue;
+            }
+            let weighted = pool.weighted_entries(kind, luck);
+            let total_weight: f64 = weighted.iter().map(|(_, w)| w).sum();
+            let mut roll = self.state.rng.random::<f64>() * total_weight;
+            for (affix, weight) in weighted {
+                roll -= weight;
+                if roll <= 0.0 {
+                    affixes.push(affix);
+                    break;
+                }
+            }
+        }
+        affixes
+    }
+
+    /// Record a new drop in the collection and report it.
+    pub(super) fn announce_drop(&mut self, item: &ItemInstance, source: LootSource) {
+        self.state.collection.found.insert(item.id.clone());
+        self.emit(GameEvent::ItemFound {
+            item: item.clone(),
+            source,
+            chest: self.state.current_chest_type,
+        });
+    }
+}
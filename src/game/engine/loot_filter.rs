@@ -0,0 +1,79 @@
+use crate::game::events::{Command, GameEvent, Rejection};
+use crate::game::item::ItemInstance;
+use crate::game::loot_filter::FilterAction;
+use crate::game::modifiers::Modifiers;
+
+use super::GameEngine;
+
+impl GameEngine {
+    /// Put a new drop in the inventory, or sell or salvage it if the loot
+    /// filter says so.
+    pub(super) fn store_drop(&mut self, mods: &Modifiers, item: ItemInstance) {
+        let action = if self.state.loot_filter.enabled && mods.loot_filter() {
+            self.state.loot_filter.action_for(&item)
+        } else {
+            FilterAction::Keep
+        };
+        // Challenges that ban selling keep what the filter would have sold
+        let action = if action == FilterAction::Sell && mods.selling_banned() {
+            FilterAction::Keep
+        } else {
+            action
+        };
+        match action {
+            FilterAction::Keep => self.keep_item(item),
+            FilterAction::Sell => {
+                let gp = self.apply_sale_effects(mods, mods.sell_price(&item), 1);
+                self.award_sale_xp(mods, gp);
+                self.emit(GameEvent::DropFiltered {
+                    name: item.name,
+                    action,
+                    value: gp,
+                });
+            }
+            FilterAction::Salvage => {
+                let pct = mods.sell_pct(item.rarity);
+                let xp = (item.xp_value * pct).floor();
+                self.award_xp(xp);
+                self.emit(GameEvent::DropFiltered {
+                    name: item.name,
+                    action,
+                    value: xp,
+                });
+            }
+        }
+    }
+
+    pub(super) fn toggle_loot_filter(&mut self) {
+        if !self.modifiers().loot_filter() {
+            self.reject(Rejection::FilterLocked);
+            return;
+        }
+        let filter = &mut self.state.loot_filter;
+        filter.enabled = !filter.enabled;
+        let enabled = filter.enabled;
+        self.emit(GameEvent::LootFilterToggled { enabled });
+    }
+
+    /// Change one of the loot filter's rules.
+    pub(super) fn edit_loot_filter(&mut self, command: Command) {
+        if !self.modifiers().loot_filter() {
+            self.reject(Rejection::FilterLocked);
+            return;
+        }
+        let filter = &mut self.state.loot_filter;
+        match command {
+            Command::SetRarityFilter(rarity, action) => {
+                filter.rarities.insert(rarity, action);
+            }
+            Command::SetItemFilter(id, Some(action)) => {
+                filter.items.insert(id, action);
+            }
+            Command::SetItemFilter(id, None) => {
+                filter.items.remove(&id);
+            }
+            Command::SetKeepAboveGp(gp) => filter.keep_above_gp = gp,
+            _ => {}
+        }
+    }
+}
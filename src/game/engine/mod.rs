@@ -0,0 +1,754 @@
+use rand::Rng;
+
+use crate::data::effects::{Effect, Stage};
+use crate::data::items::all_items;
+use crate::data::rebirth_skills::get_rebirth_skill;
+use crate::data::recipes::{RecipeDef, get_recipe};
+use crate::data::relics::relic_stat_totals;
+use crate::data::sets::all_item_sets;
+use crate::data::skills::get_skill;
+use crate::data::upgrades::{UpgradeDef, get_upgrade};
+
+use super::bignum::BigNum;
+use super::chest::{ChestProgress, ChestState, ChestType};
+use super::crafting;
+use super::events::{Command, GameEvent, Rejection, RespecTarget};
+use super::inventory::Inventory;
+use super::item::{ItemDef, ItemInstance};
+use super::modifiers::Modifiers;
+use super::player::Player;
+use super::progression::{approx_level_for_xp, total_xp_for_level, xp_for_level};
+use super::rebirth::ActiveChallenge;
+use super::relic::RelicState;
+use super::skill_tree::{SkillTreeState, respec_cost};
+use super::state::{GameState, SkillCounters};
+use super::upgrade::UpgradeState;
+
+mod achievements;
+mod automation;
+mod bonus_drops;
+mod bounties;
+mod challenges;
+mod loot;
+mod loot_filter;
+mod relics;
+mod selling;
+mod transcendence;
+
+/// Headless game rules. Owns the [`GameState`], takes [`Command`]s and
+/// reports what happened as a list of [`GameEvent`]s. Knows nothing about
+/// terminals, sound or animation.
+pub struct GameEngine {
+    pub state: GameState,
+    events: Vec<GameEvent>,
+}
+
+impl GameEngine {
+    pub fn new(state: GameState) -> Self {
+        let mut engine = Self {
+            state,
+            events: Vec::new(),
+        };
+        engine.check_sets();
+        engine.recalculate_player_stats();
+        engine.fill_bounties();
+        engine
+    }
+
+    /// Carry out a player command and return the resulting events.
+    pub fn apply(&mut self, command: Command) -> Vec<GameEvent> {
+        match command {
+            Command::OpenChest => {
+                let mods = self.modifiers();
+                self.start_opening(&mods);
+            }
+            Command::Collect => {
+                if matches!(
+                    self.state.chest_progress.state,
+                    ChestState::Revealing | ChestState::Complete
+                ) {
+                    self.collect_and_reset();
+                }
+            }
+            Command::SelectChest(ct) => self.select_chest(ct),
+            Command::BuyUpgrade(id) => self.buy_upgrade(&id),
+            Command::LearnSkill(id) => self.learn_skill(&id),
+            Command::Respec(target) => self.respec(target),
+            Command::LearnRebirthSkill(id) => self.learn_rebirth_skill(&id),
+            Command::ToggleRelic(id) => self.toggle_relic(&id),
+            Command::UnequipAllRelics => self.unequip_all_relics(),
+            Command::SaveRelicLoadout { slot, name } => self.save_relic_loadout(slot, name),
+            Command::EquipRelicLoadout(slot) => self.equip_relic_loadout(slot),
+            Command::UpgradeRelic(id) => self.upgrade_relic(&id),
+            Command::FuseRelics(ids) => self.fuse_relics(ids),
+            Command::Rebirth => self.rebirth(),
+            Command::Transcend => self.transcend(),
+            Command::LearnTranscendenceSkill(id) => self.learn_transcendence_skill(&id),
+            Command::StartChallenge(id) => self.start_challenge(&id),
+            Command::AbandonChallenge => self.abandon_challenge(),
+            Command::SellItem(index) => self.sell_item(index),
+            Command::SellAll => self.sell_all(),
+            Command::ToggleAutomation => self.toggle_automation(),
+            Command::ToggleLootFilter => self.toggle_loot_filter(),
+            Command::Craft(id) => self.craft(&id),
+            Command::SetTitle(id) => self.set_title(id),
+            Command::ClaimBounty(index) => self.claim_bounty(index),
+            command @ (Command::SetRarityFilter(..)
+            | Command::SetItemFilter(..)
+            | Command::SetKeepAboveGp(_)) => self.edit_loot_filter(command),
+            command @ (Command::AddRule(_)
+            | Command::SetRule(..)
+            | Command::RemoveRule(_)
+            | Command::MoveRule { .. }) => self.edit_rules(command),
+        }
+        self.take_events()
+    }
+
+    /// Advance the simulation by one tick (~1/30 s) and return the resulting events.
+    pub fn tick(&mut self) -> Vec<GameEvent> {
+        let mods = self.modifiers();
+        self.step(&mods);
+        self.run_automation(&mods);
+        self.take_events()
+    }
+
+    /// Advance the simulation by many ticks at once, as while the game was
+    /// closed. Nobody can buy or learn anything meanwhile, and automation
+    /// rules wait for the game to be open, so the modifiers are gathered once.
+    /// Events go to `on_event` as they happen rather than piling up over
+    /// hours of ticks.
+    pub fn fast_forward(&mut self, ticks: u64, mut on_event: impl FnMut(GameEvent)) {
+        let mods = self.modifiers();
+        for _ in 0..ticks {
+            self.step(&mods);
+            self.events.drain(..).for_each(&mut on_event);
+        }
+        self.take_events().into_iter().for_each(on_event);
+    }
+
+    fn step(&mut self, mods: &Modifiers) {
+        // Tick chest progress
+        self.state.chest_progress.tick();
+
+        // Auto-opener
+        if mods.auto_open() && self.state.chest_progress.state == ChestState::Idle {
+            self.start_opening(mods);
+        }
+
+        // Auto-collect after reveal
+        if self.state.chest_progress.state == ChestState::Revealing
+            && mods
+                .auto_collect_after()
+                .is_some_and(|limit| self.state.chest_progress.reveal_ticks > limit)
+        {
+            self.collect_and_reset();
+        }
+
+        // Idle Income: earn GP per second while chest is idle, scales with level + GP multiplier
+        if let Some(idle_rate) = mods.idle_income_every()
+            && self.state.chest_progress.state == ChestState::Idle
+        {
+            self.state.counters.idle_income_ticks += 1;
+            if self.state.counters.idle_income_ticks >= idle_rate {
+                self.state.counters.idle_income_ticks = 0;
+                // Base 5 GP, scaling with level and GP multiplier
+                let base = 5.0 + self.state.player.level as f64 * 2.0;
+                let gp = BigNum::new((base * self.state.player.gp_multiplier).max(1.0)).floor();
+                self.state.player.gp += gp;
+                self.state.stats.total_gp_earned += gp;
+                self.state.rebirth.gp_earned_this_run += gp;
+            }
+        } else {
+            self.state.counters.idle_income_ticks = 0;
+        }
+
+        // Tick chaos buff
+        self.run_down_chaos_buff(1);
+        self.run_challenge_clock(1);
+
+        // Check if chest just finished opening -> roll loot
+        if self.state.chest_progress.state == ChestState::Revealing
+            && self.state.chest_progress.reveal_ticks == 1
+        {
+            self.roll_loot(mods);
+        }
+    }
+
+    /// Open the current chest and collect it at once instead of waiting out
+    /// its ticks, for headless simulation. Returns how many ticks it would
+    /// have taken to open, and what happened.
+    pub fn open_chest_now(&mut self) -> (u32, Vec<GameEvent>) {
+        if self.state.chest_progress.state != ChestState::Idle {
+            self.collect_and_reset();
+        }
+        let mods = self.modifiers();
+        self.start_opening(&mods);
+        let progress = &mut self.state.chest_progress;
+        // Loot rolls the tick after opening finishes, which takes at least one
+        let ticks = progress.ticks_required.saturating_sub(progress.ticks_elapsed).max(1) + 1;
+        progress.ticks_elapsed = progress.ticks_required;
+        progress.state = ChestState::Revealing;
+        progress.reveal_ticks = 1;
+        self.run_down_chaos_buff(ticks);
+        self.run_challenge_clock(ticks);
+        self.roll_loot(&mods);
+        self.collect_and_reset();
+        (ticks, self.take_events())
+    }
+
+    fn run_down_chaos_buff(&mut self, ticks: u32) {
+        let counters = &mut self.state.counters;
+        if counters.chaos_buff_ticks > 0 {
+            counters.chaos_buff_ticks = counters.chaos_buff_ticks.saturating_sub(ticks);
+            if counters.chaos_buff_ticks == 0 {
+                counters.chaos_buff_type = None;
+            }
+        }
+    }
+
+    fn emit(&mut self, event: GameEvent) {
+        self.events.push(event);
+    }
+
+    fn reject(&mut self, reason: Rejection) {
+        self.emit(GameEvent::Rejected(reason));
+    }
+
+    fn take_events(&mut self) -> Vec<GameEvent> {
+        self.check_challenge();
+        self.advance_bounties();
+        self.check_achievements();
+        std::mem::take(&mut self.events)
+    }
+
+    fn select_chest(&mut self, ct: ChestType) {
+        if !self.state.unlocked_chests.contains(&ct) {
+            self.reject(Rejection::ChestLocked(ct));
+            return;
+        }
+        self.state.current_chest_type = ct;
+        let mods = self.modifiers();
+        self.start_opening(&mods);
+    }
+
+    fn learn_skill(&mut self, id: &str) {
+        let Some(skill) = get_skill(id) else {
+            self.reject(Rejection::UnknownId);
+            return;
+        };
+        let cost_mult = self.modifiers().skill_cost_mult();
+        if self.state.skill_tree.learn(skill.id, cost_mult) {
+            self.emit(GameEvent::SkillLearned {
+                id: skill.id.to_string(),
+            });
+            self.recalculate_player_stats();
+            self.check_chest_unlocks();
+        } else if self.state.skill_tree.has_skill(skill.id) {
+            self.reject(Rejection::AlreadyLearned);
+        } else if self.state.skill_tree.skill_points < skill.cost * cost_mult {
+            self.reject(Rejection::NoSkillPoints);
+        } else {
+            self.reject(Rejection::PrerequisitesNotMet);
+        }
+    }
+
+    /// GP the next respec costs.
+    pub fn respec_cost(&self, target: &RespecTarget) -> u64 {
+        respec_cost(
+            self.state.skill_tree.respecs,
+            self.state.player.level,
+            *target == RespecTarget::WholeTree,
+        )
+    }
+
+    fn respec(&mut self, target: RespecTarget) {
+        let tree = &self.state.skill_tree;
+        match &target {
+            RespecTarget::Skill(id) if !tree.has_skill(id) => {
+                self.reject(Rejection::NotLearned);
+                return;
+            }
+            RespecTarget::Skill(id) if !tree.is_leaf(id) => {
+                self.reject(Rejection::SkillInUse);
+                return;
+            }
+            RespecTarget::WholeTree if tree.learned.is_empty() => {
+                self.reject(Rejection::NotLearned);
+                return;
+            }
+            _ => {}
+        }
+        let cost = self.respec_cost(&target);
+        if self.state.player.gp < BigNum::from(cost) {
+            self.reject(Rejection::NotEnoughGp { cost });
+            return;
+        }
+
+        self.state.player.gp -= BigNum::from(cost);
+        let tree = &mut self.state.skill_tree;
+        let points_before = tree.skill_points;
+        let ids = match target {
+            RespecTarget::Skill(id) => {
+                tree.unlearn(&id);
+                vec![id]
+            }
+            RespecTarget::WholeTree => tree.unlearn_all(),
+        };
+        tree.respecs += 1;
+        let points = tree.skill_points - points_before;
+
+        self.recalculate_player_stats();
+        self.revalidate_after_respec();
+        self.emit(GameEvent::SkillsRespecced { ids, points, cost });
+    }
+
+    /// Drop state that only the unlearned skills were keeping alive: their
+    /// counters and any relic slots they granted.
+    fn revalidate_after_respec(&mut self) {
+        let mods = self.modifiers();
+        let counters = &mut self.state.counters;
+        if !mods.any(|e| matches!(e, Effect::SellStackGp(_))) {
+            counters.catalyst_stacks = 0.0;
+        }
+        if !mods.any(|e| matches!(e, Effect::PityRare { .. })) {
+            counters.empty_streak = 0;
+        }
+        if !mods.any(|e| matches!(e, Effect::ChaosSurge { .. })) {
+            counters.chaos_buff_ticks = 0;
+            counters.chaos_buff_type = None;
+        }
+        if !mods.any(|e| matches!(e, Effect::Momentum { .. })) {
+            counters.consecutive_chests = 0;
+        }
+        if mods.idle_income_every().is_none() {
+            counters.idle_income_ticks = 0;
+        }
+        let slots = mods.relic_slots();
+        if self.state.relics.equipped.len() > slots {
+            self.state.relics.equipped.truncate(slots);
+            self.recalculate_player_stats();
+        }
+    }
+
+    fn buy_upgrade(&mut self, id: &str) {
+        let Some(upg) = get_upgrade(id) else {
+            self.reject(Rejection::UnknownId);
+            return;
+        };
+        let cost = match self.upgrade_cost(&self.modifiers(), upg) {
+            Ok(cost) => cost,
+            Err(reason) => {
+                self.reject(reason);
+                return;
+            }
+        };
+        if self.state.player.gp < cost {
+            self.reject(Rejection::NotEnoughGp { cost });
+            return;
+        }
+        let level = self.purchase_upgrade(upg, cost);
+        self.emit(GameEvent::UpgradeBought {
+            id: upg.id.to_string(),
+            level,
+        });
+    }
+
+    /// Cost of the next level of an upgrade, if it can be bought at all.
+    fn upgrade_cost(&self, mods: &Modifiers, upg: &UpgradeDef) -> Result<u64, Rejection> {
+        if mods.upgrades_banned() {
+            return Err(Rejection::BannedByChallenge);
+        }
+        let current_level = self.state.upgrades.get_level(upg.id);
+        if current_level >= upg.max_level {
+            return Err(Rejection::AlreadyMaxed);
+        }
+
+        // Check level requirement for key upgrades
+        let req_level = upg
+            .effects
+            .iter()
+            .filter_map(|e| match e {
+                Effect::ChestKey { min_level, .. } => Some(*min_level),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(1);
+        if self.state.player.level < req_level {
+            return Err(Rejection::LevelTooLow { required: req_level });
+        }
+
+        Ok(upg.cost_at_level(current_level))
+    }
+
+    /// Pay for the next level of an upgrade and return the new level.
+    fn purchase_upgrade(&mut self, upg: &UpgradeDef, cost: u64) -> u32 {
+        self.state.player.gp -= BigNum::from(cost);
+        self.state.upgrades.increment(upg.id);
+        self.recalculate_player_stats();
+        self.check_chest_unlocks();
+        self.state.upgrades.get_level(upg.id)
+    }
+
+    fn learn_rebirth_skill(&mut self, id: &str) {
+        let Some(skill) = get_rebirth_skill(id) else {
+            self.reject(Rejection::UnknownId);
+            return;
+        };
+        if self.state.rebirth.has_rebirth_skill(skill.id) {
+            self.reject(Rejection::AlreadyLearned);
+        } else if self.state.rebirth.learn_rebirth_skill(skill.id) {
+            self.emit(GameEvent::RebirthSkillLearned {
+                id: skill.id.to_string(),
+            });
+            // Stat bonuses apply now; head starts wait for the next run
+            self.recalculate_player_stats();
+        } else if self.state.rebirth.essence < skill.essence_cost {
+            self.reject(Rejection::NotEnoughEssence {
+                cost: skill.essence_cost,
+            });
+        } else {
+            self.reject(Rejection::PrerequisitesNotMet);
+        }
+    }
+
+    fn rebirth(&mut self) {
+        let level = self.state.player.level;
+        if !self.state.rebirth.can_rebirth(level) {
+            self.reject(Rejection::RebirthLevelTooLow {
+                required: self.state.rebirth.min_level_for_rebirth(),
+                current: level,
+            });
+            return;
+        }
+        self.perform_rebirth(None);
+    }
+
+    fn start_opening(&mut self, mods: &Modifiers) {
+        if self.state.chest_progress.state != ChestState::Idle {
+            return;
+        }
+
+        // Use player speed for all opening (manual and auto)
+        let mut speed = self.state.player.speed;
+
+        // Momentum: each consecutive chest opens faster, up to a cap
+        for (effect, n) in mods.iter() {
+            if let Effect::Momentum { per_chest, max } = effect {
+                let bonus = (self.state.counters.consecutive_chests as f64 * per_chest * n as f64)
+                    .min(*max);
+                speed *= 1.0 + bonus;
+            }
+        }
+
+        // Chaos buff: speed
+        if self.state.counters.chaos_buff_type == Some(2) && self.state.counters.chaos_buff_ticks > 0 {
+            speed *= 1.0 + mods.chaos_surge_bonus();
+        }
+
+        if let Some(cap) = mods.speed_cap() {
+            speed = speed.min(cap);
+        }
+
+        self.emit(GameEvent::ChestStarted(self.state.current_chest_type));
+        self.state
+            .chest_progress
+            .start_opening(self.state.current_chest_type, speed);
+
+        // Instant open procs
+        for (effect, n) in mods.stage(Stage::Proc) {
+            if let Effect::InstantOpen(chance) = effect
+                && self.state.rng.random::<f64>() < chance * n as f64
+            {
+                self.state.chest_progress.ticks_elapsed = self.state.chest_progress.ticks_required;
+            }
+        }
+    }
+
+    fn award_xp(&mut self, xp: BigNum) {
+        self.state.player.xp += xp;
+
+        // Huge awards would take forever a level at a time: jump to just
+        // short of the new level, then finish below
+        let level = self.state.player.level;
+        let target = approx_level_for_xp(level, self.state.player.xp).saturating_sub(1);
+        if target > level.saturating_add(1) {
+            let cost = total_xp_for_level(target) - total_xp_for_level(level);
+            if cost <= self.state.player.xp {
+                self.state.player.xp -= cost;
+                self.state.player.level = target;
+                self.state.player.xp_to_next = xp_for_level(target);
+                // 1 skill point per level, as below
+                let points = &mut self.state.skill_tree.skill_points;
+                *points = points.saturating_add(target - level);
+                self.emit(GameEvent::LevelUp { level: target });
+                let highest = &mut self.state.rebirth.highest_level_ever;
+                *highest = (*highest).max(target);
+                self.check_chest_unlocks();
+            }
+        }
+
+        while self.state.player.xp >= self.state.player.xp_to_next
+            && self.state.player.level < u32::MAX
+        {
+            self.state.player.xp -= self.state.player.xp_to_next;
+            self.state.player.level += 1;
+            self.state.player.xp_to_next = xp_for_level(self.state.player.level);
+            // Grant 1 skill point per level
+            self.state.skill_tree.skill_points = self.state.skill_tree.skill_points.saturating_add(1);
+            self.emit(GameEvent::LevelUp {
+                level: self.state.player.level,
+            });
+            // Update highest level
+            if self.state.player.level > self.state.rebirth.highest_level_ever {
+                self.state.rebirth.highest_level_ever = self.state.player.level;
+            }
+            self.check_chest_unlocks();
+        }
+    }
+
+    fn check_chest_unlocks(&mut self) {
+        let mods = self.modifiers();
+        let reduction = mods.chest_level_reduction();
+        for ct in ChestType::ALL {
+            if !self.state.unlocked_chests.contains(&ct) {
+                let level_req = ct.required_level().saturating_sub(reduction);
+                let meets_level = self.state.player.level >= level_req;
+
+                if meets_level && mods.has_chest_key(ct) && !mods.chest_banned(ct) {
+                    self.state.unlocked_chests.push(ct);
+                    self.emit(GameEvent::ChestUnlocked(ct));
+                }
+            }
+        }
+    }
+
+    fn collect_and_reset(&mut self) {
+        self.state.chest_progress.collect();
+        self.emit(GameEvent::ChestCollected);
+    }
+
+    /// Reset the run, paying out essence, and start `challenge` if given. A
+    /// challenge still running ends as failed.
+    fn perform_rebirth(&mut self, challenge: Option<&str>) {
+        self.end_challenge(false);
+        let level = self.state.player.level;
+        let relics_equipped = self.state.relics.equipped.len();
+
+        // Calculate essence reward based on current GP
+        let essence = self.essence_reward();
+
+        // Award essence
+        self.state.rebirth.essence += essence;
+        self.state.rebirth.total_essence_earned += essence;
+        self.state.rebirth.rebirth_count += 1;
+
+        // Update highest level
+        if level > self.state.rebirth.highest_level_ever {
+            self.state.rebirth.highest_level_ever = level;
+        }
+
+        self.reset_run(challenge);
+
+        self.emit(GameEvent::Rebirth {
+            count: self.state.rebirth.rebirth_count,
+            essence,
+            relics_equipped,
+        });
+    }
+
+    /// Start a fresh run under `challenge`, keeping only what outlasts a
+    /// rebirth.
+    fn reset_run(&mut self, challenge: Option<&str>) {
+        // Reset run-specific state
+        self.state.rebirth.gp_earned_this_run = BigNum::ZERO;
+
+        // Reset player to defaults
+        self.state.player = Player::default();
+
+        // Reset inventory, upgrades, skills, chests, chest progress
+        self.state.inventory = Inventory::default();
+        self.state.upgrades = UpgradeState::default();
+        self.state.skill_tree = SkillTreeState::default();
+        self.state.chest_progress = ChestProgress::default();
+        self.state.current_chest_type = ChestType::Wooden;
+        self.state.unlocked_chests = vec![ChestType::Wooden];
+        // Loadouts are the player's own setup, so they outlast the relics
+        let loadouts = std::mem::take(&mut self.state.relics.loadouts);
+        self.state.relics = RelicState::default();
+        self.state.relics.loadouts = loadouts;
+
+        // Reset skill counters
+        self.state.counters = SkillCounters::default();
+
+        // Challenge rules apply from the very start of the run
+        self.state.rebirth.challenge = challenge.map(|id| ActiveChallenge {
+            id: id.to_string(),
+            ticks: 0,
+        });
+
+        // Apply rebirth bonuses
+        self.recalculate_player_stats();
+        self.apply_run_start_bonuses();
+        self.check_chest_unlocks();
+
+        // Unfinished bounties may name chests that are locked again
+        self.state.bounties.refreshed_at = self.state.stats.chests_opened;
+        self.replace_unfinished_bounties();
+    }
+
+    /// Hand out the head starts that rebirth skills grant at the start of a run.
+    fn apply_run_start_bonuses(&mut self) {
+        let mods = self.modifiers();
+        for (effect, _) in mods.iter() {
+            match *effect {
+                Effect::StartLevel(level) if self.state.player.level < level => {
+                    // One skill point per level skipped
+                    self.state.skill_tree.skill_points += level - self.state.player.level;
+                    self.state.player.level = level;
+                    self.state.player.xp_to_next = xp_for_level(level);
+                }
+                Effect::StartGp(gp) => self.state.player.gp += BigNum::from(gp),
+                _ => {}
+            }
+        }
+        self.unlock_start_chests(&mods);
+    }
+
+    /// Unlock the chests rebirth skills hand out at the start of a run,
+    /// unless a challenge rule bans them. Returns the ones newly unlocked.
+    fn unlock_start_chests(&mut self, mods: &Modifiers) -> Vec<ChestType> {
+        let mut unlocked = Vec::new();
+        for (effect, _) in mods.iter() {
+            let Effect::StartChests(chests) = *effect else {
+                continue;
+            };
+            for &ct in chests {
+                if !self.state.unlocked_chests.contains(&ct) && !mods.chest_banned(ct) {
+                    self.state.unlocked_chests.push(ct);
+                    unlocked.push(ct);
+                }
+            }
+        }
+        unlocked
+    }
+
+    /// Essence a rebirth would pay out right now.
+    pub fn essence_reward(&self) -> BigNum {
+        let mods = self.modifiers();
+        self.state.rebirth.calculate_essence_reward(
+            self.state.player.gp,
+            mods.essence_mult(),
+            mods.essence_exponent_bonus(),
+        )
+    }
+
+    /// Complete every unfinished set whose pieces are all in the inventory.
+    /// Returns whether any was completed, so the caller can recalculate
+    /// player stats once.
+    fn check_sets(&mut self) -> bool {
+        let mut completed_any = false;
+        for set in all_item_sets() {
+            if self.state.collection.is_complete(set.id) {
+                continue;
+            }
+            let ids = set.item_ids();
+            let inventory = &self.state.inventory.items;
+            if ids.is_empty() || !ids.iter().all(|id| inventory.iter().any(|i| i.id == *id)) {
+                continue;
+            }
+            self.state.collection.completed.insert(set.id.to_string());
+            self.emit(GameEvent::SetCompleted {
+                id: set.id.to_string(),
+            });
+            completed_any = true;
+        }
+        completed_any
+    }
+
+    /// Put an item in the inventory and apply what holding it changes.
+    fn keep_item(&mut self, item: ItemInstance) {
+        let affixed = !item.affixes.is_empty();
+        self.state.inventory.add(item);
+        if self.check_sets() || affixed {
+            self.recalculate_player_stats();
+        }
+    }
+
+    /// Success chance for a recipe: its base chance plus luck and Alchemy
+    /// bonuses.
+    pub fn craft_chance(&self, recipe: &RecipeDef) -> f64 {
+        let luck_bonus = self.state.player.luck * 0.005;
+        (recipe.base_chance + luck_bonus + self.modifiers().craft_chance_bonus()).min(1.0)
+    }
+
+    fn craft(&mut self, id: &str) {
+        let Some(recipe) = get_recipe(id) else {
+            self.reject(Rejection::UnknownId);
+            return;
+        };
+        let Some(plan) = crafting::plan(recipe, &self.state.inventory) else {
+            self.reject(Rejection::MissingIngredients);
+            return;
+        };
+        let chance = self.craft_chance(recipe);
+
+        // Use up the inputs, last stack first so the other indices stay put
+        let mut affixed = false;
+        for &(index, count) in plan.iter().rev() {
+            let stack = &mut self.state.inventory.items[index];
+            stack.count -= count;
+            if stack.count == 0 {
+                affixed |= !stack.affixes.is_empty();
+                self.state.inventory.items.remove(index);
+            }
+        }
+        if affixed {
+            self.recalculate_player_stats();
+        }
+
+        let candidates: Vec<&ItemDef> =
+            all_items().iter().filter(|i| i.rarity == recipe.output).collect();
+        if candidates.is_empty() || self.state.rng.random::<f64>() >= chance {
+            self.emit(GameEvent::Crafted {
+                recipe: recipe.id.to_string(),
+                item: None,
+            });
+            return;
+        }
+        let item_def = candidates[self.state.rng.random_range(0..candidates.len())];
+        let chest_reward = self.state.current_chest_type.reward_multiplier();
+        let gp_value = (BigNum::from(item_def.base_gp)
+            * item_def.rarity.gp_multiplier()
+            * self.state.player.gp_multiplier
+            * chest_reward).floor();
+        let xp_value = (BigNum::from(item_def.base_xp)
+            * item_def.rarity.xp_multiplier()
+            * self.state.player.xp_multiplier
+            * chest_reward).floor();
+        let item = self.make_instance(item_def, item_def.rarity, gp_value, xp_value, false);
+
+        self.state.collection.found.insert(item.id.clone());
+        self.emit(GameEvent::Crafted {
+            recipe: recipe.id.to_string(),
+            item: Some(item.clone()),
+        });
+        self.keep_item(item);
+    }
+
+    /// All effects currently in play.
+    pub fn modifiers(&self) -> Modifiers {
+        Modifiers::gather(&self.state)
+    }
+
+    pub fn recalculate_player_stats(&mut self) {
+        let relic_totals = relic_stat_totals(&self.state.relics);
+        let mods = self.modifiers();
+        self.state
+            .player
+            .recalculate_stats(mods.base_stats(), mods.player_stats(&relic_totals));
+    }
+
+    pub fn has_chest_key(&self, ct: ChestType) -> bool {
+        self.modifiers().has_chest_key(ct)
+    }
+}
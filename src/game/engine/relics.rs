@@ -0,0 +1,278 @@
+use rand::Rng;
+
+use crate::data::effects::Effect;
+use crate::data::relics::{self, relic_stat_totals};
+use crate::game::chest::ChestType;
+use crate::game::events::{GameEvent, Rejection};
+use crate::game::item::Rarity;
+use crate::game::loadout::Loadout;
+use crate::game::modifiers::Modifiers;
+use crate::game::relic::{RelicState, level_up_cost, shards_spent};
+
+use super::GameEngine;
+
+impl GameEngine {
+    pub(super) fn toggle_relic(&mut self, id: &str) {
+        if self.state.relics.is_equipped(id) {
+            self.state.relics.unequip(id);
+            self.emit(GameEvent::RelicUnequipped { id: id.to_string() });
+        } else if !self.state.relics.owns(id) {
+            self.reject(Rejection::UnknownId);
+            return;
+        } else if self.modifiers().relics_locked() {
+            self.reject(Rejection::BannedByChallenge);
+            return;
+        } else if self.state.relics.equipped.len() < self.max_equipped_relics() {
+            self.state.relics.equipped.push(id.to_string());
+            self.emit(GameEvent::RelicEquipped { id: id.to_string() });
+        } else {
+            self.reject(Rejection::RelicSlotsFull);
+            return;
+        }
+        self.recalculate_player_stats();
+    }
+
+    pub(super) fn upgrade_relic(&mut self, id: &str) {
+        let Some(relic) = relics::get_relic(id).filter(|_| self.state.relics.owns(id)) else {
+            self.reject(Rejection::UnknownId);
+            return;
+        };
+        let level = self.state.relics.level(id);
+        let Some(cost) = level_up_cost(level) else {
+            self.reject(Rejection::AlreadyMaxed);
+            return;
+        };
+        if self.state.relics.shards(relic.rarity) < cost {
+            self.reject(Rejection::NotEnoughShards {
+                rarity: relic.rarity,
+                cost,
+            });
+            return;
+        }
+
+        *self.state.relics.shards.entry(relic.rarity).or_default() -= cost;
+        self.state.relics.levels.insert(id.to_string(), level + 1);
+        self.emit(GameEvent::RelicUpgraded {
+            id: id.to_string(),
+            level: level + 1,
+        });
+        self.recalculate_player_stats();
+    }
+
+    pub(super) fn fuse_relics(&mut self, ids: Vec<String>) {
+        // Distinct owned relics, all of one rarity that fuses in this number
+        let mut rarities = Vec::new();
+        for (i, id) in ids.iter().enumerate() {
+            let relic = relics::get_relic(id)
+                .filter(|_| self.state.relics.owns(id) && !ids[..i].contains(id));
+            let Some(relic) = relic else {
+                self.reject(Rejection::UnknownId);
+                return;
+            };
+            rarities.push(relic.rarity);
+        }
+        let Some(&rarity) = rarities.first() else {
+            self.reject(Rejection::UnknownId);
+            return;
+        };
+        if rarities.iter().any(|&r| r != rarity) || relics::fusion_inputs(rarity) != Some(ids.len()) {
+            self.reject(Rejection::InvalidFusion);
+            return;
+        }
+        let candidates: Vec<_> = relics::all_relics()
+            .iter()
+            .filter(|r| r.rarity == rarity.next_tier())
+            .collect();
+        if candidates.is_empty() {
+            self.reject(Rejection::InvalidFusion);
+            return;
+        }
+
+        // Inputs go, equipped or not; shards spent leveling them come back
+        let refund: u32 = ids.iter().map(|id| shards_spent(self.state.relics.level(id))).sum();
+        if refund > 0 {
+            *self.state.relics.shards.entry(rarity).or_default() += refund;
+        }
+        for id in &ids {
+            self.state.relics.remove(id);
+        }
+
+        // Prefer a relic the player doesn't have yet
+        let unowned: Vec<_> = candidates
+            .iter()
+            .copied()
+            .filter(|r| !self.state.relics.owns(r.id))
+            .collect();
+        let pool = if unowned.is_empty() { candidates } else { unowned };
+        let result = pool[self.state.rng.random_range(0..pool.len())];
+        let duplicate = self.state.relics.owns(result.id);
+        if duplicate {
+            *self.state.relics.shards.entry(result.rarity).or_default() += 1;
+        } else {
+            self.state.relics.add_relic(result.id.to_string());
+        }
+
+        self.emit(GameEvent::RelicsFused {
+            inputs: ids,
+            id: result.id.to_string(),
+            duplicate,
+        });
+        self.recalculate_player_stats();
+    }
+
+    pub(super) fn unequip_all_relics(&mut self) {
+        if self.state.relics.equipped.is_empty() {
+            self.reject(Rejection::NoRelicsEquipped);
+            return;
+        }
+
+        let count = self.state.relics.equipped.len();
+        self.state.relics.equipped.clear();
+        self.recalculate_player_stats();
+        self.emit(GameEvent::RelicsUnequipped { count });
+    }
+
+    pub(super) fn save_relic_loadout(&mut self, slot: usize, name: String) {
+        if slot >= RelicState::MAX_LOADOUTS {
+            self.reject(Rejection::UnknownId);
+            return;
+        }
+        if self.state.relics.equipped.is_empty() {
+            self.reject(Rejection::NoRelicsEquipped);
+            return;
+        }
+
+        let relics = &mut self.state.relics;
+        if relics.loadouts.len() <= slot {
+            relics.loadouts.resize(slot + 1, None);
+        }
+        relics.loadouts[slot] = Some(Loadout { name: name.clone(), ids: relics.equipped.clone() });
+        self.emit(GameEvent::RelicLoadoutSaved { slot, name });
+    }
+
+    pub(super) fn equip_relic_loadout(&mut self, slot: usize) {
+        let Some(loadout) = self.state.relics.loadout(slot).cloned() else {
+            self.reject(Rejection::EmptyLoadout);
+            return;
+        };
+
+        // Fill the slots in the saved order, skipping relics lost since
+        let max = self.max_equipped_relics();
+        let mut equipped = Vec::new();
+        let mut missing = Vec::new();
+        let mut no_room = Vec::new();
+        for id in loadout.ids {
+            if !self.state.relics.owns(&id) {
+                missing.push(id);
+            } else if equipped.len() >= max {
+                no_room.push(id);
+            } else {
+                equipped.push(id);
+            }
+        }
+        self.state.relics.equipped = equipped;
+        self.recalculate_player_stats();
+        self.emit(GameEvent::RelicLoadoutEquipped { slot, name: loadout.name, missing, no_room });
+    }
+
+    pub(super) fn try_relic_drop(&mut self, mods: &Modifiers, item_rarity: Rarity) {
+        let drop_chance = self.relic_chance(mods, item_rarity);
+        if drop_chance > 0.0 && self.state.rng.random::<f64>() < drop_chance {
+            self.grant_random_relic(self.state.current_chest_type.index());
+        }
+    }
+
+    /// Chance that finding an item of `item_rarity` in the current chest
+    /// also drops a relic.
+    pub fn relic_drop_chance(&self, item_rarity: Rarity) -> f64 {
+        self.relic_chance(&self.modifiers(), item_rarity)
+    }
+
+    fn relic_chance(&self, mods: &Modifiers, item_rarity: Rarity) -> f64 {
+        // Relics drop from higher chests: Uncommon and Rare items roll them
+        // from Silver up, Epic and Legendary from Gold up, and Mythic and
+        // Divine only from Celestial up
+        let chest_tier = self.state.current_chest_type.index();
+        let base_drop_chance = match item_rarity {
+            Rarity::Uncommon if chest_tier >= ChestType::Silver.index() => 0.05,
+            Rarity::Rare if chest_tier >= ChestType::Silver.index() => 0.08,
+            Rarity::Epic if chest_tier >= ChestType::Gold.index() => 0.08,
+            Rarity::Legendary if chest_tier >= ChestType::Gold.index() => 0.20,
+            Rarity::Mythic | Rarity::Divine if chest_tier >= ChestType::Celestial.index() => 0.30,
+            _ => return 0.0,
+        };
+
+        let relic_totals = relic_stat_totals(&self.state.relics);
+        let drop_mult = mods.product(|e| match e {
+            Effect::RelicDropMult(v) => Some(*v),
+            _ => None,
+        });
+        let flat_bonus = mods.sum(|e| match e {
+            Effect::RelicDrop(v) => Some(*v),
+            _ => None,
+        });
+        // Equipped relics boost the skill-scaled chance; flat bonuses from
+        // skills and upgrades are added on top
+        (base_drop_chance * drop_mult * (1.0 + relic_totals.relic_drop_pct / 100.0) + flat_bonus)
+            * mods.drop_rate_mult()
+    }
+
+    /// Roll one relic eligible for `chest_tier` and give it to the player.
+    pub(super) fn grant_random_relic(&mut self, chest_tier: usize) {
+        // All relics eligible for this chest tier (including already owned)
+        let candidates: Vec<_> = relics::all_relics()
+            .iter()
+            .filter(|r| r.min_chest_tier <= chest_tier)
+            .collect();
+
+        if candidates.is_empty() {
+            return;
+        }
+
+        // Weight by rarity: rarer relics are much harder to roll
+        let weights: Vec<f64> = candidates
+            .iter()
+            .map(|r| match r.rarity {
+                Rarity::Uncommon => 50.0,
+                Rarity::Rare => 20.0,
+                Rarity::Epic => 8.0,
+                Rarity::Legendary => 3.0,
+                Rarity::Mythic => 1.0,
+                Rarity::Divine => 0.5,
+                _ => 0.0,
+            })
+            .collect();
+
+        let total_weight: f64 = weights.iter().sum();
+        let mut roll = self.state.rng.random::<f64>() * total_weight;
+
+        let mut chosen_idx = 0;
+        for (i, w) in weights.iter().enumerate() {
+            roll -= w;
+            if roll <= 0.0 {
+                chosen_idx = i;
+                break;
+            }
+        }
+
+        let relic = &candidates[chosen_idx];
+
+        // Duplicates break down into shards of their rarity
+        if self.state.relics.owns(relic.id) {
+            *self.state.relics.shards.entry(relic.rarity).or_default() += 1;
+            self.emit(GameEvent::RelicDuplicate {
+                id: relic.id.to_string(),
+            });
+            return;
+        }
+
+        self.state.relics.add_relic(relic.id.to_string());
+        self.emit(GameEvent::RelicFound {
+            id: relic.id.to_string(),
+        });
+    }
+
+    pub fn max_equipped_relics(&self) -> usize {
+        self.modifiers().relic_slots()
+    }
+}
@@ -0,0 +1,121 @@
+use rand::Rng;
+
+use crate::data::effects::Effect;
+use crate::game::bignum::BigNum;
+use crate::game::events::{GameEvent, Rejection};
+use crate::game::item::ItemInstance;
+use crate::game::modifiers::Modifiers;
+
+use super::GameEngine;
+
+impl GameEngine {
+    /// GP received for selling one unit of `item`, before sell jackpots.
+    pub fn sell_price(&self, item: &ItemInstance) -> BigNum {
+        self.modifiers().sell_price(item)
+    }
+
+    /// Sell procs, stacking bonuses and XP shared by single and bulk sales.
+    pub(super) fn apply_sale_effects(&mut self, mods: &Modifiers, gp: BigNum, count: u32) -> BigNum {
+        let mut gp = gp;
+        for (effect, n) in mods.iter() {
+            if let Effect::SellJackpot { proc, chance, mult } = *effect
+                && self.state.rng.random::<f64>() < chance * n as f64
+            {
+                gp = (gp * mult).floor();
+                self.emit(GameEvent::Proc(proc));
+            }
+        }
+
+        self.state.player.gp += gp;
+        self.state.stats.total_gp_earned += gp;
+        self.state.rebirth.gp_earned_this_run += gp;
+        self.state.counters.items_sold_count += count as u64;
+
+        // Sell stacks: each item sold raises loot GP for the rest of the run
+        if mods.any(|e| matches!(e, Effect::SellStackGp(_))) {
+            self.state.counters.catalyst_stacks += count as f64;
+        }
+        gp
+    }
+
+    /// XP granted for selling items worth `gp`.
+    pub(super) fn award_sale_xp(&mut self, mods: &Modifiers, gp: BigNum) {
+        let xp_pct = mods.sum(|e| match e {
+            Effect::SellXp(v) => Some(*v),
+            _ => None,
+        });
+        if xp_pct > 0.0 {
+            self.award_xp((gp * xp_pct).floor());
+        }
+    }
+
+    pub(super) fn sell_item(&mut self, index: usize) {
+        let mods = self.modifiers();
+        if mods.selling_banned() {
+            self.reject(Rejection::BannedByChallenge);
+            return;
+        }
+        if !mods.can_sell() {
+            self.reject(Rejection::SellingLocked);
+            return;
+        }
+        let Some(item) = self.state.inventory.items.get(index).cloned() else {
+            self.reject(Rejection::UnknownId);
+            return;
+        };
+
+        let sell_gp = self.apply_sale_effects(&mods, mods.sell_price(&item), 1);
+
+        // Decrement count or remove item
+        if item.count > 1 {
+            self.state.inventory.items[index].count -= 1;
+        } else {
+            self.state.inventory.items.remove(index);
+        }
+        if item.count == 1 && !item.affixes.is_empty() {
+            self.recalculate_player_stats();
+        }
+        self.emit(GameEvent::ItemSold {
+            name: item.name,
+            gp: sell_gp,
+            remaining: item.count - 1,
+        });
+
+        self.award_sale_xp(&mods, sell_gp);
+    }
+
+    pub(super) fn sell_all(&mut self) {
+        let mods = self.modifiers();
+        if mods.selling_banned() {
+            self.reject(Rejection::BannedByChallenge);
+            return;
+        }
+        if !mods.can_sell() {
+            self.reject(Rejection::SellingLocked);
+            return;
+        }
+        if self.state.inventory.items.is_empty() {
+            self.reject(Rejection::NothingToSell);
+            return;
+        }
+
+        let mut total_gp = BigNum::ZERO;
+        let mut total_sold = 0u32;
+        for item in &self.state.inventory.items {
+            total_gp += mods.sell_price(item) * item.count as f64;
+            total_sold += item.count;
+        }
+
+        // Sell procs roll once for the whole batch
+        let total_gp = self.apply_sale_effects(&mods, total_gp, total_sold);
+
+        self.state.inventory.items.clear();
+        self.recalculate_player_stats();
+        self.emit(GameEvent::ItemsSold {
+            count: total_sold,
+            gp: total_gp,
+        });
+
+        self.award_sale_xp(&mods, total_gp);
+    }
+}
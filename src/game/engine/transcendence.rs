@@ -0,0 +1,87 @@
+use crate::data::effects::Effect;
+use crate::data::transcendence_skills::get_transcendence_skill;
+use crate::game::bignum::BigNum;
+use crate::game::events::{GameEvent, Rejection};
+use crate::game::rebirth::RebirthState;
+use crate::game::transcendence::MIN_ESSENCE;
+
+use super::GameEngine;
+
+impl GameEngine {
+    pub(super) fn transcend(&mut self) {
+        if !self
+            .state
+            .transcendence
+            .can_transcend(self.state.rebirth.total_essence_earned)
+        {
+            self.reject(Rejection::TranscendTooEarly {
+                required: MIN_ESSENCE,
+            });
+            return;
+        }
+        self.end_challenge(false);
+        let aether = self.aether_reward();
+        let transcendence = &mut self.state.transcendence;
+        transcendence.aether += aether;
+        transcendence.total_aether_earned += aether;
+        transcendence.count += 1;
+
+        // Rebirth progress starts over; completed challenges stay
+        let challenges_completed = std::mem::take(&mut self.state.rebirth.challenges_completed);
+        self.state.rebirth = RebirthState {
+            challenges_completed,
+            ..RebirthState::default()
+        };
+        self.reset_run(None);
+
+        self.emit(GameEvent::Transcended {
+            count: self.state.transcendence.count,
+            aether,
+        });
+    }
+
+    pub(super) fn learn_transcendence_skill(&mut self, id: &str) {
+        let Some(skill) = get_transcendence_skill(id) else {
+            self.reject(Rejection::UnknownId);
+            return;
+        };
+        if self.state.transcendence.has_skill(skill.id) {
+            self.reject(Rejection::AlreadyLearned);
+        } else if self.state.transcendence.learn(skill.id) {
+            self.emit(GameEvent::TranscendenceSkillLearned {
+                id: skill.id.to_string(),
+            });
+            // Chests it starts runs with open up right away
+            let mods = self.modifiers();
+            for effect in skill.effects {
+                if let Effect::StartChests(chests) = effect {
+                    for &ct in chests.iter() {
+                        if !self.state.unlocked_chests.contains(&ct) && !mods.chest_banned(ct) {
+                            self.state.unlocked_chests.push(ct);
+                            self.emit(GameEvent::ChestUnlocked(ct));
+                        }
+                    }
+                }
+            }
+            // So do chests it holds the key to, once the level is there
+            self.check_chest_unlocks();
+            self.recalculate_player_stats();
+        } else if self.state.transcendence.aether < skill.aether_cost {
+            self.reject(Rejection::NotEnoughAether {
+                cost: skill.aether_cost,
+            });
+        } else {
+            self.reject(Rejection::PrerequisitesNotMet);
+        }
+    }
+
+    /// Aether transcending would pay out right now. The level of the current
+    /// run counts towards the best level.
+    pub fn aether_reward(&self) -> BigNum {
+        let rebirth = &self.state.rebirth;
+        self.state.transcendence.calculate_aether_reward(
+            rebirth.total_essence_earned,
+            rebirth.highest_level_ever.max(self.state.player.level),
+        )
+    }
+}
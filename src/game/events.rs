@@ -0,0 +1,246 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::automation::AutoRule;
+use super::bignum::BigNum;
+use super::bounty::BountyReward;
+use super::chest::ChestType;
+use super::item::{ItemInstance, Rarity};
+use super::loot_filter::FilterAction;
+
+/// A player action understood by [`GameEngine::apply`](super::engine::GameEngine::apply).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Start opening the current chest (ignored unless the chest is idle).
+    OpenChest,
+    /// Collect a revealed chest and return it to idle.
+    Collect,
+    /// Switch to another chest type and immediately start opening it.
+    SelectChest(ChestType),
+    BuyUpgrade(String),
+    LearnSkill(String),
+    LearnRebirthSkill(String),
+    /// Pay GP to unlearn skills and get their points back.
+    Respec(RespecTarget),
+    ToggleRelic(String),
+    UnequipAllRelics,
+    /// Spend shards to raise an owned relic one level.
+    UpgradeRelic(String),
+    /// Fuse owned relics of one rarity into a random relic of the next.
+    FuseRelics(Vec<String>),
+    /// Save the equipped relics into a loadout slot under this name.
+    SaveRelicLoadout { slot: usize, name: String },
+    /// Swap the equipped relics for the loadout in this slot.
+    EquipRelicLoadout(usize),
+    Rebirth,
+    /// Trade rebirth progress for Aether.
+    Transcend,
+    LearnTranscendenceSkill(String),
+    /// Rebirth into a challenge run with that challenge's rules.
+    StartChallenge(String),
+    /// Give up the running challenge; its rules lift at once.
+    AbandonChallenge,
+    /// Sell one item from the stack at this index into `Inventory.items`.
+    SellItem(usize),
+    SellAll,
+    /// Turn upgrade automation on or off as a whole.
+    ToggleAutomation,
+    AddRule(AutoRule),
+    /// Replace the rule at this index.
+    SetRule(usize, AutoRule),
+    RemoveRule(usize),
+    /// Swap the rule at `index` with the one above (or below) it.
+    MoveRule { index: usize, up: bool },
+    /// Turn the loot filter on or off as a whole.
+    ToggleLootFilter,
+    SetRarityFilter(Rarity, FilterAction),
+    /// Give an item id its own rule, or remove it with `None`.
+    SetItemFilter(String, Option<FilterAction>),
+    SetKeepAboveGp(BigNum),
+    /// Craft a recipe by id from the cheapest items that fit.
+    Craft(String),
+    /// Show the title from this unlocked achievement, or none.
+    SetTitle(Option<String>),
+    /// Collect the reward of a finished bounty by board index.
+    ClaimBounty(usize),
+}
+
+/// What a respec unlearns.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RespecTarget {
+    /// One learned skill that no other learned skill needs.
+    Skill(String),
+    WholeTree,
+}
+
+/// Where a found item came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LootSource {
+    Chest,
+    MultiDrop,
+    ChestRadar,
+    Scavenger,
+}
+
+/// Named random effects worth announcing to the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Proc {
+    Windfall,
+    GoldRush,
+    RealityTear,
+    Singularity,
+    ChestRadar,
+    ElixirOfFortune,
+}
+
+impl Proc {
+    pub fn name(self) -> &'static str {
+        match self {
+            Proc::Windfall => "Windfall",
+            Proc::GoldRush => "Gold Rush",
+            Proc::RealityTear => "Reality Tear",
+            Proc::Singularity => "Singularity",
+            Proc::ChestRadar => "Chest Radar",
+            Proc::ElixirOfFortune => "Elixir of Fortune",
+        }
+    }
+}
+
+/// Something that happened inside the engine. The front end decides how
+/// (and whether) to show it.
+#[derive(Debug, Clone)]
+pub enum GameEvent {
+    ChestStarted(ChestType),
+    ChestCollected,
+    /// `chest` is the one whose loot table it rolled from.
+    ItemFound { item: ItemInstance, source: LootSource, chest: ChestType },
+    Proc(Proc),
+    LevelUp { level: u32 },
+    ChestUnlocked(ChestType),
+    RelicFound { id: String },
+    /// An owned relic dropped again and became a shard.
+    RelicDuplicate { id: String },
+    RelicUpgraded { id: String, level: u32 },
+    /// `inputs` became `id`, or a shard of it if it was already owned.
+    RelicsFused { inputs: Vec<String>, id: String, duplicate: bool },
+    UpgradeBought { id: String, level: u32 },
+    SkillLearned { id: String },
+    /// `ids` were unlearned for `cost` GP, refunding `points`.
+    SkillsRespecced { ids: Vec<String>, points: u32, cost: u64 },
+    RebirthSkillLearned { id: String },
+    RelicEquipped { id: String },
+    RelicUnequipped { id: String },
+    RelicsUnequipped { count: usize },
+    RelicLoadoutSaved { slot: usize, name: String },
+    /// `missing` relics are no longer owned; `no_room` didn't fit in the
+    /// slots available.
+    RelicLoadoutEquipped { slot: usize, name: String, missing: Vec<String>, no_room: Vec<String> },
+    /// `relics_equipped` is how many were equipped going into the rebirth.
+    Rebirth { count: u32, essence: BigNum, relics_equipped: usize },
+    ItemSold { name: String, gp: BigNum, remaining: u32 },
+    ItemsSold { count: u32, gp: BigNum },
+    AutomationToggled { enabled: bool },
+    /// The loot filter sold (`value` in GP) or salvaged (`value` in XP) a drop.
+    DropFiltered { name: String, action: FilterAction, value: BigNum },
+    LootFilterToggled { enabled: bool },
+    /// An automation rule bought an upgrade.
+    AutoBought { id: String, level: u32 },
+    /// Every piece of an item set has been held at once.
+    SetCompleted { id: String },
+    /// A recipe was crafted; `item` is `None` if it failed.
+    Crafted { recipe: String, item: Option<ItemInstance> },
+    AchievementUnlocked { id: String },
+    TitleChanged { title: Option<String> },
+    BountyCompleted { index: usize },
+    BountyClaimed { reward: BountyReward },
+    /// Unfinished bounties were swapped for new ones.
+    BountiesRefreshed,
+    /// Rebirth skills and essence were traded for `aether`.
+    Transcended { count: u32, aether: BigNum },
+    TranscendenceSkillLearned { id: String },
+    ChallengeStarted { id: String },
+    /// The goal was reached in `secs` seconds.
+    ChallengeCompleted { id: String, secs: u64 },
+    /// Time ran out, or the player gave up or rebirthed.
+    ChallengeFailed { id: String },
+    Rejected(Rejection),
+}
+
+/// Why a command could not be carried out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    UnknownId,
+    ChestLocked(ChestType),
+    AlreadyMaxed,
+    LevelTooLow { required: u32 },
+    NotEnoughGp { cost: u64 },
+    AlreadyLearned,
+    NoSkillPoints,
+    PrerequisitesNotMet,
+    NotEnoughEssence { cost: u64 },
+    RelicSlotsFull,
+    NoRelicsEquipped,
+    RebirthLevelTooLow { required: u32, current: u32 },
+    SellingLocked,
+    NothingToSell,
+    AutomationLocked,
+    TooManyRules,
+    FilterLocked,
+    MissingIngredients,
+    NotEnoughShards { rarity: Rarity, cost: u32 },
+    InvalidFusion,
+    EmptyLoadout,
+    NotLearned,
+    SkillInUse,
+    BountyNotDone,
+    BannedByChallenge,
+    ChallengeRunning,
+    NoChallenge,
+    TranscendTooEarly { required: u64 },
+    NotEnoughAether { cost: u64 },
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rejection::UnknownId => write!(f, "Nothing selected!"),
+            Rejection::ChestLocked(ct) => write!(f, "{} chest is locked!", ct.name()),
+            Rejection::AlreadyMaxed => write!(f, "Already maxed!"),
+            Rejection::LevelTooLow { required } => write!(f, "Need level {}!", required),
+            Rejection::NotEnoughGp { cost } => write!(f, "Need {} GP!", cost),
+            Rejection::AlreadyLearned => write!(f, "Already learned!"),
+            Rejection::NoSkillPoints => write!(f, "No skill points!"),
+            Rejection::PrerequisitesNotMet => write!(f, "Prerequisites not met!"),
+            Rejection::NotEnoughEssence { cost } => write!(f, "Need {} Essence!", cost),
+            Rejection::RelicSlotsFull => write!(f, "All relic slots full!"),
+            Rejection::NoRelicsEquipped => write!(f, "No relics equipped!"),
+            Rejection::RebirthLevelTooLow { required, current } => write!(
+                f,
+                "Need level {} to rebirth! (currently {})",
+                required, current
+            ),
+            Rejection::SellingLocked => write!(f, "Learn Transmute Basics to sell items!"),
+            Rejection::NothingToSell => write!(f, "No items to sell!"),
+            Rejection::AutomationLocked => write!(f, "Learn Quartermaster to automate upgrades!"),
+            Rejection::TooManyRules => write!(f, "No room for more rules!"),
+            Rejection::FilterLocked => write!(f, "Learn Alchemical Sieve to filter drops!"),
+            Rejection::MissingIngredients => write!(f, "Not enough items for that recipe!"),
+            Rejection::NotEnoughShards { rarity, cost } => {
+                write!(f, "Need {} {} shards!", cost, rarity.label())
+            }
+            Rejection::InvalidFusion => write!(f, "Those relics can't be fused!"),
+            Rejection::EmptyLoadout => write!(f, "No loadout saved in that slot!"),
+            Rejection::NotLearned => write!(f, "No skill to unlearn!"),
+            Rejection::SkillInUse => write!(f, "Unlearn the skills that build on it first!"),
+            Rejection::BountyNotDone => write!(f, "That bounty isn't finished yet!"),
+            Rejection::BannedByChallenge => write!(f, "Not allowed in this challenge!"),
+            Rejection::ChallengeRunning => write!(f, "Finish or abandon your challenge first!"),
+            Rejection::NoChallenge => write!(f, "No challenge running!"),
+            Rejection::TranscendTooEarly { required } => {
+                write!(f, "Earn {} Essence in total to transcend!", required)
+            }
+            Rejection::NotEnoughAether { cost } => write!(f, "Need {} Aether!", cost),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::item::ItemInstance;
+use super::item::{ItemInstance, Rarity};
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Inventory {
@@ -9,15 +9,19 @@ pub struct Inventory {
 
 impl Inventory {
     pub fn add(&mut self, mut item: ItemInstance) {
-        // Try to find existing item with same id and rarity
-        if let Some(existing) = self.items.iter_mut().find(|i| i.id == item.id && i.rarity == item.rarity) {
+        // Try to find existing item with same id, rarity and affixes
+        if let Some(existing) = self
+            .items
+            .iter_mut()
+            .find(|i| i.id == item.id && i.rarity == item.rarity && i.affixes == item.affixes)
+        {
             // Combine: increment count, average the values
-            let old_total_gp = existing.gp_value * existing.count as u64;
-            let old_total_xp = existing.xp_value * existing.count as u64;
+            let old_total_gp = existing.gp_value * existing.count as f64;
+            let old_total_xp = existing.xp_value * existing.count as f64;
             let new_count = existing.count + 1;
 
-            existing.gp_value = (old_total_gp + item.gp_value) / new_count as u64;
-            existing.xp_value = (old_total_xp + item.xp_value) / new_count as u64;
+            existing.gp_value = ((old_total_gp + item.gp_value) / new_count as f64).floor();
+            existing.xp_value = ((old_total_xp + item.xp_value) / new_count as f64).floor();
             existing.count = new_count;
             existing.is_crit = existing.is_crit || item.is_crit; // Keep crit flag if any were crit
         } else {
@@ -31,3 +35,107 @@ impl Inventory {
         self.items.iter().map(|i| i.count as usize).sum()
     }
 }
+
+/// Orders the Inventory tab can list stacks in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortMode {
+    /// Grouped by rarity, best first.
+    #[default]
+    Rarity,
+    /// Highest GP value per item first.
+    Value,
+    /// Biggest stacks first.
+    Count,
+    Name,
+    /// Most recently found stacks first.
+    Newest,
+}
+
+impl SortMode {
+    pub fn label(self) -> &'static str {
+        match self {
+            SortMode::Rarity => "Rarity",
+            SortMode::Value => "GP value",
+            SortMode::Count => "Count",
+            SortMode::Name => "Name",
+            SortMode::Newest => "Newest",
+        }
+    }
+
+    pub fn next(self) -> SortMode {
+        match self {
+            SortMode::Rarity => SortMode::Value,
+            SortMode::Value => SortMode::Count,
+            SortMode::Count => SortMode::Name,
+            SortMode::Name => SortMode::Newest,
+            SortMode::Newest => SortMode::Rarity,
+        }
+    }
+}
+
+/// How the player wants the inventory shown.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryQuery {
+    pub sort: SortMode,
+    /// Only this rarity, if set.
+    pub rarity: Option<Rarity>,
+    /// Case-insensitive part of the item name.
+    pub search: String,
+}
+
+impl InventoryQuery {
+    fn matches(&self, item: &ItemInstance) -> bool {
+        self.rarity.is_none_or(|r| r == item.rarity)
+            && (self.search.is_empty()
+                || item.name.to_lowercase().contains(&self.search.to_lowercase()))
+    }
+}
+
+/// The stacks the Inventory tab shows, in order. Rows map back to indexes
+/// into [`Inventory::items`], so commands act on the stack the player sees.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryView {
+    rows: Vec<usize>,
+}
+
+impl InventoryView {
+    pub fn new(inventory: &Inventory, query: &InventoryQuery) -> Self {
+        let items = &inventory.items;
+        let mut rows: Vec<usize> = (0..items.len())
+            .filter(|&i| query.matches(&items[i]))
+            .collect();
+        let tier = |i: usize| Rarity::ALL.iter().position(|&r| r == items[i].rarity);
+        match query.sort {
+            // Stable sorts keep the order found within equal keys
+            SortMode::Rarity => rows.sort_by_key(|&i| std::cmp::Reverse(tier(i))),
+            SortMode::Value => rows.sort_by(|&a, &b| {
+                items[b]
+                    .gp_value
+                    .partial_cmp(&items[a].gp_value)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            }),
+            SortMode::Count => rows.sort_by_key(|&i| std::cmp::Reverse(items[i].count)),
+            SortMode::Name => rows.sort_by_key(|&i| items[i].name.to_lowercase()),
+            SortMode::Newest => rows.reverse(),
+        }
+        Self { rows }
+    }
+
+    /// Index into [`Inventory::items`] of a row.
+    pub fn item_index(&self, row: usize) -> Option<usize> {
+        self.rows.get(row).copied()
+    }
+
+    /// Indexes into [`Inventory::items`], in display order.
+    pub fn rows(&self) -> &[usize] {
+        &self.rows
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+}
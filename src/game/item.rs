@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+use super::bignum::BigNum;
+use crate::data::affixes::{AffixDef, AffixKind, get_affix};
+use crate::data::pack::leak;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Rarity {
     Common,
     Uncommon,
@@ -8,9 +12,20 @@ pub enum Rarity {
     Epic,
     Legendary,
     Mythic,
+    Divine,
 }
 
 impl Rarity {
+    pub const ALL: [Rarity; 7] = [
+        Rarity::Common,
+        Rarity::Uncommon,
+        Rarity::Rare,
+        Rarity::Epic,
+        Rarity::Legendary,
+        Rarity::Mythic,
+        Rarity::Divine,
+    ];
+
     pub fn color(self) -> ratatui::style::Color {
         match self {
             Rarity::Common => ratatui::style::Color::Gray,
@@ -19,6 +34,7 @@ impl Rarity {
             Rarity::Epic => ratatui::style::Color::Magenta,
             Rarity::Legendary => ratatui::style::Color::Yellow,
             Rarity::Mythic => ratatui::style::Color::Rgb(255, 50, 50),
+            Rarity::Divine => ratatui::style::Color::Rgb(255, 245, 170),
         }
     }
 
@@ -30,6 +46,7 @@ impl Rarity {
             Rarity::Epic => "Epic",
             Rarity::Legendary => "Legendary",
             Rarity::Mythic => "Mythic",
+            Rarity::Divine => "Divine",
         }
     }
 
@@ -41,6 +58,7 @@ impl Rarity {
             Rarity::Epic => 15.0,
             Rarity::Legendary => 50.0,
             Rarity::Mythic => 150.0,
+            Rarity::Divine => 500.0,
         }
     }
 
@@ -52,34 +70,71 @@ impl Rarity {
             Rarity::Epic => 10.0,
             Rarity::Legendary => 25.0,
             Rarity::Mythic => 60.0,
+            Rarity::Divine => 150.0,
+        }
+    }
+
+    /// The next rarity up. Mythic stays Mythic: Divine only comes from the
+    /// chests that drop it, never from an upgrade.
+    pub fn next_tier(self) -> Rarity {
+        match self {
+            Rarity::Common => Rarity::Uncommon,
+            Rarity::Uncommon => Rarity::Rare,
+            Rarity::Rare => Rarity::Epic,
+            Rarity::Epic => Rarity::Legendary,
+            Rarity::Legendary => Rarity::Mythic,
+            Rarity::Mythic => Rarity::Mythic,
+            Rarity::Divine => Rarity::Divine,
         }
     }
 }
 
 pub type ItemId = &'static str;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ItemDef {
+    #[serde(deserialize_with = "leak::str")]
     pub id: ItemId,
+    #[serde(deserialize_with = "leak::str")]
     pub name: &'static str,
     pub rarity: Rarity,
     pub base_gp: u64,
     pub base_xp: u64,
+    #[serde(deserialize_with = "leak::str")]
     pub description: &'static str,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ItemInstance {
     pub id: String,
+    /// Display name, affixes included.
     pub name: String,
     pub rarity: Rarity,
-    pub gp_value: u64,
-    pub xp_value: u64,
+    pub gp_value: BigNum,
+    pub xp_value: BigNum,
     pub is_crit: bool,
-    #[serde(default = "default_count")]
     pub count: u32,
+    /// Affix ids, prefix first. Only stacks with the same affixes combine.
+    pub affixes: Vec<String>,
+}
+
+impl ItemInstance {
+    pub fn affix_defs(&self) -> impl Iterator<Item = &'static AffixDef> + '_ {
+        self.affixes.iter().filter_map(|id| get_affix(id))
+    }
 }
 
-fn default_count() -> u32 {
-    1
+/// "Gleaming Gold Bar of Haste"
+pub fn affixed_name(base: &str, affixes: &[&AffixDef]) -> String {
+    let mut name = String::new();
+    for affix in affixes.iter().filter(|a| a.kind == AffixKind::Prefix) {
+        name.push_str(affix.name);
+        name.push(' ');
+    }
+    name.push_str(base);
+    for affix in affixes.iter().filter(|a| a.kind == AffixKind::Suffix) {
+        name.push(' ');
+        name.push_str(affix.name);
+    }
+    name
 }
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// A named set of ids to switch to in one go. Relic loadouts use it; skill
+/// and upgrade builds can be stored the same way.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Loadout {
+    pub name: String,
+    pub ids: Vec<String>,
+}
+
+impl Loadout {
+    /// Whether `ids` holds exactly this loadout, in any order.
+    pub fn matches(&self, ids: &[String]) -> bool {
+        self.ids.len() == ids.len() && self.ids.iter().all(|id| ids.contains(id))
+    }
+}
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::bignum::BigNum;
+use super::item::{ItemInstance, Rarity};
+
+/// What happens to a new drop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FilterAction {
+    #[default]
+    Keep,
+    /// Sold at the usual sell price.
+    Sell,
+    /// Broken down for XP at the sell percentage.
+    Salvage,
+}
+
+impl FilterAction {
+    pub fn label(self) -> &'static str {
+        match self {
+            FilterAction::Keep => "Keep",
+            FilterAction::Sell => "Sell",
+            FilterAction::Salvage => "Salvage",
+        }
+    }
+
+    pub fn next(self) -> FilterAction {
+        match self {
+            FilterAction::Keep => FilterAction::Sell,
+            FilterAction::Sell => FilterAction::Salvage,
+            FilterAction::Salvage => FilterAction::Keep,
+        }
+    }
+}
+
+/// Decides what happens to each drop before it reaches the inventory. Kept
+/// through rebirth; only runs while the Alchemical Sieve skill is learned.
+///
+/// An item's own rule wins, then the keep threshold, then its rarity's rule.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LootFilter {
+    pub enabled: bool,
+    /// Rarities without an entry are kept.
+    pub rarities: HashMap<Rarity, FilterAction>,
+    /// Rules for single items by id.
+    pub items: HashMap<String, FilterAction>,
+    /// Drops worth at least this much GP are always kept. Zero turns it off.
+    pub keep_above_gp: BigNum,
+}
+
+impl LootFilter {
+    pub fn rarity_action(&self, rarity: Rarity) -> FilterAction {
+        self.rarities.get(&rarity).copied().unwrap_or_default()
+    }
+
+    /// Per-item rules, sorted by id.
+    pub fn item_rules(&self) -> Vec<(&str, FilterAction)> {
+        let mut rules: Vec<(&str, FilterAction)> =
+            self.items.iter().map(|(id, &action)| (id.as_str(), action)).collect();
+        rules.sort_by_key(|&(id, _)| id);
+        rules
+    }
+
+    pub fn action_for(&self, item: &ItemInstance) -> FilterAction {
+        if let Some(&action) = self.items.get(&item.id) {
+            return action;
+        }
+        if !self.keep_above_gp.is_zero() && item.gp_value >= self.keep_above_gp {
+            return FilterAction::Keep;
+        }
+        self.rarity_action(item.rarity)
+    }
+}
@@ -1,11 +1,26 @@
+pub mod achievements;
+pub mod automation;
+pub mod bignum;
+pub mod bounty;
 pub mod chest;
+pub mod collection;
+pub mod crafting;
+pub mod engine;
+pub mod events;
 pub mod inventory;
 pub mod item;
+pub mod loadout;
+pub mod loot_filter;
+pub mod modifiers;
+pub mod offline;
 pub mod player;
+pub mod profiles;
 pub mod progression;
 pub mod rebirth;
 pub mod relic;
+pub mod rng;
 pub mod save;
 pub mod skill_tree;
 pub mod state;
+pub mod transcendence;
 pub mod upgrade;
@@ -0,0 +1,373 @@
+use crate::data::achievements::all_achievements;
+use crate::data::affixes::all_affixes;
+use crate::data::challenges::{all_challenges, get_challenge};
+use crate::data::effects::{Effect, RARE_PLUS, Stage, Stat};
+use crate::data::rebirth_skills::all_rebirth_skills;
+use crate::data::relics::RelicStatTotals;
+use crate::data::sets::all_item_sets;
+use crate::data::skills::all_skills;
+use crate::data::transcendence_skills::all_transcendence_skills;
+use crate::data::upgrades::all_upgrades;
+
+use super::bignum::BigNum;
+use super::chest::ChestType;
+use super::item::{ItemInstance, Rarity};
+use super::player::Player;
+use super::relic::RelicState;
+use super::state::GameState;
+
+/// One value per [`Stat`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct StatBlock {
+    pub luck: f64,
+    pub speed: f64,
+    pub gp_mult: f64,
+    pub xp_mult: f64,
+    pub crit_chance: f64,
+}
+
+impl StatBlock {
+    pub fn get_mut(&mut self, stat: Stat) -> &mut f64 {
+        match stat {
+            Stat::Luck => &mut self.luck,
+            Stat::Speed => &mut self.speed,
+            Stat::GpMult => &mut self.gp_mult,
+            Stat::XpMult => &mut self.xp_mult,
+            Stat::CritChance => &mut self.crit_chance,
+        }
+    }
+}
+
+/// Every effect in play for a game state, each paired with how many times it
+/// applies (an upgrade's level, 1 for everything else).
+///
+/// Effects are ordered by [`Stage`] and then by data order (transcendence
+/// skills, rebirth skills, skills, upgrades, completed item sets, held
+/// affixes, achievements, completed challenges, then the rules of the running
+/// challenge), so procs always draw from the RNG in the same sequence.
+#[derive(Debug, Clone, Default)]
+pub struct Modifiers {
+    effects: Vec<(&'static Effect, u32)>,
+}
+
+impl Modifiers {
+    pub fn gather(state: &GameState) -> Self {
+        let mut effects = Vec::new();
+        for skill in all_transcendence_skills() {
+            if state.transcendence.has_skill(skill.id) {
+                effects.extend(skill.effects.iter().map(|e| (e, 1)));
+            }
+        }
+        for skill in all_rebirth_skills() {
+            if state.rebirth.has_rebirth_skill(skill.id) {
+                effects.extend(skill.effects.iter().map(|e| (e, 1)));
+            }
+        }
+        for skill in all_skills() {
+            if state.skill_tree.has_skill(skill.id) {
+                effects.extend(skill.effects.iter().map(|e| (e, 1)));
+            }
+        }
+        for upg in all_upgrades() {
+            let level = state.upgrades.get_level(upg.id);
+            if level > 0 {
+                effects.extend(upg.effects.iter().map(|e| (e, level)));
+            }
+        }
+        for set in all_item_sets() {
+            if state.collection.is_complete(set.id) {
+                effects.extend(set.effects.iter().map(|e| (e, 1)));
+            }
+        }
+        for affix in all_affixes().iter().filter(|a| !a.held.is_empty()) {
+            let held = state.inventory.items.iter().any(|i| i.affixes.iter().any(|a| a == affix.id));
+            if held {
+                effects.extend(affix.held.iter().map(|e| (e, 1)));
+            }
+        }
+        for achievement in all_achievements() {
+            if state.achievements.is_unlocked(achievement.id) {
+                effects.extend(achievement.effects.iter().map(|e| (e, 1)));
+            }
+        }
+        for challenge in all_challenges() {
+            if state.rebirth.has_completed_challenge(challenge.id) {
+                effects.extend(challenge.effects.iter().map(|e| (e, 1)));
+            }
+        }
+        if let Some(challenge) = state.rebirth.challenge.as_ref().and_then(|c| get_challenge(&c.id)) {
+            effects.extend(challenge.rules.iter().map(|e| (e, 1)));
+        }
+        // Stable sort keeps data order within a stage
+        effects.sort_by_key(|(e, _)| e.stage());
+        Self { effects }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'static Effect, u32)> + '_ {
+        self.effects.iter().copied()
+    }
+
+    /// Effects belonging to one stage, in pipeline order.
+    pub fn stage(&self, stage: Stage) -> impl Iterator<Item = (&'static Effect, u32)> + '_ {
+        self.iter().filter(move |(e, _)| e.stage() == stage)
+    }
+
+    /// Sum of `f(effect) * level` over the effects `f` picks out.
+    pub fn sum(&self, f: impl Fn(&Effect) -> Option<f64>) -> f64 {
+        self.iter()
+            .filter_map(|(e, n)| f(e).map(|v| v * n as f64))
+            .sum()
+    }
+
+    /// Product of `1 + f(effect) * level` over the effects `f` picks out.
+    pub fn product(&self, f: impl Fn(&Effect) -> Option<f64>) -> f64 {
+        self.iter()
+            .filter_map(|(e, n)| f(e).map(|v| 1.0 + v * n as f64))
+            .product()
+    }
+
+    /// Largest value `f` picks out, ignoring levels.
+    pub fn max(&self, f: impl Fn(&Effect) -> Option<f64>) -> Option<f64> {
+        self.iter().filter_map(|(e, _)| f(e)).reduce(f64::max)
+    }
+
+    pub fn any(&self, f: impl Fn(&Effect) -> bool) -> bool {
+        self.iter().any(|(e, _)| f(e))
+    }
+
+    /// Base stats: player defaults plus rebirth bonuses.
+    pub fn base_stats(&self) -> StatBlock {
+        let defaults = Player::default();
+        let mut base = StatBlock {
+            luck: defaults.base_luck,
+            speed: defaults.base_speed,
+            gp_mult: defaults.base_gp_multiplier,
+            xp_mult: defaults.base_xp_multiplier,
+            crit_chance: defaults.base_crit_chance,
+        };
+        for (effect, n) in self.stage(Stage::Base) {
+            if let Effect::BaseStat(stat, v) = effect {
+                *base.get_mut(*stat) += v * n as f64;
+            }
+        }
+        let mult = self.product(|e| match e {
+            Effect::BaseStatsMult(v) => Some(*v),
+            _ => None,
+        });
+        base.luck *= mult;
+        base.speed *= mult;
+        base.gp_mult *= mult;
+        base.xp_mult *= mult;
+        base.crit_chance = (base.crit_chance * mult).min(0.75);
+        base
+    }
+
+    /// Flat stat bonuses from skills and upgrades.
+    pub fn stat_bonuses(&self) -> StatBlock {
+        let mut bonus = StatBlock::default();
+        for (effect, n) in self.stage(Stage::Additive) {
+            if let Effect::Stat(stat, v) = effect {
+                *bonus.get_mut(*stat) += v * n as f64;
+            }
+        }
+        bonus
+    }
+
+    /// Final player stats: base, plus flat bonuses, times relic percentages.
+    pub fn player_stats(&self, relics: &RelicStatTotals) -> StatBlock {
+        let base = self.base_stats();
+        let bonus = self.stat_bonuses();
+        StatBlock {
+            luck: (base.luck + bonus.luck + relics.luck).max(0.0),
+            speed: (base.speed + bonus.speed) * (1.0 + relics.speed_pct / 100.0),
+            gp_mult: (base.gp_mult + bonus.gp_mult) * (1.0 + relics.gp_pct / 100.0),
+            xp_mult: (base.xp_mult + bonus.xp_mult) * (1.0 + relics.xp_pct / 100.0),
+            crit_chance: (base.crit_chance + bonus.crit_chance + relics.crit).min(0.75),
+        }
+    }
+
+    /// How much more often chaotic effects trigger.
+    pub fn chaos_mult(&self) -> f64 {
+        self.product(|e| match e {
+            Effect::ChaosAmplifier(v) => Some(*v),
+            _ => None,
+        })
+    }
+
+    /// Multiplier for multi-drop, scavenge and relic drop chances.
+    pub fn drop_rate_mult(&self) -> f64 {
+        self.product(|e| match e {
+            Effect::DropRates(v) => Some(*v),
+            _ => None,
+        })
+    }
+
+    /// Strength of the GP/XP/Speed buff a chaos surge grants.
+    pub fn chaos_surge_bonus(&self) -> f64 {
+        self.max(|e| match e {
+            Effect::ChaosSurge { bonus, .. } => Some(*bonus),
+            _ => None,
+        })
+        .unwrap_or(0.0)
+    }
+
+    pub fn relic_slots(&self) -> usize {
+        if self.relics_locked() {
+            return 0;
+        }
+        RelicState::MAX_EQUIPPED + self.sum(|e| match e {
+            Effect::RelicSlots(n) => Some(*n as f64),
+            _ => None,
+        }) as usize
+    }
+
+    pub fn relics_locked(&self) -> bool {
+        self.any(|e| matches!(e, Effect::NoRelics))
+    }
+
+    /// Whether a challenge rule keeps `ct` locked.
+    pub fn chest_banned(&self, ct: ChestType) -> bool {
+        self.any(|e| matches!(e, Effect::OnlyChests(chests) if !chests.contains(&ct)))
+    }
+
+    /// Highest opening speed allowed, if capped.
+    pub fn speed_cap(&self) -> Option<f64> {
+        self.iter()
+            .filter_map(|(e, _)| match e {
+                Effect::SpeedCap(v) => Some(*v),
+                _ => None,
+            })
+            .reduce(f64::min)
+    }
+
+    /// Multiplier on the skill point cost of skills.
+    pub fn skill_cost_mult(&self) -> u32 {
+        self.max(|e| match e {
+            Effect::SkillCostMult(n) => Some(*n as f64),
+            _ => None,
+        })
+        .map_or(1, |n| n as u32)
+    }
+
+    pub fn has_chest_key(&self, ct: ChestType) -> bool {
+        ct == ChestType::Wooden
+            || self.any(|e| matches!(e, Effect::ChestKey { chest, .. } if *chest == ct))
+    }
+
+    pub fn chest_level_reduction(&self) -> u32 {
+        self.sum(|e| match e {
+            Effect::ChestLevelReduction(n) => Some(*n as f64),
+            _ => None,
+        }) as u32
+    }
+
+    pub fn auto_open(&self) -> bool {
+        self.any(|e| matches!(e, Effect::AutoOpen))
+    }
+
+    pub fn auto_buy(&self) -> bool {
+        self.any(|e| matches!(e, Effect::AutoBuy))
+    }
+
+    /// Ticks a revealed chest waits before collecting itself, if it does.
+    pub fn auto_collect_after(&self) -> Option<u32> {
+        self.iter()
+            .filter_map(|(e, _)| match e {
+                Effect::AutoCollect { after_ticks } => Some(*after_ticks),
+                _ => None,
+            })
+            .min()
+            .or(self.auto_open().then_some(60))
+    }
+
+    pub fn idle_income_every(&self) -> Option<u32> {
+        self.iter()
+            .filter_map(|(e, _)| match e {
+                Effect::IdleIncome { every_ticks } => Some(*every_ticks),
+                _ => None,
+            })
+            .min()
+    }
+
+    /// Longest stretch of closed-game time that is played out on load.
+    pub fn offline_cap_secs(&self) -> u64 {
+        (self.sum(|e| match e {
+            Effect::OfflineHours(h) => Some(*h),
+            _ => None,
+        }) * 3600.0) as u64
+    }
+
+    pub fn can_sell(&self) -> bool {
+        self.any(|e| matches!(e, Effect::SellPct(_))) && !self.selling_banned()
+    }
+
+    /// Whether a challenge rule forbids every kind of selling.
+    pub fn selling_banned(&self) -> bool {
+        self.any(|e| matches!(e, Effect::NoSelling))
+    }
+
+    /// Whether a challenge rule forbids buying upgrades.
+    pub fn upgrades_banned(&self) -> bool {
+        self.any(|e| matches!(e, Effect::NoUpgrades))
+    }
+
+    /// Fraction of an item's GP value paid when selling it.
+    pub fn sell_pct(&self, rarity: Rarity) -> f64 {
+        let mut pct = self
+            .max(|e| match e {
+                Effect::SellPct(v) => Some(*v),
+                _ => None,
+            })
+            .unwrap_or(0.5);
+        if RARE_PLUS.contains(&rarity)
+            && let Some(rare) = self.max(|e| match e {
+                Effect::RareSellPct(v) => Some(*v),
+                _ => None,
+            })
+        {
+            pct = pct.max(rare);
+        }
+        pct * self.product(|e| match e {
+            Effect::SellMult(v) => Some(*v),
+            _ => None,
+        })
+    }
+
+    /// GP received for selling one unit of `item`, before sell jackpots.
+    pub fn sell_price(&self, item: &ItemInstance) -> BigNum {
+        (item.gp_value * self.sell_pct(item.rarity)).floor().max(BigNum::from(1u64))
+    }
+
+    pub fn loot_filter(&self) -> bool {
+        self.any(|e| matches!(e, Effect::LootFilter))
+    }
+
+    /// Added to crafting success chances.
+    pub fn craft_chance_bonus(&self) -> f64 {
+        self.sum(|e| match e {
+            Effect::CraftChance(v) => Some(*v),
+            _ => None,
+        })
+    }
+
+    /// Whether drops of `rarity` are recycled for GP instead of kept. A
+    /// challenge that bans selling stops recycling too.
+    pub fn recycles(&self, rarity: Rarity) -> bool {
+        !self.selling_banned() && self.any(|e| matches!(e, Effect::Recycle(r) if *r == rarity))
+    }
+
+    /// Added to the exponent of the essence formula.
+    pub fn essence_exponent_bonus(&self) -> f64 {
+        self.sum(|e| match e {
+            Effect::EssenceExponent(v) => Some(*v),
+            _ => None,
+        })
+    }
+
+    pub fn essence_mult(&self) -> f64 {
+        self.product(|e| match e {
+            Effect::EssenceGain(v) => Some(*v),
+            _ => None,
+        })
+    }
+}
@@ -0,0 +1,74 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::bignum::BigNum;
+use super::engine::GameEngine;
+use super::events::GameEvent;
+
+/// Engine ticks per second of real time.
+pub const TICKS_PER_SEC: u64 = 30;
+
+/// What happened while the game was closed.
+#[derive(Debug, Clone, Default)]
+pub struct OfflineReport {
+    pub away_secs: u64,
+    /// Time actually played out, after the offline cap.
+    pub simulated_secs: u64,
+    pub chests: u64,
+    pub gp: BigNum,
+    pub xp: BigNum,
+    pub levels: u32,
+    pub items: u64,
+    pub relics: Vec<String>,
+}
+
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Play out the time between the last save and `now`, up to the offline cap
+/// from upgrades and skills. Returns `None` if nothing could have happened:
+/// no known save time, no cap, or nothing that runs unattended.
+pub fn catch_up(engine: &mut GameEngine, now: u64) -> Option<OfflineReport> {
+    let last_saved = engine.state.last_saved;
+    if last_saved == 0 || now <= last_saved {
+        return None;
+    }
+    let mods = engine.modifiers();
+    if !mods.auto_open() && mods.idle_income_every().is_none() {
+        return None;
+    }
+    let away_secs = now - last_saved;
+    let simulated_secs = away_secs.min(mods.offline_cap_secs());
+    if simulated_secs == 0 {
+        return None;
+    }
+
+    let state = &engine.state;
+    let (chests, gp, xp, level, items) = (
+        state.stats.chests_opened,
+        state.stats.total_gp_earned,
+        state.stats.total_xp_earned,
+        state.player.level,
+        state.stats.items_found,
+    );
+    let mut relics = Vec::new();
+    engine.fast_forward(simulated_secs * TICKS_PER_SEC, |event| {
+        if let GameEvent::RelicFound { id } = event {
+            relics.push(id);
+        }
+    });
+    let state = &engine.state;
+    Some(OfflineReport {
+        away_secs,
+        simulated_secs,
+        chests: state.stats.chests_opened - chests,
+        gp: state.stats.total_gp_earned - gp,
+        xp: state.stats.total_xp_earned - xp,
+        levels: state.player.level - level,
+        items: state.stats.items_found - items,
+        relics,
+    })
+}
@@ -1,11 +1,14 @@
 use serde::{Deserialize, Serialize};
 
+use super::bignum::BigNum;
+use super::modifiers::StatBlock;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Player {
     pub level: u32,
-    pub xp: u64,
-    pub xp_to_next: u64,
-    pub gp: u64,
+    pub xp: BigNum,
+    pub xp_to_next: BigNum,
+    pub gp: BigNum,
 
     // Base stats (before upgrades/relics)
     pub base_luck: f64,
@@ -26,9 +29,9 @@ impl Default for Player {
     fn default() -> Self {
         Self {
             level: 1,
-            xp: 0,
-            xp_to_next: 100,
-            gp: 0,
+            xp: BigNum::ZERO,
+            xp_to_next: BigNum::from(100u64),
+            gp: BigNum::ZERO,
             base_luck: 0.0,
             base_speed: 1.0,
             base_gp_multiplier: 1.0,
@@ -44,25 +47,18 @@ impl Default for Player {
 }
 
 impl Player {
-    pub fn recalculate_stats(
-        &mut self,
-        upgrade_luck: f64,
-        upgrade_speed: f64,
-        upgrade_gp_mult: f64,
-        upgrade_xp_mult: f64,
-        upgrade_crit: f64,
-        relic_luck: f64,
-        relic_speed_pct: f64,
-        relic_gp_mult_pct: f64,
-        relic_xp_mult_pct: f64,
-        relic_crit: f64,
-    ) {
-        self.luck = self.base_luck + upgrade_luck + relic_luck;
-        self.speed = (self.base_speed + upgrade_speed) * (1.0 + relic_speed_pct / 100.0);
-        self.gp_multiplier =
-            (self.base_gp_multiplier + upgrade_gp_mult) * (1.0 + relic_gp_mult_pct / 100.0);
-        self.xp_multiplier =
-            (self.base_xp_multiplier + upgrade_xp_mult) * (1.0 + relic_xp_mult_pct / 100.0);
-        self.crit_chance = (self.base_crit_chance + upgrade_crit + relic_crit).min(0.75);
+    /// Store the base stats and the final stats from the modifier pipeline.
+    pub fn recalculate_stats(&mut self, base: StatBlock, total: StatBlock) {
+        self.base_luck = base.luck;
+        self.base_speed = base.speed;
+        self.base_gp_multiplier = base.gp_mult;
+        self.base_xp_multiplier = base.xp_mult;
+        self.base_crit_chance = base.crit_chance;
+
+        self.luck = total.luck;
+        self.speed = total.speed;
+        self.gp_multiplier = total.gp_mult;
+        self.xp_multiplier = total.xp_mult;
+        self.crit_chance = total.crit_chance;
     }
 }
@@ -0,0 +1,215 @@
+use directories::ProjectDirs;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::time::SystemTime;
+
+use super::save;
+
+/// Profile used when none is picked, and the one older single-save installs
+/// are moved into.
+pub const DEFAULT_PROFILE: &str = "main";
+
+pub const MAX_NAME_LEN: usize = 24;
+
+/// What the profile picker shows for one save slot.
+#[derive(Debug, Clone)]
+pub struct ProfileSummary {
+    pub name: String,
+    pub level: u32,
+    pub rebirth_count: u32,
+    pub last_played: Option<SystemTime>,
+    /// The save exists but does not load.
+    pub damaged: bool,
+}
+
+static DATA_DIR: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+fn data_dir() -> Option<PathBuf> {
+    DATA_DIR
+        .get_or_init(|| {
+            ProjectDirs::from("", "", "lootbox-game").map(|dirs| dirs.data_dir().to_path_buf())
+        })
+        .clone()
+}
+
+/// Keep profiles under `dir` instead of the platform data directory for the
+/// rest of the process. Returns false (and changes nothing) if a save or
+/// profile has already been looked up.
+pub fn use_data_dir(dir: PathBuf) -> bool {
+    DATA_DIR.set(Some(dir)).is_ok()
+}
+
+fn profiles_dir() -> Option<PathBuf> {
+    data_dir().map(|dir| dir.join("profiles"))
+}
+
+/// Directory holding a profile's save and its backups.
+pub fn profile_dir(name: &str) -> Option<PathBuf> {
+    profiles_dir().map(|dir| dir.join(name))
+}
+
+/// Profile names become directory names, so keep them simple.
+pub fn validate_name(name: &str) -> Result<(), ProfileError> {
+    let valid = !name.is_empty()
+        && name.chars().count() <= MAX_NAME_LEN
+        && name.trim() == name
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_'));
+    if valid {
+        Ok(())
+    } else {
+        Err(ProfileError::InvalidName(name.to_string()))
+    }
+}
+
+/// Every profile, most recently played first.
+pub fn list() -> Vec<ProfileSummary> {
+    let Some(entries) = profiles_dir().and_then(|dir| fs::read_dir(dir).ok()) else {
+        return Vec::new();
+    };
+    let mut profiles: Vec<ProfileSummary> = entries
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .map(summarize)
+        .collect();
+    profiles.sort_by(|a, b| {
+        b.last_played
+            .cmp(&a.last_played)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    profiles
+}
+
+fn summarize(name: String) -> ProfileSummary {
+    let mut summary = ProfileSummary {
+        name,
+        level: 1,
+        rebirth_count: 0,
+        last_played: None,
+        damaged: false,
+    };
+    let Some(path) = profile_dir(&summary.name).map(|dir| dir.join("save.json")) else {
+        return summary;
+    };
+    let Ok(data) = fs::read_to_string(&path) else {
+        return summary;
+    };
+    summary.last_played = fs::metadata(&path).and_then(|m| m.modified()).ok();
+    match save::load_from_str(&data) {
+        Ok(state) => {
+            summary.level = state.player.level;
+            summary.rebirth_count = state.rebirth.rebirth_count;
+        }
+        Err(_) => summary.damaged = true,
+    }
+    summary
+}
+
+pub fn create(name: &str) -> Result<(), ProfileError> {
+    let dir = new_profile_dir(name)?;
+    fs::create_dir_all(dir).map_err(ProfileError::Io)
+}
+
+/// Copy a profile's save and backups into a new profile.
+pub fn copy(from: &str, to: &str) -> Result<(), ProfileError> {
+    let source = existing_profile_dir(from)?;
+    let dest = new_profile_dir(to)?;
+    fs::create_dir_all(&dest).map_err(ProfileError::Io)?;
+    for entry in fs::read_dir(&source).map_err(ProfileError::Io)?.flatten() {
+        if entry.path().is_file() {
+            fs::copy(entry.path(), dest.join(entry.file_name())).map_err(ProfileError::Io)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn rename(from: &str, to: &str) -> Result<(), ProfileError> {
+    let source = existing_profile_dir(from)?;
+    let dest = new_profile_dir(to)?;
+    fs::rename(source, dest).map_err(ProfileError::Io)
+}
+
+/// Delete a profile with its save and all backups.
+pub fn delete(name: &str) -> Result<(), ProfileError> {
+    let dir = existing_profile_dir(name)?;
+    fs::remove_dir_all(dir).map_err(ProfileError::Io)
+}
+
+fn existing_profile_dir(name: &str) -> Result<PathBuf, ProfileError> {
+    let dir = profile_dir(name).ok_or(ProfileError::NoDataDir)?;
+    if dir.is_dir() {
+        Ok(dir)
+    } else {
+        Err(ProfileError::NotFound(name.to_string()))
+    }
+}
+
+fn new_profile_dir(name: &str) -> Result<PathBuf, ProfileError> {
+    validate_name(name)?;
+    let dir = profile_dir(name).ok_or(ProfileError::NoDataDir)?;
+    if dir.exists() {
+        Err(ProfileError::AlreadyExists(name.to_string()))
+    } else {
+        Ok(dir)
+    }
+}
+
+/// Move a save from before profiles (`save.json` and its backups at the top
+/// of the data directory) into the default profile.
+pub fn adopt_legacy_save() {
+    let (Some(root), Some(main)) = (data_dir(), profile_dir(DEFAULT_PROFILE)) else {
+        return;
+    };
+    if !root.join("save.json").exists() || main.exists() || fs::create_dir_all(&main).is_err() {
+        return;
+    }
+    let Ok(entries) = fs::read_dir(&root) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        if name.to_string_lossy().starts_with("save.") && entry.path().is_file() {
+            let _ = fs::rename(entry.path(), main.join(name));
+        }
+    }
+}
+
+/// Why a profile operation failed.
+#[derive(Debug)]
+pub enum ProfileError {
+    NoDataDir,
+    InvalidName(String),
+    AlreadyExists(String),
+    NotFound(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileError::NoDataDir => write!(f, "no data directory for profiles"),
+            ProfileError::InvalidName(name) => write!(
+                f,
+                "{:?} is not a valid profile name (letters, digits, spaces, - and _, up to {} characters)",
+                name, MAX_NAME_LEN
+            ),
+            ProfileError::AlreadyExists(name) => write!(f, "profile {:?} already exists", name),
+            ProfileError::NotFound(name) => write!(f, "profile {:?} does not exist", name),
+            ProfileError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for ProfileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ProfileError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
@@ -1,3 +1,25 @@
-pub fn xp_for_level(level: u32) -> u64 {
-    (100.0 * (level as f64).powf(2.0)) as u64
+use super::bignum::BigNum;
+
+/// XP to go from `level` to the next one.
+pub fn xp_for_level(level: u32) -> BigNum {
+    BigNum::new(100.0 * (level as f64).powf(2.0))
+}
+
+/// XP to go from level 1 to `level`: the sum of [`xp_for_level`] below it.
+pub fn total_xp_for_level(level: u32) -> BigNum {
+    let l = level as f64;
+    BigNum::new(100.0 * (l - 1.0) * l * (2.0 * l - 1.0) / 6.0)
+}
+
+/// A level no higher than the one a player at `level` with `xp` towards the
+/// next would reach, and usually a level or two short of it.
+pub fn approx_level_for_xp(level: u32, xp: BigNum) -> u32 {
+    let total = (total_xp_for_level(level) + xp).to_f64();
+    // total ~= 100 * L^3 / 3
+    let estimate = (3.0 * total / 100.0).cbrt();
+    if estimate >= u32::MAX as f64 {
+        u32::MAX
+    } else {
+        (estimate as u32).max(level)
+    }
 }
@@ -1,16 +1,38 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
+use super::bignum::BigNum;
+use crate::data::challenges::get_challenge;
 use crate::data::rebirth_skills::{all_rebirth_skills, get_rebirth_skill};
+use crate::game::offline::TICKS_PER_SEC;
+
+/// The challenge the current run is playing under.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActiveChallenge {
+    pub id: String,
+    /// Ticks played since the challenge started.
+    pub ticks: u64,
+}
+
+impl ActiveChallenge {
+    /// Seconds left before the challenge fails.
+    pub fn secs_left(&self) -> u64 {
+        get_challenge(&self.id)
+            .map_or(0, |c| c.time_limit_secs.saturating_sub(self.ticks / TICKS_PER_SEC))
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct RebirthState {
     pub rebirth_count: u32,
-    pub total_essence_earned: u64,
-    pub essence: u64,
+    pub total_essence_earned: BigNum,
+    pub essence: BigNum,
     pub rebirth_skills: HashSet<String>,
-    pub gp_earned_this_run: u64,
+    pub gp_earned_this_run: BigNum,
     pub highest_level_ever: u32,
+    pub challenge: Option<ActiveChallenge>,
+    /// Challenges finished in time; their rewards are permanent.
+    pub challenges_completed: HashSet<String>,
 }
 
 impl RebirthState {
@@ -22,22 +44,23 @@ impl RebirthState {
         current_level >= self.min_level_for_rebirth()
     }
 
-    pub fn calculate_essence_reward(&self, current_gp: u64) -> u64 {
-        if current_gp == 0 {
-            return 0;
-        }
-        let mut essence = ((current_gp as f64).powf(0.35) / 3.0) as u64;
-
-        // Essence Siphon rebirth skill: +20% essence
-        if self.has_rebirth_skill("rb_essence_boost") {
-            essence = (essence as f64 * 1.2) as u64;
-        }
-        // Ascension rebirth skill: +50% essence
-        if self.has_rebirth_skill("rb_ascension") {
-            essence = (essence as f64 * 1.5) as u64;
+    /// Essence for rebirthing with `current_gp`, scaled by rebirth skill
+    /// bonuses. `exponent_bonus` raises the 0.35 power GP is taken to.
+    pub fn calculate_essence_reward(
+        &self,
+        current_gp: BigNum,
+        essence_mult: f64,
+        exponent_bonus: f64,
+    ) -> BigNum {
+        if current_gp.is_zero() {
+            return BigNum::ZERO;
         }
+        let essence = (current_gp.powf(0.35 + exponent_bonus) / 3.0).floor();
+        (essence * essence_mult).floor().max(BigNum::from(1u64))
+    }
 
-        essence.max(1)
+    pub fn has_completed_challenge(&self, id: &str) -> bool {
+        self.challenges_completed.contains(id)
     }
 
     pub fn has_rebirth_skill(&self, id: &str) -> bool {
@@ -66,7 +89,7 @@ impl RebirthState {
             return false;
         }
         if let Some(skill) = get_rebirth_skill(id) {
-            self.essence -= skill.essence_cost;
+            self.essence -= BigNum::from(skill.essence_cost);
             self.rebirth_skills.insert(id.to_string());
             true
         } else {
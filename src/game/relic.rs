@@ -1,13 +1,59 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::item::Rarity;
+use super::loadout::Loadout;
+
+/// Highest level a relic can be upgraded to.
+pub const MAX_RELIC_LEVEL: u32 = 5;
+
+/// Shards needed to go from level `n` to `n + 1`, at index `n - 1`.
+const LEVEL_COSTS: [u32; 4] = [2, 4, 7, 12];
+
+/// Shards of the relic's rarity needed to level it up from `level`, or
+/// `None` at the top level.
+pub fn level_up_cost(level: u32) -> Option<u32> {
+    LEVEL_COSTS.get(level.checked_sub(1)? as usize).copied()
+}
+
+/// Shards spent getting a relic from level 1 to `level`.
+pub fn shards_spent(level: u32) -> u32 {
+    LEVEL_COSTS.iter().take(level.saturating_sub(1) as usize).sum()
+}
+
+/// How much a relic's effect is scaled at `level`: +50% per level above 1.
+pub fn level_mult(level: u32) -> f64 {
+    1.0 + 0.5 * level.saturating_sub(1) as f64
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct RelicState {
     pub owned: Vec<String>,
     pub equipped: Vec<String>,
+    /// Shards from duplicate drops, by the duplicate's rarity.
+    pub shards: HashMap<Rarity, u32>,
+    /// Relics upgraded past level 1.
+    pub levels: HashMap<String, u32>,
+    /// Saved loadouts by slot.
+    pub loadouts: Vec<Option<Loadout>>,
 }
 
 impl RelicState {
+    /// Relic slots before skills add more; see `Modifiers::relic_slots`.
     pub const MAX_EQUIPPED: usize = 3;
+    pub const MAX_LOADOUTS: usize = 3;
+
+    pub fn level(&self, id: &str) -> u32 {
+        self.levels.get(id).copied().unwrap_or(1)
+    }
+
+    pub fn shards(&self, rarity: Rarity) -> u32 {
+        self.shards.get(&rarity).copied().unwrap_or(0)
+    }
+
+    pub fn loadout(&self, slot: usize) -> Option<&Loadout> {
+        self.loadouts.get(slot)?.as_ref()
+    }
 
     pub fn owns(&self, id: &str) -> bool {
         self.owned.iter().any(|r| r == id)
@@ -23,15 +69,11 @@ impl RelicState {
         }
     }
 
-    pub fn equip(&mut self, id: &str) -> bool {
-        if self.equipped.len() >= Self::MAX_EQUIPPED {
-            return false;
-        }
-        if !self.owns(id) || self.is_equipped(id) {
-            return false;
-        }
-        self.equipped.push(id.to_string());
-        true
+    /// Drop a relic entirely: unequipped, unowned and back to level 1.
+    pub fn remove(&mut self, id: &str) {
+        self.owned.retain(|r| r != id);
+        self.equipped.retain(|r| r != id);
+        self.levels.remove(id);
     }
 
     pub fn unequip(&mut self, id: &str) -> bool {
@@ -42,12 +84,4 @@ impl RelicState {
             false
         }
     }
-
-    pub fn toggle_equip(&mut self, id: &str) -> bool {
-        if self.is_equipped(id) {
-            self.unequip(id)
-        } else {
-            self.equip(id)
-        }
-    }
 }
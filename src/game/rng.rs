@@ -0,0 +1,66 @@
+use rand::{RngCore, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
+use serde::{Deserialize, Serialize};
+
+/// Seeded game RNG that remembers how far along its stream it is, so a
+/// saved game picks up exactly where it left off.
+///
+/// The generator's own state is saved, so loading costs the same however
+/// long the game has run. `draws` is only kept for display.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameRng {
+    seed: u64,
+    draws: u64,
+    state: Xoshiro256PlusPlus,
+}
+
+impl GameRng {
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            draws: 0,
+            state: Xoshiro256PlusPlus::seed_from_u64(seed),
+        }
+    }
+
+    /// Fresh stream with a seed taken from the OS.
+    pub fn from_os_seed() -> Self {
+        Self::from_seed(rand::random())
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Number of 64-bit steps taken since seeding.
+    pub fn draws(&self) -> u64 {
+        self.draws
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::from_os_seed()
+    }
+}
+
+// Every call below advances the underlying xoshiro stream by whole 64-bit
+// steps (next_u32 discards the low half), so `draws` counts stream steps.
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.draws += 1;
+        (self.state.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.draws += 1;
+        self.state.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for chunk in dst.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
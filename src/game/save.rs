@@ -1,27 +1,428 @@
-use directories::ProjectDirs;
-use std::fs;
-use std::path::PathBuf;
+use rand::SeedableRng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+use serde_json::{Value, json};
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 
+use super::profiles;
 use super::state::GameState;
 
-fn save_path() -> Option<PathBuf> {
-    ProjectDirs::from("", "", "lootbox-game").map(|dirs| {
-        let data_dir = dirs.data_dir();
-        fs::create_dir_all(data_dir).ok();
-        data_dir.join("save.json")
-    })
+/// Version written into new saves. Bump it and add a step to [`MIGRATIONS`]
+/// whenever the save format changes.
+pub const SAVE_VERSION: u32 = 15;
+
+/// `MIGRATIONS[n]` turns a version `n` save into a version `n + 1` save.
+///
+/// Each step works on the raw JSON and writes the shape of its own version
+/// literally, so later changes to the Rust types can't change what an old
+/// step produces.
+const MIGRATIONS: [fn(&mut Value); SAVE_VERSION as usize] = [
+    v0_add_skill_tree,
+    v1_add_rng_and_counters,
+    v2_add_last_saved,
+    v3_add_automation,
+    v4_add_loot_filter,
+    v5_add_collection,
+    v6_add_affixes,
+    v7_add_relic_shards,
+    v8_add_relic_loadouts,
+    v9_add_respecs,
+    v10_add_achievements,
+    v11_add_bounties,
+    v12_add_challenges,
+    v13_add_transcendence,
+    v14_add_divines_found,
+];
+
+/// How many previous saves are kept as `save.1.json` (newest) to `save.N.json`.
+pub const BACKUP_COUNT: usize = 5;
+
+/// A new backup is taken at most this often, so a session of autosaves
+/// doesn't push every older backup out within minutes.
+pub const BACKUP_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+fn save_path(profile: &str) -> Option<PathBuf> {
+    profiles::profile_dir(profile).map(|dir| dir.join("save.json"))
+}
+
+fn backup_path(dir: &Path, n: usize) -> PathBuf {
+    dir.join(format!("save.{n}.json"))
+}
+
+/// Write the save without ever leaving a half-written `save.json`: the new
+/// save goes to a temp file first and is renamed over the old one, which is
+/// kept as the newest backup if the last one is [`BACKUP_INTERVAL`] old.
+pub fn save_game(profile: &str, state: &GameState) -> Result<(), SaveError> {
+    let dir = profiles::profile_dir(profile).ok_or(SaveError::NoSaveDir)?;
+    fs::create_dir_all(&dir).map_err(SaveError::Io)?;
+    let text = to_save_string(state)?;
+
+    let tmp = dir.join("save.json.tmp");
+    let mut file = File::create(&tmp).map_err(SaveError::Io)?;
+    file.write_all(text.as_bytes()).map_err(SaveError::Io)?;
+    file.sync_all().map_err(SaveError::Io)?;
+    drop(file);
+
+    rotate_backups(&dir).map_err(SaveError::Io)?;
+    fs::rename(&tmp, dir.join("save.json")).map_err(SaveError::Io)
+}
+
+/// Serialize a save with its checksum.
+pub fn to_save_string(state: &GameState) -> Result<String, SaveError> {
+    let mut save = serde_json::to_value(state).map_err(SaveError::Json)?;
+    save["checksum"] = json!(checksum(&save));
+    serde_json::to_string_pretty(&save).map_err(SaveError::Json)
+}
+
+fn rotate_backups(dir: &Path) -> io::Result<()> {
+    let current = dir.join("save.json");
+    if !current.exists() {
+        return Ok(());
+    }
+    // A backup dated in the future (the clock went back) counts as due
+    let newest = fs::metadata(backup_path(dir, 1)).and_then(|m| m.modified());
+    if let Ok(elapsed) = newest.map(|time| SystemTime::now().duration_since(time))
+        && elapsed.is_ok_and(|elapsed| elapsed < BACKUP_INTERVAL)
+    {
+        return Ok(());
+    }
+    for n in (1..BACKUP_COUNT).rev() {
+        let from = backup_path(dir, n);
+        if from.exists() {
+            fs::rename(&from, backup_path(dir, n + 1))?;
+        }
+    }
+    fs::copy(&current, backup_path(dir, 1))?;
+    Ok(())
+}
+
+/// FNV-1a over the compact JSON of the save (keys sorted, no checksum).
+fn checksum(save: &Value) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in save.to_string().bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    format!("{hash:016x}")
+}
+
+/// Remove the checksum from a raw save and check it. Saves from before
+/// checksums have none and pass.
+fn verify_checksum(save: &mut Value) -> Result<(), SaveError> {
+    let Some(stored) = save.as_object_mut().and_then(|root| root.remove("checksum")) else {
+        return Ok(());
+    };
+    if stored.as_str() == Some(checksum(save).as_str()) {
+        Ok(())
+    } else {
+        Err(SaveError::ChecksumMismatch)
+    }
+}
+
+/// A backup that loads cleanly.
+#[derive(Debug, Clone)]
+pub struct Backup {
+    pub path: PathBuf,
+    pub saved_at: Option<SystemTime>,
+    pub state: GameState,
+}
+
+/// Every backup that still loads, newest first.
+pub fn load_backups(profile: &str) -> Vec<Backup> {
+    let Some(dir) = profiles::profile_dir(profile) else {
+        return Vec::new();
+    };
+    (1..=BACKUP_COUNT)
+        .map(|n| backup_path(&dir, n))
+        .filter_map(|path| {
+            let state = load_from_str(&fs::read_to_string(&path).ok()?).ok()?;
+            let saved_at = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            Some(Backup {
+                path,
+                saved_at,
+                state,
+            })
+        })
+        .collect()
+}
+
+/// Load a profile's save file, migrating it to the current version. `Ok(None)` means
+/// there is no save yet.
+pub fn load_game(profile: &str) -> Result<Option<GameState>, SaveError> {
+    let Some(path) = save_path(profile) else {
+        return Ok(None);
+    };
+    let data = match fs::read_to_string(&path) {
+        Ok(data) => data,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(SaveError::Io(e)),
+    };
+    load_from_str(&data).map(Some)
+}
+
+/// Move a save that failed to load out of the way, so the next save doesn't
+/// overwrite it. Returns where it went.
+pub fn set_aside_unreadable_save(profile: &str) -> Option<PathBuf> {
+    let path = save_path(profile)?;
+    let aside = path.with_extension("json.unreadable");
+    fs::rename(&path, &aside).ok()?;
+    Some(aside)
+}
+
+/// Parse a save of any known version.
+pub fn load_from_str(data: &str) -> Result<GameState, SaveError> {
+    let mut save: Value = serde_json::from_str(data).map_err(SaveError::Json)?;
+    verify_checksum(&mut save)?;
+    migrate(&mut save)?;
+    serde_json::from_value(save).map_err(SaveError::Json)
+}
+
+/// Bring a raw save up to [`SAVE_VERSION`].
+pub fn migrate(save: &mut Value) -> Result<(), SaveError> {
+    let version = save_version(save)?;
+    if version > SAVE_VERSION {
+        return Err(SaveError::TooNew { version });
+    }
+    for step in &MIGRATIONS[version as usize..] {
+        step(save);
+    }
+    save["save_version"] = json!(SAVE_VERSION);
+    Ok(())
+}
+
+fn save_version(save: &Value) -> Result<u32, SaveError> {
+    let Some(root) = save.as_object() else {
+        return Err(SaveError::NotAnObject);
+    };
+    match root.get("save_version") {
+        Some(v) => v
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| SaveError::BadVersion(v.clone())),
+        // Saves from before versioning, told apart by what they contain
+        None if !root.contains_key("skill_tree") => Ok(0),
+        None if !root.contains_key("rng") => Ok(1),
+        None => Ok(2),
+    }
+}
+
+fn insert_missing(object: &mut Value, key: &str, value: Value) {
+    if let Some(map) = object.as_object_mut() {
+        map.entry(key).or_insert(value);
+    }
 }
 
-pub fn save_game(state: &GameState) {
-    if let Some(path) = save_path() {
-        if let Ok(json) = serde_json::to_string_pretty(state) {
-            let _ = fs::write(path, json);
+/// v0 -> v1: the skill tree and rebirth. Chest keys and the auto opener were
+/// upgrades and become skills, and the player gets the skill points their
+/// level has earned. Item stacks, Mythic stats and sound settings also date
+/// from v1.
+fn v0_add_skill_tree(save: &mut Value) {
+    let level = save["player"]["level"].as_u64().unwrap_or(1);
+    let owned = |id: &str| save["upgrades"]["levels"][id].as_u64().unwrap_or(0) >= 1;
+    let gold = owned("gold_key");
+    let silver = gold || owned("silver_key");
+    let iron = silver || owned("iron_key");
+    let auto = owned("auto_opener");
+
+    let mut learned = Vec::new();
+    if iron {
+        learned.push("iron_key");
+    }
+    if silver {
+        learned.push("silver_key");
+    }
+    if gold {
+        learned.push("gold_key");
+    }
+    if auto {
+        // Auto opener requires swift_hands
+        learned.extend(["swift_hands", "auto_opener"]);
+    }
+    // One point per level after the first, minus what was spent above
+    let skill_points = level.saturating_sub(1).saturating_sub(learned.len() as u64);
+
+    save["skill_tree"] = json!({ "learned": learned, "skill_points": skill_points });
+    insert_missing(
+        save,
+        "rebirth",
+        json!({
+            "rebirth_count": 0,
+            "total_essence_earned": 0,
+            "essence": 0,
+            "rebirth_skills": [],
+            "gp_earned_this_run": 0,
+            "highest_level_ever": level,
+        }),
+    );
+    insert_missing(&mut save["stats"], "mythics_found", json!(0));
+    if let Some(items) = save["inventory"]["items"].as_array_mut() {
+        for item in items {
+            insert_missing(item, "count", json!(1));
+        }
+    }
+    insert_missing(save, "volume", json!(0.8));
+    insert_missing(save, "show_animations", json!(true));
+    insert_missing(save, "chest_sounds", json!(true));
+    insert_missing(save, "ui_sounds", json!(true));
+}
+
+/// v1 -> v2: the seeded loot RNG and saved skill counters. Old saves never
+/// had a seed, so they get a fresh one at the start of its stream.
+fn v1_add_rng_and_counters(save: &mut Value) {
+    let seed = rand::random::<u64>();
+    let state = serde_json::to_value(Xoshiro256PlusPlus::seed_from_u64(seed)).unwrap_or_default();
+    insert_missing(
+        save,
+        "rng",
+        json!({ "seed": seed, "draws": 0, "state": state }),
+    );
+    insert_missing(
+        save,
+        "counters",
+        json!({
+            "chests_since_xp_surge": 0,
+            "idle_income_ticks": 0,
+            "consecutive_chests": 0,
+            "empty_streak": 0,
+            "chaos_buff_ticks": 0,
+            "chaos_buff_type": null,
+            "items_sold_count": 0,
+            "catalyst_stacks": 0.0,
+            "rare_streak_count": 0,
+            "consecutive_crits": 0,
+        }),
+    );
+}
+
+/// v2 -> v3: the last-saved time for offline progress. Unknown for old
+/// saves, so they get none.
+fn v2_add_last_saved(save: &mut Value) {
+    insert_missing(save, "last_saved", json!(0));
+}
+
+/// v3 -> v4: upgrade automation rules, none yet.
+fn v3_add_automation(save: &mut Value) {
+    insert_missing(save, "automation", json!({ "enabled": false, "rules": [] }));
+}
+
+/// v4 -> v5: the loot filter, keeping everything.
+fn v4_add_loot_filter(save: &mut Value) {
+    insert_missing(
+        save,
+        "loot_filter",
+        json!({ "enabled": false, "rarities": {}, "items": {}, "keep_above_gp": 0 }),
+    );
+}
+
+/// v5 -> v6: the item collection. Whatever is in the inventory has been
+/// found; sets are checked again on load.
+fn v5_add_collection(save: &mut Value) {
+    let found: Vec<Value> = save["inventory"]["items"]
+        .as_array()
+        .map(|items| items.iter().map(|item| item["id"].clone()).collect())
+        .unwrap_or_default();
+    insert_missing(save, "collection", json!({ "found": found, "completed": [] }));
+}
+
+/// v6 -> v7: item affixes. Everything found so far is plain.
+fn v6_add_affixes(save: &mut Value) {
+    if let Some(items) = save["inventory"]["items"].as_array_mut() {
+        for item in items {
+            insert_missing(item, "affixes", json!([]));
+        }
+    }
+}
+
+/// v7 -> v8: relic shards and levels. Every relic starts at level 1.
+fn v7_add_relic_shards(save: &mut Value) {
+    insert_missing(&mut save["relics"], "shards", json!({}));
+    insert_missing(&mut save["relics"], "levels", json!({}));
+}
+
+/// v8 -> v9: saved relic loadouts, none yet.
+fn v8_add_relic_loadouts(save: &mut Value) {
+    insert_missing(&mut save["relics"], "loadouts", json!([]));
+}
+
+/// v9 -> v10: skill respecs bought this run, none yet, and the points paid
+/// for each skill. Skills learned before then refund their listed cost.
+fn v9_add_respecs(save: &mut Value) {
+    insert_missing(&mut save["skill_tree"], "respecs", json!(0));
+    insert_missing(&mut save["skill_tree"], "paid", json!({}));
+}
+
+/// v10 -> v11: achievements. Counting ones unlock from the lifetime stats
+/// once the game loads.
+fn v10_add_achievements(save: &mut Value) {
+    insert_missing(save, "achievements", json!({ "unlocked": [], "title": null }));
+}
+
+/// v11 -> v12: the bounty board. It is filled once the game loads, and
+/// rotates from the chests opened so far.
+fn v11_add_bounties(save: &mut Value) {
+    let opened = save["stats"]["chests_opened"].clone();
+    insert_missing(save, "bounties", json!({ "bounties": [], "refreshed_at": opened }));
+}
+
+/// v12 -> v13: challenge runs, none running or completed yet.
+fn v12_add_challenges(save: &mut Value) {
+    insert_missing(&mut save["rebirth"], "challenge", json!(null));
+    insert_missing(&mut save["rebirth"], "challenges_completed", json!([]));
+}
+
+/// v13 -> v14: the transcendence layer, not reached yet.
+fn v13_add_transcendence(save: &mut Value) {
+    insert_missing(
+        save,
+        "transcendence",
+        json!({ "count": 0, "aether": 0, "total_aether_earned": 0, "skills": [] }),
+    );
+}
+
+/// v14 -> v15: Divine drops are counted, none found yet.
+fn v14_add_divines_found(save: &mut Value) {
+    insert_missing(&mut save["stats"], "divines_found", json!(0));
+}
+
+/// Why a save could not be loaded.
+#[derive(Debug)]
+pub enum SaveError {
+    NoSaveDir,
+    Io(io::Error),
+    Json(serde_json::Error),
+    ChecksumMismatch,
+    NotAnObject,
+    BadVersion(Value),
+    TooNew { version: u32 },
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::NoSaveDir => write!(f, "no data directory to save in"),
+            SaveError::Io(e) => write!(f, "{}", e),
+            SaveError::Json(e) => write!(f, "save is damaged: {}", e),
+            SaveError::ChecksumMismatch => write!(f, "save is damaged: checksum does not match"),
+            SaveError::NotAnObject => write!(f, "save is damaged: not a JSON object"),
+            SaveError::BadVersion(v) => write!(f, "save has an invalid save_version: {}", v),
+            SaveError::TooNew { version } => write!(
+                f,
+                "save is from a newer version of the game (save_version {}, this build reads up to {})",
+                version, SAVE_VERSION
+            ),
         }
     }
 }
 
-pub fn load_game() -> Option<GameState> {
-    let path = save_path()?;
-    let data = fs::read_to_string(path).ok()?;
-    serde_json::from_str(&data).ok()
+impl std::error::Error for SaveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SaveError::Io(e) => Some(e),
+            SaveError::Json(e) => Some(e),
+            _ => None,
+        }
+    }
 }
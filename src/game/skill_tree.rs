@@ -1,12 +1,25 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use crate::data::skills::{all_skills, get_skill};
 
+/// GP to respec after `respecs` earlier respecs this run, at player `level`.
+/// Doubles each time; resetting the whole tree costs three times as much.
+pub fn respec_cost(respecs: u32, level: u32, whole_tree: bool) -> u64 {
+    let base = 250 * level.max(1) as u64;
+    let cost = base.saturating_mul(1u64.checked_shl(respecs).unwrap_or(u64::MAX));
+    if whole_tree { cost.saturating_mul(3) } else { cost }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct SkillTreeState {
     pub learned: HashSet<String>,
     pub skill_points: u32,
+    /// Respecs bought this run; each one costs more.
+    pub respecs: u32,
+    /// Points paid for each learned skill, which is what a respec refunds.
+    /// Skills learned before this was recorded refund their listed cost.
+    pub paid: HashMap<String, u32>,
 }
 
 impl SkillTreeState {
@@ -14,13 +27,14 @@ impl SkillTreeState {
         self.learned.contains(id)
     }
 
-    pub fn can_learn(&self, id: &str) -> bool {
+    /// Whether `id` can be learned with skill costs multiplied by `cost_mult`.
+    pub fn can_learn(&self, id: &str, cost_mult: u32) -> bool {
         if self.has_skill(id) {
             return false;
         }
         if let Some(skill) = get_skill(id) {
             // Check if we have enough skill points for the cost
-            if self.skill_points < skill.cost {
+            if self.skill_points < skill.cost * cost_mult {
                 return false;
             }
             // Check prerequisites
@@ -33,57 +47,60 @@ impl SkillTreeState {
         }
     }
 
-    pub fn learn(&mut self, id: &str) -> bool {
-        if !self.can_learn(id) {
+    pub fn learn(&mut self, id: &str, cost_mult: u32) -> bool {
+        if !self.can_learn(id, cost_mult) {
             return false;
         }
         if let Some(skill) = get_skill(id) {
+            let cost = skill.cost * cost_mult;
             self.learned.insert(id.to_string());
-            self.skill_points = self.skill_points.saturating_sub(skill.cost);
+            self.paid.insert(id.to_string(), cost);
+            self.skill_points = self.skill_points.saturating_sub(cost);
             true
         } else {
             false
         }
     }
 
-    pub fn total_learned(&self) -> u32 {
-        self.learned.len() as u32
+    /// Learned and not a prerequisite of any other learned skill, so it can
+    /// be unlearned on its own.
+    pub fn is_leaf(&self, id: &str) -> bool {
+        self.has_skill(id)
+            && !all_skills()
+                .iter()
+                .any(|s| self.has_skill(s.id) && s.prerequisites.contains(&id))
     }
 
-    /// Migrate from old save: grant retroactive skill points based on level,
-    /// and auto-learn skills for old key/auto_opener upgrades.
-    pub fn migrate_from_old_save(
-        &mut self,
-        player_level: u32,
-        old_iron_key: bool,
-        old_silver_key: bool,
-        old_gold_key: bool,
-        old_auto_opener: bool,
-    ) {
-        // Auto-convert old key upgrades to skills
-        if old_iron_key && !self.has_skill("iron_key") {
-            self.learned.insert("iron_key".to_string());
-        }
-        if old_silver_key && !self.has_skill("silver_key") {
-            // Must have iron_key as prerequisite
-            self.learned.insert("iron_key".to_string());
-            self.learned.insert("silver_key".to_string());
-        }
-        if old_gold_key && !self.has_skill("gold_key") {
-            self.learned.insert("iron_key".to_string());
-            self.learned.insert("silver_key".to_string());
-            self.learned.insert("gold_key".to_string());
-        }
-        if old_auto_opener && !self.has_skill("auto_opener") {
-            // Auto opener requires swift_hands
-            self.learned.insert("swift_hands".to_string());
-            self.learned.insert("auto_opener".to_string());
+    /// Points learning `id` cost, as refunded on a respec.
+    pub fn paid_for(&self, id: &str) -> u32 {
+        self.paid
+            .get(id)
+            .copied()
+            .unwrap_or_else(|| get_skill(id).map_or(0, |s| s.cost))
+    }
+
+    /// Unlearn a leaf skill and refund the points paid for it.
+    pub fn unlearn(&mut self, id: &str) -> bool {
+        if !self.is_leaf(id) {
+            return false;
         }
+        self.skill_points += self.paid_for(id);
+        self.learned.remove(id);
+        self.paid.remove(id);
+        true
+    }
 
-        // Grant retroactive skill points: level - 1 total points (level 1 = 0 points)
-        // minus already learned skills
-        let total_points_earned = player_level.saturating_sub(1);
-        self.skill_points = total_points_earned.saturating_sub(self.total_learned());
+    /// Unlearn every skill, refunding all the points paid. Returns the ids.
+    pub fn unlearn_all(&mut self) -> Vec<String> {
+        let ids: Vec<String> = self.learned.iter().cloned().collect();
+        self.skill_points += ids.iter().map(|id| self.paid_for(id)).sum::<u32>();
+        self.learned.clear();
+        self.paid.clear();
+        ids
+    }
+
+    pub fn total_learned(&self) -> u32 {
+        self.learned.len() as u32
     }
 
     /// Returns a flat list of all skill IDs in display order (grouped by branch).
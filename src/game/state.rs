@@ -1,80 +1,89 @@
 use serde::{Deserialize, Serialize};
 
+use super::achievements::AchievementState;
+use super::automation::AutomationState;
+use super::collection::Collection;
+use super::bignum::BigNum;
+use super::bounty::BountyBoard;
 use super::chest::{ChestProgress, ChestType};
 use super::inventory::Inventory;
+use super::loot_filter::LootFilter;
 use super::player::Player;
 use super::rebirth::RebirthState;
 use super::relic::RelicState;
+use super::rng::GameRng;
+use super::save::SAVE_VERSION;
 use super::skill_tree::SkillTreeState;
+use super::transcendence::TranscendenceState;
 use super::upgrade::UpgradeState;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct LifetimeStats {
     pub chests_opened: u64,
     pub items_found: u64,
-    pub total_gp_earned: u64,
-    pub total_xp_earned: u64,
+    pub total_gp_earned: BigNum,
+    pub total_xp_earned: BigNum,
     pub legendaries_found: u64,
     pub epics_found: u64,
     pub rares_found: u64,
-    #[serde(default)]
     pub mythics_found: u64,
+    pub divines_found: u64,
     pub crits_rolled: u64,
-    pub highest_single_gp: u64,
+    pub highest_single_gp: BigNum,
 }
 
-impl Default for LifetimeStats {
-    fn default() -> Self {
-        Self {
-            chests_opened: 0,
-            items_found: 0,
-            total_gp_earned: 0,
-            total_xp_earned: 0,
-            legendaries_found: 0,
-            epics_found: 0,
-            rares_found: 0,
-            mythics_found: 0,
-            crits_rolled: 0,
-            highest_single_gp: 0,
-        }
-    }
+/// Short-lived counters driving streak and proc skills. Saved so a reloaded
+/// game rolls the same as one that never stopped; cleared on rebirth.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SkillCounters {
+    pub chests_since_xp_surge: u32,
+    pub idle_income_ticks: u32,
+    pub consecutive_chests: u32,     // momentum skill
+    pub empty_streak: u32,           // gambler_spirit skill
+    pub chaos_buff_ticks: u32,       // chaos_surge skill
+    pub chaos_buff_type: Option<u8>, // 0=GP, 1=XP, 2=Speed
+    pub items_sold_count: u64,       // alchemy tracking
+    pub catalyst_stacks: f64,        // catalyst_brew (resets on rebirth)
+    pub rare_streak_count: u32,      // lucky_streak tracking
+    pub consecutive_crits: u32,      // combo_counter tracking
 }
 
+/// Everything that goes into a save. Saves are migrated to the current
+/// [`SAVE_VERSION`] before they are deserialized, so every field is present.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameState {
+    pub save_version: u32,
     pub player: Player,
     pub inventory: Inventory,
     pub upgrades: UpgradeState,
     pub relics: RelicState,
-    #[serde(default)]
     pub skill_tree: SkillTreeState,
-    #[serde(default)]
     pub rebirth: RebirthState,
     pub chest_progress: ChestProgress,
     pub current_chest_type: ChestType,
     pub stats: LifetimeStats,
     pub unlocked_chests: Vec<ChestType>,
-    #[serde(default = "default_volume")]
     pub volume: f32,
-    #[serde(default = "default_true")]
     pub show_animations: bool,
-    #[serde(default = "default_true")]
     pub chest_sounds: bool,
-    #[serde(default = "default_true")]
     pub ui_sounds: bool,
-}
-
-fn default_volume() -> f32 {
-    0.8
-}
-
-fn default_true() -> bool {
-    true
+    /// Loot RNG seed and stream position.
+    pub rng: GameRng,
+    pub counters: SkillCounters,
+    /// Unix time of the last save, for offline progress. 0 if unknown.
+    pub last_saved: u64,
+    pub automation: AutomationState,
+    pub loot_filter: LootFilter,
+    pub collection: Collection,
+    pub achievements: AchievementState,
+    pub bounties: BountyBoard,
+    pub transcendence: TranscendenceState,
 }
 
 impl Default for GameState {
     fn default() -> Self {
         Self {
+            save_version: SAVE_VERSION,
             player: Player::default(),
             inventory: Inventory::default(),
             upgrades: UpgradeState::default(),
@@ -85,10 +94,19 @@ impl Default for GameState {
             current_chest_type: ChestType::Wooden,
             stats: LifetimeStats::default(),
             unlocked_chests: vec![ChestType::Wooden],
-            volume: default_volume(),
-            show_animations: default_true(),
-            chest_sounds: default_true(),
-            ui_sounds: default_true(),
+            volume: 0.8,
+            show_animations: true,
+            chest_sounds: true,
+            ui_sounds: true,
+            rng: GameRng::default(),
+            counters: SkillCounters::default(),
+            last_saved: 0,
+            automation: AutomationState::default(),
+            loot_filter: LootFilter::default(),
+            collection: Collection::default(),
+            achievements: AchievementState::default(),
+            bounties: BountyBoard::default(),
+            transcendence: TranscendenceState::default(),
         }
     }
 }
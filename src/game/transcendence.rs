@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use super::bignum::BigNum;
+use crate::data::transcendence_skills::get_transcendence_skill;
+
+/// Total essence that has to be earned before transcending pays anything.
+pub const MIN_ESSENCE: u64 = 10_000;
+
+/// The prestige layer above rebirth. Kept through rebirths and transcendence.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TranscendenceState {
+    pub count: u32,
+    pub aether: BigNum,
+    pub total_aether_earned: BigNum,
+    pub skills: HashSet<String>,
+}
+
+impl TranscendenceState {
+    /// Aether for transcending with `total_essence` earned and `best_level`
+    /// reached: grows with the square root of the essence and with the level.
+    pub fn calculate_aether_reward(&self, total_essence: BigNum, best_level: u32) -> BigNum {
+        if !self.can_transcend(total_essence) {
+            return BigNum::ZERO;
+        }
+        let level_factor = best_level as f64 / 50.0;
+        ((total_essence / 2500.0).powf(0.5) * level_factor)
+            .floor()
+            .max(BigNum::from(1u64))
+    }
+
+    pub fn can_transcend(&self, total_essence: BigNum) -> bool {
+        total_essence >= MIN_ESSENCE
+    }
+
+    pub fn has_skill(&self, id: &str) -> bool {
+        self.skills.contains(id)
+    }
+
+    pub fn can_learn(&self, id: &str) -> bool {
+        if self.has_skill(id) {
+            return false;
+        }
+        if let Some(skill) = get_transcendence_skill(id) {
+            if self.aether < skill.aether_cost {
+                return false;
+            }
+            skill
+                .prerequisites
+                .iter()
+                .all(|pre| self.has_skill(pre))
+        } else {
+            false
+        }
+    }
+
+    pub fn learn(&mut self, id: &str) -> bool {
+        if !self.can_learn(id) {
+            return false;
+        }
+        if let Some(skill) = get_transcendence_skill(id) {
+            self.aether -= BigNum::from(skill.aether_cost);
+            self.skills.insert(id.to_string());
+            true
+        } else {
+            false
+        }
+    }
+}
@@ -1,15 +1,17 @@
 mod animation;
 mod app;
 mod audio;
-mod data;
 mod event;
-mod game;
 mod ui;
 
+use lootbox_game::{data, game};
+
 use std::io;
+use std::path::Path;
 
 use app::App;
-use color_eyre::Result;
+use color_eyre::{Result, eyre::eyre};
+use data::pack::{self, DataPack};
 use crossterm::{
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
@@ -20,6 +22,38 @@ use ratatui::prelude::*;
 fn main() -> Result<()> {
     color_eyre::install()?;
 
+    let seed = parse_seed()?;
+    let profile = arg_value("--profile")?;
+    if let Some(name) = &profile {
+        game::profiles::validate_name(name)?;
+    }
+
+    if let Some(dir) = arg_value("--save-dir")?
+        && !game::profiles::use_data_dir(dir.into())
+    {
+        return Err(eyre!("saves were read before --save-dir was applied"));
+    }
+
+    if let Some(dir) = arg_value("--export-data")? {
+        DataPack::builtin().write_dir(Path::new(&dir))?;
+        println!("Wrote the built-in data pack to {dir}");
+        return Ok(());
+    }
+    if let Some(dir) = arg_value("--data-dir")? {
+        let data = DataPack::load_dir(Path::new(&dir))?;
+        if !pack::install(data) {
+            return Err(eyre!("game data was read before --data-dir was applied"));
+        }
+    }
+    if has_flag("--check-data") {
+        let errors = pack::current().validate();
+        if !errors.is_empty() {
+            return Err(pack::PackError::Invalid(errors).into());
+        }
+        println!("Data pack OK");
+        return Ok(());
+    }
+
     // Terminal setup
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -27,7 +61,7 @@ fn main() -> Result<()> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let result = run(&mut terminal);
+    let result = run(&mut terminal, seed, profile);
 
     // Restore terminal
     disable_raw_mode()?;
@@ -37,8 +71,44 @@ fn main() -> Result<()> {
     result
 }
 
-fn run(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
-    let mut app = App::new();
+/// Read `--seed <n>` (or `--seed=<n>`) from the command line.
+fn parse_seed() -> Result<Option<u64>> {
+    let Some(value) = arg_value("--seed")? else {
+        return Ok(None);
+    };
+    let seed = value
+        .parse::<u64>()
+        .map_err(|_| eyre!("--seed must be a whole number, got {value:?}"))?;
+    Ok(Some(seed))
+}
+
+/// Value of `<flag> <value>` (or `<flag>=<value>`) on the command line.
+fn arg_value(flag: &str) -> Result<Option<String>> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == flag {
+            return args
+                .next()
+                .map(Some)
+                .ok_or_else(|| eyre!("{flag} needs a value"));
+        }
+        if let Some(v) = arg.strip_prefix(flag).and_then(|rest| rest.strip_prefix('=')) {
+            return Ok(Some(v.to_string()));
+        }
+    }
+    Ok(None)
+}
+
+fn has_flag(flag: &str) -> bool {
+    std::env::args().skip(1).any(|arg| arg == flag)
+}
+
+fn run(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    seed: Option<u64>,
+    profile: Option<String>,
+) -> Result<()> {
+    let mut app = App::new(seed, profile);
     let mut event_handler = EventHandler::new(33); // ~30 ticks/sec
 
     loop {
@@ -3,15 +3,17 @@ use ratatui::widgets::{Block, Borders, Gauge, Paragraph};
 use ratatui::Frame;
 
 use crate::app::App;
-use crate::game::chest::{ChestState, ChestType};
+use crate::data::challenges::get_challenge;
+use crate::game::chest::ChestState;
 use super::chest_art::get_chest_art;
+use super::widgets::number::format_number;
 use super::widgets::rarity_label::rarity_span;
 
 pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(app.state.current_chest_type.color()))
-        .title(format!(" {} Chest ", app.state.current_chest_type.name()));
+        .border_style(Style::default().fg(app.state().current_chest_type.color()))
+        .title(format!(" {} Chest ", app.state().current_chest_type.name()));
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
@@ -41,7 +43,7 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
     draw_messages(frame, app, sections[5]);
 }
 
-fn draw_chest_selector(frame: &mut Frame, app: &App, area: Rect) {
+fn draw_chest_selector(frame: &mut Frame, _app: &App, area: Rect) {
     let line = Line::from(Span::styled(
         "Press [C] for chests",
         Style::default().fg(Color::DarkGray),
@@ -51,9 +53,9 @@ fn draw_chest_selector(frame: &mut Frame, app: &App, area: Rect) {
 
 fn draw_chest_art(frame: &mut Frame, app: &App, area: Rect) {
     let art = get_chest_art(
-        app.state.current_chest_type,
-        app.state.chest_progress.state,
-        app.state.chest_progress.ticks_elapsed,
+        app.state().current_chest_type,
+        app.state().chest_progress.state,
+        app.state().chest_progress.ticks_elapsed,
     );
 
     let lines: Vec<Line> = art
@@ -62,7 +64,7 @@ fn draw_chest_art(frame: &mut Frame, app: &App, area: Rect) {
         .map(|l| {
             Line::from(Span::styled(
                 *l,
-                Style::default().fg(app.state.current_chest_type.color()),
+                Style::default().fg(app.state().current_chest_type.color()),
             ))
         })
         .collect();
@@ -77,8 +79,8 @@ fn draw_chest_art(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn draw_progress_bar(frame: &mut Frame, app: &App, area: Rect) {
-    let progress = app.state.chest_progress.progress_fraction();
-    let label = match app.state.chest_progress.state {
+    let progress = app.state().chest_progress.progress_fraction();
+    let label = match app.state().chest_progress.state {
         ChestState::Idle => "Press [Space] to open".to_string(),
         ChestState::Opening => format!("Opening... {:.0}%", progress * 100.0),
         ChestState::Revealing => "Collect! [Space]".to_string(),
@@ -88,7 +90,7 @@ fn draw_progress_bar(frame: &mut Frame, app: &App, area: Rect) {
     let gauge = Gauge::default()
         .gauge_style(
             Style::default()
-                .fg(app.state.current_chest_type.color())
+                .fg(app.state().current_chest_type.color())
                 .bg(Color::DarkGray),
         )
         .ratio(progress)
@@ -97,7 +99,7 @@ fn draw_progress_bar(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn draw_reveal(frame: &mut Frame, app: &App, area: Rect) {
-    if let Some(ref item) = app.state.chest_progress.last_item {
+    if let Some(ref item) = app.state().chest_progress.last_item {
         let mut lines = vec![
             Line::from(vec![
                 rarity_span(item.rarity),
@@ -108,7 +110,11 @@ fn draw_reveal(frame: &mut Frame, app: &App, area: Rect) {
                         .add_modifier(Modifier::BOLD),
                 ),
             ]),
-            Line::from(format!("  +{} GP  +{} XP", item.gp_value, item.xp_value)),
+            Line::from(format!(
+                "  +{} GP  +{} XP",
+                format_number(item.gp_value),
+                format_number(item.xp_value)
+            )),
         ];
         if item.is_crit {
             lines.push(Line::from(Span::styled(
@@ -124,9 +130,9 @@ fn draw_reveal(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn draw_player_status(frame: &mut Frame, app: &App, area: Rect) {
-    let player = &app.state.player;
-    let xp_progress = if player.xp_to_next > 0 {
-        player.xp as f64 / player.xp_to_next as f64
+    let player = &app.state().player;
+    let xp_progress = if !player.xp_to_next.is_zero() {
+        player.xp.to_f64() / player.xp_to_next.to_f64()
     } else {
         0.0
     };
@@ -137,18 +143,29 @@ fn draw_player_status(frame: &mut Frame, app: &App, area: Rect) {
         .split(area);
 
     // Level + XP bar
-    let level_line = Line::from(vec![
-        Span::styled(
-            format!("Lv.{}", player.level),
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Span::raw(format!(
-            "  XP: {}/{}",
-            player.xp, player.xp_to_next
-        )),
-    ]);
+    let mut level_spans = vec![Span::styled(
+        format!("Lv.{}", player.level),
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    )];
+    if let Some(title) = &app.state().achievements.title {
+        level_spans.push(Span::styled(format!(" {}", title), Style::default().fg(Color::Magenta)));
+    }
+    if let Some(active) = &app.state().rebirth.challenge
+        && let Some(challenge) = get_challenge(&active.id)
+    {
+        let left = active.secs_left();
+        level_spans.push(Span::styled(
+            format!("  [{} {}:{:02}]", challenge.name, left / 60, left % 60),
+            Style::default().fg(Color::LightRed),
+        ));
+    }
+    level_spans.push(Span::raw(format!(
+        "  XP: {}/{}",
+        format_number(player.xp), format_number(player.xp_to_next)
+    )));
+    let level_line = Line::from(level_spans);
     frame.render_widget(Paragraph::new(level_line), status_layout[0]);
 
     // XP gauge
@@ -167,12 +184,18 @@ fn draw_player_status(frame: &mut Frame, app: &App, area: Rect) {
                 .add_modifier(Modifier::BOLD),
         ),
     ];
-    if app.state.rebirth.rebirth_count > 0 || app.state.rebirth.essence > 0 {
+    if app.state().rebirth.rebirth_count > 0 || app.state().rebirth.essence > 0 {
         stats_spans.push(Span::styled(
-            format!("  Ess: {}", app.state.rebirth.essence),
+            format!("  Ess: {}", format_number(app.state().rebirth.essence)),
             Style::default().fg(Color::Rgb(200, 150, 255)),
         ));
     }
+    if app.state().transcendence.count > 0 {
+        stats_spans.push(Span::styled(
+            format!("  Ae: {}", format_number(app.state().transcendence.aether)),
+            Style::default().fg(Color::Rgb(120, 220, 255)),
+        ));
+    }
     stats_spans.push(Span::raw(format!(
         "  Lk: {:.0}  Spd: {:.1}x  Crt: {:.0}%",
         player.luck,
@@ -202,21 +225,3 @@ fn draw_messages(frame: &mut Frame, app: &App, area: Rect) {
     let paragraph = Paragraph::new(lines);
     frame.render_widget(paragraph, area);
 }
-
-fn format_number(n: u64) -> String {
-    if n >= 1_000_000_000_000_000_000 {
-        format!("{:.2}Qi", n as f64 / 1_000_000_000_000_000_000.0)
-    } else if n >= 1_000_000_000_000_000 {
-        format!("{:.2}Qa", n as f64 / 1_000_000_000_000_000.0)
-    } else if n >= 1_000_000_000_000 {
-        format!("{:.2}T", n as f64 / 1_000_000_000_000.0)
-    } else if n >= 1_000_000_000 {
-        format!("{:.2}B", n as f64 / 1_000_000_000.0)
-    } else if n >= 1_000_000 {
-        format!("{:.2}M", n as f64 / 1_000_000.0)
-    } else if n >= 1_000 {
-        format!("{:.1}K", n as f64 / 1_000.0)
-    } else {
-        n.to_string()
-    }
-}
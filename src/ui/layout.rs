@@ -2,7 +2,9 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph};
 use ratatui::Frame;
 
-use crate::app::App;
+use crate::app::{App, CHEST_MENU_PAGE, RestoreOffer};
+use crate::data::relics;
+use crate::game::offline::OfflineReport;
 use super::game_view;
 use super::tab_panel;
 
@@ -51,12 +53,153 @@ pub fn draw_layout(frame: &mut Frame, app: &App) {
     // Float texts overlay
     draw_float_texts(frame, app, columns[0]);
 
+    // Progress made while the game was closed
+    if let Some(report) = &app.offline_report {
+        draw_offline_overlay(frame, report, size);
+    }
+
+    // Damaged save: offer a backup
+    if let Some(offer) = &app.restore_offer {
+        draw_restore_overlay(frame, offer, size);
+    }
+
     // Firework and flash overlays (rendered directly to buffer)
     let buf = frame.buffer_mut();
     app.flashes.render(buf, size);
     app.fireworks.render(buf, size);
 }
 
+fn draw_restore_overlay(frame: &mut Frame, offer: &RestoreOffer, area: Rect) {
+    let overlay_width = 56.min(area.width.saturating_sub(4));
+    let overlay_height = 14.min(area.height.saturating_sub(4));
+    let x = (area.width.saturating_sub(overlay_width)) / 2;
+    let y = (area.height.saturating_sub(overlay_height)) / 2;
+    let overlay_area = Rect::new(x, y, overlay_width, overlay_height);
+
+    // Clear background
+    let clear = ratatui::widgets::Clear;
+    frame.render_widget(clear, overlay_area);
+
+    let backup = &offer.backup.state;
+    let age = offer
+        .backup
+        .saved_at
+        .and_then(|t| t.elapsed().ok())
+        .map(|d| format_age(d.as_secs()))
+        .unwrap_or_else(|| "an unknown time ago".to_string());
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            " Your save could not be loaded:",
+            Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            format!(" {}", offer.error),
+            Style::default().fg(Color::Red),
+        )),
+        Line::from(""),
+        Line::from(format!(" A backup from {} still loads:", age)),
+        Line::from(Span::styled(
+            format!(
+                "   Level {}  {} GP  {} rebirths",
+                backup.player.level, backup.player.gp, backup.rebirth.rebirth_count
+            ),
+            Style::default().fg(Color::Cyan),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(" [Y] Restore backup", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::raw("   "),
+            Span::styled("[N] Start a new game", Style::default().fg(Color::DarkGray)),
+        ]),
+    ];
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Red))
+        .title(" Save Damaged ");
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(ratatui::widgets::Wrap { trim: false });
+    frame.render_widget(paragraph, overlay_area);
+}
+
+fn draw_offline_overlay(frame: &mut Frame, report: &OfflineReport, area: Rect) {
+    let overlay_width = 50.min(area.width.saturating_sub(4));
+    let overlay_height = (12 + report.relics.len() as u16).min(area.height.saturating_sub(4));
+    let x = (area.width.saturating_sub(overlay_width)) / 2;
+    let y = (area.height.saturating_sub(overlay_height)) / 2;
+    let overlay_area = Rect::new(x, y, overlay_width, overlay_height);
+
+    // Clear background
+    let clear = ratatui::widgets::Clear;
+    frame.render_widget(clear, overlay_area);
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(format!(" You were away for {}.", format_duration(report.away_secs))),
+    ];
+    if report.simulated_secs < report.away_secs {
+        lines.push(Line::from(Span::styled(
+            format!(" Offline progress is capped at {}.", format_duration(report.simulated_secs)),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    lines.push(Line::from(""));
+    let stat = |label: &str, value: String, color: Color| {
+        Line::from(vec![
+            Span::styled(format!("   {:<10}", label), Style::default().fg(Color::White)),
+            Span::styled(value, Style::default().fg(color).add_modifier(Modifier::BOLD)),
+        ])
+    };
+    lines.push(stat("GP", format!("+{}", report.gp), Color::Yellow));
+    lines.push(stat("XP", format!("+{}", report.xp), Color::Cyan));
+    if report.levels > 0 {
+        lines.push(stat("Levels", format!("+{}", report.levels), Color::Green));
+    }
+    lines.push(stat("Items", format!("{} from {} chests", report.items, report.chests), Color::White));
+    for id in &report.relics {
+        if let Some(relic) = relics::get_relic(id) {
+            lines.push(Line::from(Span::styled(
+                format!("   Relic found: {}", relic.name),
+                Style::default().fg(relic.rarity.color()).add_modifier(Modifier::BOLD),
+            )));
+        }
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " Press any key to continue",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .title(" While You Were Away ");
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(ratatui::widgets::Wrap { trim: false });
+    frame.render_widget(paragraph, overlay_area);
+}
+
+fn format_duration(secs: u64) -> String {
+    match secs {
+        0..60 => format!("{} seconds", secs),
+        60..3600 => format!("{} minutes", secs / 60),
+        _ => format!("{}h {}m", secs / 3600, secs % 3600 / 60),
+    }
+}
+
+pub fn format_age(secs: u64) -> String {
+    match secs {
+        0..60 => format!("{} seconds ago", secs),
+        60..3600 => format!("{} minutes ago", secs / 60),
+        3600..86400 => format!("{} hours ago", secs / 3600),
+        _ => format!("{} days ago", secs / 86400),
+    }
+}
+
 fn draw_help_overlay(frame: &mut Frame, area: Rect) {
     let overlay_width = 50.min(area.width.saturating_sub(4));
     let overlay_height = 18.min(area.height.saturating_sub(4));
@@ -99,49 +242,28 @@ fn draw_help_overlay(frame: &mut Frame, area: Rect) {
 fn draw_chest_menu_overlay(frame: &mut Frame, app: &App, area: Rect) {
     use crate::game::chest::ChestType;
 
-    let overlay_width = 50.min(area.width.saturating_sub(4));
-    let overlay_height = 20.min(area.height.saturating_sub(4));
-    let x = (area.width.saturating_sub(overlay_width)) / 2;
-    let y = area.y + 2; // Position near top of game view
-    let overlay_area = Rect::new(x, y, overlay_width, overlay_height);
-
-    // Clear background
-    let clear = ratatui::widgets::Clear;
-    frame.render_widget(clear, overlay_area);
-
+    let mut selected_line: u16 = 0;
     let mut lines = vec![
         Line::from(Span::styled("Select Chest Type", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))),
         Line::from(""),
     ];
 
-    for (i, ct) in ChestType::ALL.iter().enumerate() {
-        let unlocked = app.state.unlocked_chests.contains(ct);
+    let page = app.chest_menu_page_range();
+    for (i, ct) in ChestType::ALL.iter().enumerate().take(page.end).skip(page.start) {
+        let unlocked = app.state().unlocked_chests.contains(ct);
         let is_selected = i == app.chest_menu_selected;
+        if is_selected {
+            selected_line = lines.len() as u16;
+        }
         let level_req = ct.required_level();
 
-        let key_name = match ct {
-            ChestType::Wooden => "None",
-            ChestType::Iron => "Iron Key",
-            ChestType::Silver => "Silver Key",
-            ChestType::Gold => "Gold Key",
-            ChestType::Crystal => "Crystal Key",
-            ChestType::Shadow => "Shadow Key",
-            ChestType::Void => "Void Key",
-        };
+        let key_name = format!("{} Key", ct.name());
 
-        let has_level = app.state.player.level >= level_req;
-        let has_key = match ct {
-            ChestType::Wooden => true,
-            ChestType::Iron => app.state.upgrades.get_level("iron_key") > 0,
-            ChestType::Silver => app.state.upgrades.get_level("silver_key") > 0,
-            ChestType::Gold => app.state.upgrades.get_level("gold_key") > 0,
-            ChestType::Crystal => app.state.upgrades.get_level("crystal_key") > 0,
-            ChestType::Shadow => app.state.upgrades.get_level("shadow_key") > 0,
-            ChestType::Void => app.state.upgrades.get_level("void_key") > 0,
-        };
+        let has_level = app.state().player.level >= level_req;
+        let has_key = app.engine.has_chest_key(*ct);
 
         let marker = if is_selected { "\u{25b6} " } else { "  " };
-        let number = format!("[{}] ", i + 1);
+        let number = format!("[{}] ", i - page.start + 1);
 
         let name_style = if is_selected {
             Style::default().fg(ct.color()).add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
@@ -170,7 +292,7 @@ fn draw_chest_menu_overlay(frame: &mut Frame, app: &App, area: Rect) {
                 if !has_level {
                     req_parts.push(format!("{} ({})", key_name, level_req));
                 } else {
-                    req_parts.push(key_name.to_string());
+                    req_parts.push(key_name);
                 }
             } else if !has_level {
                 req_parts.push(format!("Level {}", level_req));
@@ -190,16 +312,38 @@ fn draw_chest_menu_overlay(frame: &mut Frame, app: &App, area: Rect) {
         lines.push(Line::from(""));
     }
 
-    lines.push(Line::from(Span::styled(
-        "[↑↓] Navigate  [E] Select  [1-7] Quick Select  [C/Space/Esc] Close",
-        Style::default().fg(Color::DarkGray),
-    )));
+    let mut footer = format!("[↑↓] Navigate  [E] Select  [1-{}] Quick Select", page.len());
+    let pages = ChestType::ALL.len().div_ceil(CHEST_MENU_PAGE);
+    if pages > 1 {
+        footer.push_str(&format!("  [←→] Page {}/{}", page.start / CHEST_MENU_PAGE + 1, pages));
+    }
+    footer.push_str("  [C/Space/Esc] Close");
+    lines.push(Line::from(Span::styled(footer, Style::default().fg(Color::DarkGray))));
 
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Cyan))
         .title(" Chest Selection ");
-    let paragraph = Paragraph::new(lines).block(block);
+
+    // Grow with the chest list, scrolling to the selection when it doesn't fit
+    let overlay_width = 50.min(area.width.saturating_sub(4));
+    let overlay_height = (lines.len() as u16 + 2).min(area.height.saturating_sub(4));
+    let x = (area.width.saturating_sub(overlay_width)) / 2;
+    let y = area.y + 2; // Position near top of game view
+    let overlay_area = Rect::new(x, y, overlay_width, overlay_height);
+
+    // Clear background
+    let clear = ratatui::widgets::Clear;
+    frame.render_widget(clear, overlay_area);
+
+    let visible_height = overlay_height.saturating_sub(2);
+    let margin = 3u16;
+    let scroll_y = if selected_line + margin >= visible_height {
+        (selected_line + margin + 1).saturating_sub(visible_height)
+    } else {
+        0
+    };
+    let paragraph = Paragraph::new(lines).block(block).scroll((scroll_y, 0));
     frame.render_widget(paragraph, overlay_area);
 }
 
@@ -1,6 +1,7 @@
 mod chest_art;
 mod game_view;
 mod layout;
+mod profile_picker;
 mod tab_panel;
 pub mod tabs;
 pub mod widgets;
@@ -10,5 +11,9 @@ use ratatui::Frame;
 use crate::app::App;
 
 pub fn draw(frame: &mut Frame, app: &App) {
+    if let Some(picker) = &app.picker {
+        profile_picker::draw(frame, picker);
+        return;
+    }
     layout::draw_layout(frame, app);
 }
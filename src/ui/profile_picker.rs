@@ -0,0 +1,101 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::app::ProfilePicker;
+use super::layout::format_age;
+
+pub fn draw(frame: &mut Frame, picker: &ProfilePicker) {
+    let area = frame.area();
+    let width = 60.min(area.width.saturating_sub(4));
+    let height = (picker.profiles.len() as u16 + 10).clamp(14, 24).min(area.height.saturating_sub(2));
+    let x = (area.width.saturating_sub(width)) / 2;
+    let y = (area.height.saturating_sub(height)) / 2;
+    let picker_area = Rect::new(x, y, width, height);
+    frame.render_widget(Clear, picker_area);
+
+    let mut lines = vec![Line::from("")];
+
+    if picker.profiles.is_empty() {
+        lines.push(Line::from(Span::styled(
+            " No profiles yet. Press [N] to create one.",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    for (i, profile) in picker.profiles.iter().enumerate() {
+        let selected = i == picker.selected;
+        let marker = if selected { "\u{25b6} " } else { "  " };
+        let name_style = if selected {
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let last_played = profile
+            .last_played
+            .and_then(|t| t.elapsed().ok())
+            .map(|d| format_age(d.as_secs()))
+            .unwrap_or_else(|| "never played".to_string());
+
+        let mut spans = vec![
+            Span::styled(format!(" {}{:<24}", marker, profile.name), name_style),
+        ];
+        if profile.damaged {
+            spans.push(Span::styled("damaged save", Style::default().fg(Color::Red)));
+        } else {
+            spans.push(Span::styled(
+                format!("Lv {:<4}", profile.level),
+                Style::default().fg(Color::Cyan),
+            ));
+            spans.push(Span::styled(
+                format!("R{:<4}", profile.rebirth_count),
+                Style::default().fg(Color::Magenta),
+            ));
+        }
+        spans.push(Span::styled(
+            format!(" {}", last_played),
+            Style::default().fg(Color::DarkGray),
+        ));
+        lines.push(Line::from(spans));
+    }
+
+    lines.push(Line::from(""));
+    if let Some(input) = &picker.input {
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {}: ", input.action.label()), Style::default().fg(Color::White)),
+            Span::styled(
+                format!("{}_", input.text),
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            ),
+        ]));
+        lines.push(Line::from(Span::styled(
+            " [Enter] Confirm  [Esc] Cancel",
+            Style::default().fg(Color::DarkGray),
+        )));
+    } else if picker.confirm_delete {
+        lines.push(Line::from(Span::styled(
+            " Press [D] again to delete this profile and its backups",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )));
+    } else {
+        lines.push(Line::from(Span::styled(
+            " [Enter] Play  [N] New  [C] Copy  [R] Rename  [D] Delete  [Q] Quit",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    if let Some(error) = &picker.error {
+        lines.push(Line::from(Span::styled(
+            format!(" {}", error),
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .title(" Choose a Profile ");
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(ratatui::widgets::Wrap { trim: false });
+    frame.render_widget(paragraph, picker_area);
+}
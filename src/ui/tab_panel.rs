@@ -27,8 +27,21 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
         ])
         .split(inner);
 
-    // Tab bar
-    let titles: Vec<Line> = ActiveTab::ALL
+    let selected = ActiveTab::ALL
+        .iter()
+        .position(|&t| t == app.active_tab)
+        .unwrap_or(0);
+
+    // Tab bar, scrolled so the active tab fits on narrow terminals. Each
+    // title is padded by a space on both sides plus a divider.
+    let width_of = |tabs: &[ActiveTab]| -> usize {
+        tabs.iter().map(|t| t.label().len() + 3).sum::<usize>().saturating_sub(1)
+    };
+    let mut first = 0;
+    while first < selected && width_of(&ActiveTab::ALL[first..=selected]) > sections[0].width as usize {
+        first += 1;
+    }
+    let titles: Vec<Line> = ActiveTab::ALL[first..]
         .iter()
         .map(|t| {
             let style = if *t == app.active_tab {
@@ -42,13 +55,8 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
         })
         .collect();
 
-    let selected = ActiveTab::ALL
-        .iter()
-        .position(|&t| t == app.active_tab)
-        .unwrap_or(0);
-
     let tabs = Tabs::new(titles)
-        .select(selected)
+        .select(selected - first)
         .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
         .divider("\u{2502}");
 
@@ -68,7 +76,14 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
         ActiveTab::Upgrades => tabs::upgrades::draw(frame, app, sections[2]),
         ActiveTab::Relics => tabs::relics::draw(frame, app, sections[2]),
         ActiveTab::Inventory => tabs::inventory::draw(frame, app, sections[2]),
+        ActiveTab::Crafting => tabs::crafting::draw(frame, app, sections[2]),
+        ActiveTab::Collection => tabs::collection::draw(frame, app, sections[2]),
         ActiveTab::Stats => tabs::stats::draw(frame, app, sections[2]),
+        ActiveTab::Achievements => tabs::achievements::draw(frame, app, sections[2]),
+        ActiveTab::Bounties => tabs::bounties::draw(frame, app, sections[2]),
         ActiveTab::Rebirth => tabs::rebirth::draw(frame, app, sections[2]),
+        ActiveTab::Challenges => tabs::challenges::draw(frame, app, sections[2]),
+        ActiveTab::Transcendence => tabs::transcendence::draw(frame, app, sections[2]),
+        ActiveTab::Automation => tabs::automation::draw(frame, app, sections[2]),
     }
 }
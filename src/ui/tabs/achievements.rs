@@ -0,0 +1,131 @@
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::data::achievements::all_achievements;
+
+const BAR_WIDTH: usize = 20;
+
+pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
+    let state = app.state();
+    let unlocked = &state.achievements;
+    let achievements = all_achievements();
+
+    let mut lines = Vec::new();
+    let mut selected_line: u16 = 0;
+
+    let done = achievements.iter().filter(|a| unlocked.is_unlocked(a.id)).count();
+    let mut header = vec![
+        Span::styled(
+            " Achievements",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("  {}/{} unlocked", done, achievements.len()),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ];
+    if let Some(title) = &unlocked.title {
+        header.push(Span::styled(
+            format!("  Title: {}", title),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+    lines.push(Line::from(header));
+    lines.push(Line::from(Span::styled(
+        " Unlocks survive rebirth.",
+        Style::default().fg(Color::DarkGray),
+    )));
+    lines.push(Line::from(""));
+
+    let tab_scroll = app.tab_scroll.min(achievements.len().saturating_sub(1));
+    for (i, achievement) in achievements.iter().enumerate() {
+        let is_selected = i == tab_scroll;
+        if is_selected {
+            selected_line = lines.len() as u16;
+        }
+        let is_unlocked = unlocked.is_unlocked(achievement.id);
+
+        let marker = if is_selected { "\u{25b6}" } else { " " };
+        let (check, check_color) = if is_unlocked {
+            ("[*]", Color::Green)
+        } else {
+            ("[ ]", Color::DarkGray)
+        };
+        let name_style = if is_selected {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else if is_unlocked {
+            Style::default().fg(Color::Green)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{} ", marker), Style::default().fg(Color::Yellow)),
+            Span::styled(format!("{} ", check), Style::default().fg(check_color)),
+            Span::styled(format!("{:<24}", achievement.name), name_style),
+            Span::styled(achievement.description, Style::default().fg(Color::Gray)),
+        ]));
+
+        // Progress bar for counting goals
+        if !is_unlocked && let Some((current, target)) = achievement.progress(state) {
+            let filled = (current as f64 / target.max(1) as f64 * BAR_WIDTH as f64) as usize;
+            lines.push(Line::from(vec![
+                Span::raw("       "),
+                Span::styled("\u{2588}".repeat(filled), Style::default().fg(Color::Cyan)),
+                Span::styled(
+                    "\u{2591}".repeat(BAR_WIDTH - filled.min(BAR_WIDTH)),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(
+                    format!(" {}/{}", current, target),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]));
+        }
+
+        if is_selected {
+            let mut spans = vec![Span::raw("       \u{2514} ")];
+            if !achievement.reward.is_empty() {
+                spans.push(Span::styled(
+                    format!("Reward: {}  ", achievement.reward),
+                    Style::default().fg(if is_unlocked { Color::Cyan } else { Color::DarkGray }),
+                ));
+            }
+            if let Some(title) = achievement.title {
+                spans.push(Span::styled(
+                    format!("Title: \"{}\"", title),
+                    Style::default().fg(Color::Magenta),
+                ));
+                if is_unlocked {
+                    let shown = unlocked.title.as_deref() == Some(title);
+                    spans.push(Span::styled(
+                        if shown { "  [E] Hide title" } else { "  [E] Show title" },
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+            }
+            lines.push(Line::from(spans));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " [E] Show/hide title  [\u{2191}\u{2193}] Navigate",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let visible_height = area.height;
+    let margin = 2u16;
+    let scroll_y = if selected_line + margin >= visible_height {
+        (selected_line + margin + 1).saturating_sub(visible_height)
+    } else {
+        0
+    };
+    let paragraph = Paragraph::new(lines).scroll((scroll_y, 0));
+    frame.render_widget(paragraph, area);
+}
@@ -0,0 +1,130 @@
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::game::automation::MAX_RULES;
+
+pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
+    let automation = &app.state().automation;
+    let unlocked = app.engine.modifiers().auto_buy();
+
+    let mut lines = Vec::new();
+    let mut selected_line: u16 = 0;
+
+    if !unlocked {
+        lines.push(Line::from(Span::styled(
+            " Upgrade Automation",
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        )));
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            " Learn the Quartermaster rebirth skill to write rules",
+            Style::default().fg(Color::Gray),
+        )));
+        lines.push(Line::from(Span::styled(
+            " that buy upgrades for you.",
+            Style::default().fg(Color::Gray),
+        )));
+        if !automation.rules.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                format!(" {} saved rules are waiting.", automation.rules.len()),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        frame.render_widget(Paragraph::new(lines), area);
+        return;
+    }
+
+    // Status header
+    let (status, status_color) = if automation.enabled {
+        ("RUNNING", Color::Green)
+    } else {
+        ("PAUSED", Color::Red)
+    };
+    lines.push(Line::from(vec![
+        Span::styled(
+            " Upgrade Automation: ",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            status,
+            Style::default()
+                .fg(status_color)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("  ({}/{} rules)", automation.rules.len(), MAX_RULES),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]));
+    lines.push(Line::from(Span::styled(
+        " Rules are checked top to bottom; the first with something to buy buys it.",
+        Style::default().fg(Color::DarkGray),
+    )));
+    lines.push(Line::from(""));
+
+    if automation.rules.is_empty() {
+        lines.push(Line::from(Span::styled(
+            " No rules yet. Press [N] to add one.",
+            Style::default().fg(Color::Gray),
+        )));
+    }
+
+    let tab_scroll = app.tab_scroll.min(automation.rules.len().saturating_sub(1));
+    for (i, rule) in automation.rules.iter().enumerate() {
+        let is_selected = i == tab_scroll;
+        if is_selected {
+            selected_line = lines.len() as u16;
+        }
+        let marker = if is_selected { "\u{25b6}" } else { " " };
+        let check = if rule.enabled { "[x]" } else { "[ ]" };
+        let text_style = if is_selected {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else if rule.enabled && automation.enabled {
+            Style::default().fg(Color::White)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{} ", marker), Style::default().fg(Color::Yellow)),
+            Span::styled(format!("{:>2}. ", i + 1), Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                format!("{} ", check),
+                if rule.enabled {
+                    Style::default().fg(Color::Green)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                },
+            ),
+            Span::styled(rule.describe(), text_style),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " [N] New  [E] On/Off  [D] Delete  [A] Action  [T] Target  [+/-] Cost %",
+        Style::default().fg(Color::DarkGray),
+    )));
+    lines.push(Line::from(Span::styled(
+        " [[/]] Move  [P] Pause all  [\u{2191}\u{2193}] Navigate",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let visible_height = area.height;
+    let margin = 2u16;
+    let scroll_y = if selected_line + margin >= visible_height {
+        (selected_line + margin + 1).saturating_sub(visible_height)
+    } else {
+        0
+    };
+    let paragraph = Paragraph::new(lines).scroll((scroll_y, 0));
+    frame.render_widget(paragraph, area);
+}
@@ -0,0 +1,109 @@
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::game::bounty::REFRESH_CHESTS;
+
+const BAR_WIDTH: usize = 20;
+
+pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
+    let state = app.state();
+    let board = &state.bounties;
+
+    let mut lines = Vec::new();
+    let mut selected_line: u16 = 0;
+
+    let next_refresh = (board.refreshed_at + REFRESH_CHESTS).saturating_sub(state.stats.chests_opened);
+    lines.push(Line::from(vec![
+        Span::styled(
+            " Bounty Board",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("  New bounties in {} chests", next_refresh),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]));
+    lines.push(Line::from(Span::styled(
+        " Finished bounties stay until claimed.",
+        Style::default().fg(Color::DarkGray),
+    )));
+    lines.push(Line::from(""));
+
+    let tab_scroll = app.tab_scroll.min(board.bounties.len().saturating_sub(1));
+    for (i, bounty) in board.bounties.iter().enumerate() {
+        let is_selected = i == tab_scroll;
+        if is_selected {
+            selected_line = lines.len() as u16;
+        }
+
+        let marker = if is_selected { "\u{25b6}" } else { " " };
+        let (check, check_color) = if bounty.done {
+            ("[*]", Color::Green)
+        } else {
+            ("[ ]", Color::DarkGray)
+        };
+        let name_style = if is_selected {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else if bounty.done {
+            Style::default().fg(Color::Green)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{} ", marker), Style::default().fg(Color::Yellow)),
+            Span::styled(format!("{} ", check), Style::default().fg(check_color)),
+            Span::styled(bounty.objective.describe(), name_style),
+        ]));
+
+        let (current, target) = bounty.progress(state);
+        let filled = (current / target.max(1.0) * BAR_WIDTH as f64) as usize;
+        let count = if target > 1.0 {
+            format!(" {}/{}", current as u64, target as u64)
+        } else {
+            String::new()
+        };
+        lines.push(Line::from(vec![
+            Span::raw("       "),
+            Span::styled("\u{2588}".repeat(filled.min(BAR_WIDTH)), Style::default().fg(Color::Cyan)),
+            Span::styled(
+                "\u{2591}".repeat(BAR_WIDTH - filled.min(BAR_WIDTH)),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::styled(count, Style::default().fg(Color::DarkGray)),
+        ]));
+
+        let mut spans = vec![
+            Span::raw("       \u{2514} "),
+            Span::styled(
+                format!("Reward: {}", bounty.reward.describe()),
+                Style::default().fg(if bounty.done { Color::Cyan } else { Color::DarkGray }),
+            ),
+        ];
+        if is_selected && bounty.done {
+            spans.push(Span::styled("  [E] Claim", Style::default().fg(Color::Green)));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " [E] Claim  [\u{2191}\u{2193}] Navigate",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let visible_height = area.height;
+    let margin = 2u16;
+    let scroll_y = if selected_line + margin >= visible_height {
+        (selected_line + margin + 1).saturating_sub(visible_height)
+    } else {
+        0
+    };
+    let paragraph = Paragraph::new(lines).scroll((scroll_y, 0));
+    frame.render_widget(paragraph, area);
+}
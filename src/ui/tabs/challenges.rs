@@ -0,0 +1,145 @@
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::data::challenges::{all_challenges, get_challenge};
+
+pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
+    let state = app.state();
+    let rb = &state.rebirth;
+    let challenges = all_challenges();
+
+    let mut lines = Vec::new();
+    let mut selected_line: u16 = 0;
+
+    let done = challenges.iter().filter(|c| rb.has_completed_challenge(c.id)).count();
+    lines.push(Line::from(vec![
+        Span::styled(
+            " Challenges",
+            Style::default()
+                .fg(Color::LightRed)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("  {}/{} completed", done, challenges.len()),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]));
+    lines.push(Line::from(Span::styled(
+        " Rebirth under extra rules. Reach the goal in time for a permanent reward.",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    // Running challenge and its clock
+    match rb.challenge.as_ref().and_then(|a| get_challenge(&a.id).map(|c| (a, c))) {
+        Some((active, challenge)) => {
+            let left = active.secs_left();
+            lines.push(Line::from(vec![
+                Span::styled(" Running: ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    challenge.name,
+                    Style::default()
+                        .fg(Color::LightRed)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("  {}  {}:{:02} left", challenge.goal.describe(), left / 60, left % 60),
+                    Style::default().fg(Color::Yellow),
+                ),
+            ]));
+            let abandon = if app.abandon_confirm {
+                " [A] CONFIRM ABANDON - Press A again!"
+            } else {
+                " [A] Abandon"
+            };
+            lines.push(Line::from(Span::styled(abandon, Style::default().fg(Color::DarkGray))));
+        }
+        None => {
+            let min_level = rb.min_level_for_rebirth();
+            let (text, color) = if rb.can_rebirth(state.player.level) {
+                (" Ready: starting one rebirths you now.".to_string(), Color::Green)
+            } else {
+                (format!(" Starting one rebirths you; need level {}.", min_level), Color::DarkGray)
+            };
+            lines.push(Line::from(Span::styled(text, Style::default().fg(color))));
+        }
+    }
+    lines.push(Line::from(""));
+
+    let tab_scroll = app.tab_scroll.min(challenges.len().saturating_sub(1));
+    for (i, challenge) in challenges.iter().enumerate() {
+        let is_selected = i == tab_scroll;
+        if is_selected {
+            selected_line = lines.len() as u16;
+        }
+        let is_done = rb.has_completed_challenge(challenge.id);
+        let is_running = rb.challenge.as_ref().is_some_and(|a| a.id == challenge.id);
+
+        let marker = if is_selected { "\u{25b6}" } else { " " };
+        let (check, check_color) = if is_running {
+            ("[>]", Color::LightRed)
+        } else if is_done {
+            ("[*]", Color::Green)
+        } else {
+            ("[ ]", Color::DarkGray)
+        };
+        let name_style = if is_selected {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else if is_done {
+            Style::default().fg(Color::Green)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{} ", marker), Style::default().fg(Color::Yellow)),
+            Span::styled(format!("{} ", check), Style::default().fg(check_color)),
+            Span::styled(format!("{:<16}", challenge.name), name_style),
+            Span::styled(challenge.description, Style::default().fg(Color::Gray)),
+        ]));
+
+        if is_selected {
+            lines.push(Line::from(vec![
+                Span::raw("       \u{2514} "),
+                Span::styled(
+                    format!(
+                        "{} within {} min",
+                        challenge.goal.describe(),
+                        challenge.time_limit_secs / 60
+                    ),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled(
+                    format!("  Reward: {}", challenge.reward),
+                    Style::default().fg(if is_done { Color::Cyan } else { Color::DarkGray }),
+                ),
+            ]));
+            if app.challenge_confirm.as_deref() == Some(challenge.id) {
+                lines.push(Line::from(Span::styled(
+                    "         [E] CONFIRM - Press E again to rebirth into it!",
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                )));
+            }
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " [E] Start  [A] Abandon  [\u{2191}\u{2193}] Navigate",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let visible_height = area.height;
+    let margin = 2u16;
+    let scroll_y = if selected_line + margin >= visible_height {
+        (selected_line + margin + 1).saturating_sub(visible_height)
+    } else {
+        0
+    };
+    let paragraph = Paragraph::new(lines).scroll((scroll_y, 0));
+    frame.render_widget(paragraph, area);
+}
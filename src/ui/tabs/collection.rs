@@ -0,0 +1,109 @@
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::data::items::get_item;
+use crate::data::sets::all_item_sets;
+
+pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
+    let state = app.state();
+    let collection = &state.collection;
+    let sets = all_item_sets();
+
+    let mut lines = Vec::new();
+    let mut selected_line: u16 = 0;
+    let mut selected_end: u16 = 0;
+
+    let completed = sets.iter().filter(|s| collection.is_complete(s.id)).count();
+    lines.push(Line::from(vec![
+        Span::styled(
+            " Collection",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("  {}/{} sets complete", completed, sets.len()),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]));
+    lines.push(Line::from(Span::styled(
+        " Hold every piece of a set at once for a permanent bonus.",
+        Style::default().fg(Color::DarkGray),
+    )));
+    lines.push(Line::from(""));
+
+    let tab_scroll = app.tab_scroll.min(sets.len().saturating_sub(1));
+    for (i, set) in sets.iter().enumerate() {
+        let is_selected = i == tab_scroll;
+        if is_selected {
+            selected_line = lines.len() as u16;
+        }
+        let ids = set.item_ids();
+        let held = ids
+            .iter()
+            .filter(|id| state.inventory.items.iter().any(|item| item.id == **id))
+            .count();
+        let complete = collection.is_complete(set.id);
+
+        let marker = if is_selected { "\u{25b6}" } else { " " };
+        let name_style = if is_selected {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else if complete {
+            Style::default().fg(Color::Green)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let (status, status_color) = if complete {
+            ("COMPLETE".to_string(), Color::Green)
+        } else {
+            (format!("{}/{} held", held, ids.len()), Color::DarkGray)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{} ", marker), Style::default().fg(Color::Yellow)),
+            Span::styled(set.name, name_style),
+            Span::styled(format!("  [{}]", status), Style::default().fg(status_color)),
+            Span::styled(
+                format!("  {}", set.description),
+                Style::default().fg(if complete { Color::Cyan } else { Color::Gray }),
+            ),
+        ]));
+
+        for id in ids {
+            let is_held = state.inventory.items.iter().any(|item| item.id == id);
+            let line = match get_item(id) {
+                Some(item) if is_held || collection.has_found(id) => {
+                    let (mark, note) = if is_held {
+                        ("\u{25cf}", "")
+                    } else {
+                        ("\u{25cb}", "  (found, not held)")
+                    };
+                    Line::from(vec![
+                        Span::styled(format!("     {} ", mark), Style::default().fg(item.rarity.color())),
+                        Span::styled(item.name, Style::default().fg(item.rarity.color())),
+                        Span::styled(note, Style::default().fg(Color::DarkGray)),
+                    ])
+                }
+                _ => Line::from(Span::styled(
+                    "     \u{25cb} ???",
+                    Style::default().fg(Color::DarkGray),
+                )),
+            };
+            lines.push(line);
+        }
+        if is_selected {
+            selected_end = lines.len() as u16;
+        }
+        lines.push(Line::from(""));
+    }
+
+    // Show the whole selected set, or at least its header if it won't fit
+    let scroll_y = (selected_end + 1)
+        .saturating_sub(area.height)
+        .min(selected_line);
+    let paragraph = Paragraph::new(lines).scroll((scroll_y, 0));
+    frame.render_widget(paragraph, area);
+}
@@ -0,0 +1,97 @@
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::data::recipes::all_recipes;
+use crate::game::crafting;
+
+pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
+    let inventory = &app.state().inventory;
+    let recipes = all_recipes();
+
+    let mut lines = Vec::new();
+    let mut selected_line: u16 = 0;
+
+    lines.push(Line::from(Span::styled(
+        " Crafting",
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )));
+    lines.push(Line::from(Span::styled(
+        " Inputs are used up even if the craft fails. Luck and Alchemy raise the odds.",
+        Style::default().fg(Color::DarkGray),
+    )));
+    lines.push(Line::from(""));
+
+    let tab_scroll = app.tab_scroll.min(recipes.len().saturating_sub(1));
+    for (i, recipe) in recipes.iter().enumerate() {
+        let is_selected = i == tab_scroll;
+        if is_selected {
+            selected_line = lines.len() as u16;
+        }
+        let plan = crafting::plan(recipe, inventory);
+        let chance = app.engine.craft_chance(recipe);
+
+        let marker = if is_selected { "\u{25b6}" } else { " " };
+        let name_style = if is_selected {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else if plan.is_some() {
+            Style::default().fg(Color::White)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{} ", marker), Style::default().fg(Color::Yellow)),
+            Span::styled(recipe.name, name_style),
+            Span::styled("  \u{2192} random ", Style::default().fg(Color::DarkGray)),
+            Span::styled(recipe.output.label(), Style::default().fg(recipe.output.color())),
+            Span::styled(
+                format!("  {:.0}% success", chance * 100.0),
+                Style::default().fg(if chance >= 1.0 { Color::Green } else { Color::Cyan }),
+            ),
+        ]));
+
+        let needs: Vec<String> = recipe.inputs.iter().map(|input| input.describe()).collect();
+        lines.push(Line::from(Span::styled(
+            format!("     Needs: {}", needs.join(" + ")),
+            Style::default().fg(Color::Gray),
+        )));
+
+        match plan {
+            Some(plan) => {
+                let uses: Vec<String> = plan
+                    .iter()
+                    .map(|&(index, count)| format!("{}x {}", count, inventory.items[index].name))
+                    .collect();
+                lines.push(Line::from(vec![
+                    Span::styled("     Uses: ", Style::default().fg(Color::DarkGray)),
+                    Span::styled(uses.join(", "), Style::default().fg(Color::Green)),
+                ]));
+            }
+            None => lines.push(Line::from(Span::styled(
+                "     Not enough items",
+                Style::default().fg(Color::Red),
+            ))),
+        }
+        lines.push(Line::from(""));
+    }
+
+    lines.push(Line::from(Span::styled(
+        " [E] Craft  [\u{2191}\u{2193}] Navigate",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let visible_height = area.height;
+    let margin = 4u16;
+    let scroll_y = if selected_line + margin >= visible_height {
+        (selected_line + margin + 1).saturating_sub(visible_height)
+    } else {
+        0
+    };
+    let paragraph = Paragraph::new(lines).scroll((scroll_y, 0));
+    frame.render_widget(paragraph, area);
+}
@@ -3,11 +3,20 @@ use ratatui::widgets::Paragraph;
 use ratatui::Frame;
 
 use crate::app::App;
+use crate::data::items::get_item;
+use crate::game::inventory::SortMode;
 use crate::game::item::Rarity;
+use crate::game::loot_filter::FilterAction;
+use crate::ui::widgets::number::format_number;
 use crate::ui::widgets::rarity_label::rarity_span;
 
 pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
-    let inv = &app.state.inventory;
+    if app.filter_open {
+        draw_filter(frame, app, area);
+        return;
+    }
+
+    let inv = &app.state().inventory;
 
     if inv.items.is_empty() {
         let lines = vec![
@@ -48,142 +57,157 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
         .split(area);
 
     let mut lines = Vec::new();
-
-    let rarities = [
-        Rarity::Mythic,
-        Rarity::Legendary,
-        Rarity::Epic,
-        Rarity::Rare,
-        Rarity::Uncommon,
-        Rarity::Common,
-    ];
-
-    // Build display list with proper scrolling
-    let mut display_items: Vec<(usize, &crate::game::item::ItemInstance)> = Vec::new();
     let mut selected_line: u16 = 0;
 
-    // Show items organized by rarity (compact view)
-    for rarity in &rarities {
-        let items_of_rarity: Vec<(usize, &crate::game::item::ItemInstance)> = inv.items
-            .iter()
-            .enumerate()
-            .filter(|(_, i)| i.rarity == *rarity)
-            .collect();
+    let query = &app.inventory_query;
+    let view = app.inventory_view();
 
-        if items_of_rarity.is_empty() {
-            continue;
-        }
+    // Sort, rarity filter and search
+    let search = if app.search_typing {
+        format!("/{}_", query.search)
+    } else if query.search.is_empty() {
+        "[/] Search".to_string()
+    } else {
+        format!("/{}", query.search)
+    };
+    lines.push(Line::from(vec![
+        Span::styled(" Sort: ", Style::default().fg(Color::DarkGray)),
+        Span::styled(query.sort.label(), Style::default().fg(Color::White)),
+        Span::styled("  Show: ", Style::default().fg(Color::DarkGray)),
+        match query.rarity {
+            Some(rarity) => rarity_span(rarity),
+            None => Span::styled("All", Style::default().fg(Color::White)),
+        },
+        Span::raw("  "),
+        Span::styled(
+            search,
+            if app.search_typing {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            },
+        ),
+    ]));
+    lines.push(Line::from(""));
 
-        // Section header with rarity color bar
-        let total_count: u32 = items_of_rarity.iter().map(|(_, i)| i.count).sum();
-        let bar_char = "\u{2588}"; // full block
-        lines.push(Line::from(vec![
-            Span::styled(
-                bar_char.repeat(2),
-                Style::default().fg(rarity.color()),
-            ),
-            Span::raw(" "),
-            rarity_span(*rarity),
-            Span::styled(
-                format!(" \u{00d7}{}", total_count),
-                Style::default()
-                    .fg(Color::White)
-                    .add_modifier(Modifier::BOLD),
-            ),
-        ]));
+    if view.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "    No items match.",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
 
-        // Show individual items (compact: one line per item)
-        for (idx, item) in items_of_rarity {
-            let display_idx = display_items.len();
-            display_items.push((idx, item));
+    // Rarity headers only make sense while grouped by rarity
+    let grouped = query.sort == SortMode::Rarity;
+    let mut current_rarity: Option<Rarity> = None;
 
-            let is_selected = display_idx == app.tab_scroll;
+    for (row, &idx) in view.rows().iter().enumerate() {
+        let item = &inv.items[idx];
+        let rarity = item.rarity;
 
-            if is_selected {
-                selected_line = lines.len() as u16;
+        // Section header with rarity color bar
+        if grouped && current_rarity != Some(rarity) {
+            if current_rarity.is_some() {
+                lines.push(Line::from(""));
             }
-
-            let marker = if is_selected { "\u{25b6}" } else { " " };
-
-            let name_style = if is_selected {
-                Style::default()
-                    .fg(rarity.color())
-                    .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
-            } else {
-                Style::default().fg(rarity.color())
-            };
-
-            let crit_marker = if item.is_crit { "\u{2605}" } else { "" };
-
-            // Compact format: [marker] name [×count] [crit] | GP | XP
-            let gp_str = if item.gp_value >= 1_000_000_000_000_000_000 {
-                format!("{:.2}Qi", item.gp_value as f64 / 1_000_000_000_000_000_000.0)
-            } else if item.gp_value >= 1_000_000_000_000_000 {
-                format!("{:.2}Qa", item.gp_value as f64 / 1_000_000_000_000_000.0)
-            } else if item.gp_value >= 1_000_000_000_000 {
-                format!("{:.2}T", item.gp_value as f64 / 1_000_000_000_000.0)
-            } else if item.gp_value >= 1_000_000_000 {
-                format!("{:.2}B", item.gp_value as f64 / 1_000_000_000.0)
-            } else if item.gp_value >= 1_000_000 {
-                format!("{:.2}M", item.gp_value as f64 / 1_000_000.0)
-            } else if item.gp_value >= 1_000 {
-                format!("{:.1}K", item.gp_value as f64 / 1_000.0)
-            } else {
-                item.gp_value.to_string()
-            };
-
-            let xp_str = if item.xp_value >= 1_000_000_000_000_000_000 {
-                format!("{:.2}Qi", item.xp_value as f64 / 1_000_000_000_000_000_000.0)
-            } else if item.xp_value >= 1_000_000_000_000_000 {
-                format!("{:.2}Qa", item.xp_value as f64 / 1_000_000_000_000_000.0)
-            } else if item.xp_value >= 1_000_000_000_000 {
-                format!("{:.2}T", item.xp_value as f64 / 1_000_000_000_000.0)
-            } else if item.xp_value >= 1_000_000_000 {
-                format!("{:.2}B", item.xp_value as f64 / 1_000_000_000.0)
-            } else if item.xp_value >= 1_000_000 {
-                format!("{:.2}M", item.xp_value as f64 / 1_000_000.0)
-            } else if item.xp_value >= 1_000 {
-                format!("{:.1}K", item.xp_value as f64 / 1_000.0)
-            } else {
-                item.xp_value.to_string()
-            };
-
-            let count_str = if item.count > 1 {
-                format!(" \u{00d7}{}", item.count)
-            } else {
-                String::new()
-            };
-
-            let mut line_spans = vec![
-                Span::styled(marker, Style::default().fg(Color::Yellow)),
-                Span::raw(" "),
-                Span::styled(item.name.clone(), name_style),
-                Span::styled(count_str, Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
-                Span::styled(crit_marker, Style::default().fg(Color::Yellow)),
-                Span::raw("  "),
+            let total_count: u32 = view
+                .rows()
+                .iter()
+                .map(|&i| &inv.items[i])
+                .filter(|i| i.rarity == rarity)
+                .map(|i| i.count)
+                .sum();
+            let bar_char = "\u{2588}"; // full block
+            lines.push(Line::from(vec![
                 Span::styled(
-                    format!("{}GP", gp_str),
-                    Style::default().fg(Color::Rgb(100, 100, 60)),
+                    bar_char.repeat(2),
+                    Style::default().fg(rarity.color()),
                 ),
                 Span::raw(" "),
+                rarity_span(rarity),
                 Span::styled(
-                    format!("{}XP", xp_str),
-                    Style::default().fg(Color::Cyan),
+                    format!(" \u{00d7}{}", total_count),
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
                 ),
-            ];
+            ]));
+            current_rarity = Some(rarity);
+        }
 
-            if is_selected && app.state.skill_tree.has_skill("transmute_basics") {
-                line_spans.push(Span::raw("  "));
-                line_spans.push(Span::styled(
-                    "[S]Sell",
-                    Style::default().fg(Color::DarkGray),
-                ));
-            }
+        let is_selected = row == app.tab_scroll;
 
-            lines.push(Line::from(line_spans));
+        if is_selected {
+            selected_line = lines.len() as u16;
         }
 
-        lines.push(Line::from(""));
+        let marker = if is_selected { "\u{25b6}" } else { " " };
+
+        let name_style = if is_selected {
+            Style::default()
+                .fg(rarity.color())
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+        } else {
+            Style::default().fg(rarity.color())
+        };
+
+        let crit_marker = if item.is_crit { "\u{2605}" } else { "" };
+
+        // Compact format: [marker] name [×count] [crit] | GP | XP
+        let gp_str = format_number(item.gp_value);
+
+        let xp_str = format_number(item.xp_value);
+
+        let count_str = if item.count > 1 {
+            format!(" \u{00d7}{}", item.count)
+        } else {
+            String::new()
+        };
+
+        let mut line_spans = vec![
+            Span::styled(marker, Style::default().fg(Color::Yellow)),
+            Span::raw(" "),
+            Span::styled(item.name.clone(), name_style),
+            Span::styled(count_str, Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+            Span::styled(crit_marker, Style::default().fg(Color::Yellow)),
+            Span::raw("  "),
+            Span::styled(
+                format!("{}GP", gp_str),
+                Style::default().fg(Color::Rgb(100, 100, 60)),
+            ),
+            Span::raw(" "),
+            Span::styled(
+                format!("{}XP", xp_str),
+                Style::default().fg(Color::Cyan),
+            ),
+        ];
+
+        if let Some(action) = app.state().loot_filter.items.get(&item.id) {
+            line_spans.push(Span::raw(" "));
+            line_spans.push(Span::styled(
+                format!("[{}]", action.label()),
+                Style::default().fg(Color::Rgb(255, 165, 0)),
+            ));
+        }
+
+        if is_selected && app.engine.modifiers().can_sell() {
+            line_spans.push(Span::raw("  "));
+            line_spans.push(Span::styled(
+                "[S]Sell",
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+
+        lines.push(Line::from(line_spans));
+
+        if is_selected {
+            for affix in item.affix_defs() {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("     {}: ", affix.name), Style::default().fg(Color::Rgb(180, 140, 255))),
+                    Span::styled(affix.description, Style::default().fg(Color::Gray)),
+                ]));
+            }
+        }
     }
 
     // Calculate scroll offset for smooth scrolling
@@ -198,7 +222,7 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(paragraph, sections[0]);
 
     // Total at bottom with sell hints
-    let can_sell = app.state.skill_tree.has_skill("transmute_basics");
+    let can_sell = app.engine.modifiers().can_sell();
 
     let total_lines = if can_sell {
         vec![
@@ -209,7 +233,7 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
                 ),
                 Span::raw("  "),
                 Span::styled(
-                    "[\u{2191}\u{2193}] Navigate  [S] Sell Selected  [A] Sell All",
+                    "[S] Sell  [A] Sell All  [F] Filter  [I] Item Rule  [O] Sort  [R] Rarity  [/] Search",
                     Style::default().fg(Color::DarkGray),
                 ),
             ]),
@@ -223,7 +247,7 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
                 ),
                 Span::raw("  "),
                 Span::styled(
-                    "[\u{2191}\u{2193}] Navigate  (Learn Transmute Basics to sell items)",
+                    "[O] Sort  [R] Rarity  [/] Search  (Learn Transmute Basics to sell items)",
                     Style::default().fg(Color::DarkGray),
                 ),
             ]),
@@ -233,3 +257,116 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(Paragraph::new(total_lines), sections[1]);
 }
 
+
+/// The loot filter editor: one row per rarity, the keep threshold, then the
+/// per-item rules. Row order matches `App::handle_filter_input`.
+fn draw_filter(frame: &mut Frame, app: &App, area: Rect) {
+    let filter = &app.state().loot_filter;
+    let unlocked = app.engine.modifiers().loot_filter();
+    let item_rules = filter.item_rules();
+    let threshold_row = Rarity::ALL.len();
+    let selected = app.filter_selected.min(threshold_row + item_rules.len());
+    let alchemy = Color::Rgb(255, 165, 0);
+
+    let mut lines = Vec::new();
+    let mut selected_line: u16 = 0;
+
+    let (status, status_color) = match (unlocked, filter.enabled) {
+        (false, _) => ("LOCKED", Color::DarkGray),
+        (true, true) => ("ON", Color::Green),
+        (true, false) => ("OFF", Color::Red),
+    };
+    lines.push(Line::from(vec![
+        Span::styled(
+            " Loot Filter: ",
+            Style::default().fg(alchemy).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            status,
+            Style::default().fg(status_color).add_modifier(Modifier::BOLD),
+        ),
+    ]));
+    if !unlocked {
+        lines.push(Line::from(Span::styled(
+            " Learn Alchemical Sieve (Alchemy) to filter new drops.",
+            Style::default().fg(Color::Gray),
+        )));
+    }
+    lines.push(Line::from(Span::styled(
+        " Item rules win, then the keep threshold, then rarity.",
+        Style::default().fg(Color::DarkGray),
+    )));
+    lines.push(Line::from(""));
+
+    let action_style = |action: FilterAction| match action {
+        FilterAction::Keep => Style::default().fg(Color::Green),
+        FilterAction::Sell => Style::default().fg(Color::Yellow),
+        FilterAction::Salvage => Style::default().fg(Color::Cyan),
+    };
+    let marker = |row: usize| if row == selected { "\u{25b6} " } else { "  " };
+
+    // By rarity
+    for (row, &rarity) in Rarity::ALL.iter().enumerate() {
+        if row == selected {
+            selected_line = lines.len() as u16;
+        }
+        let action = filter.rarity_action(rarity);
+        lines.push(Line::from(vec![
+            Span::styled(marker(row), Style::default().fg(Color::Yellow)),
+            Span::styled(format!("{:<12}", rarity.label()), Style::default().fg(rarity.color())),
+            Span::styled(action.label(), action_style(action)),
+        ]));
+    }
+
+    // Keep threshold
+    lines.push(Line::from(""));
+    if selected == threshold_row {
+        selected_line = lines.len() as u16;
+    }
+    let threshold = if filter.keep_above_gp.is_zero() {
+        "off".to_string()
+    } else {
+        format!("{} GP", format_number(filter.keep_above_gp))
+    };
+    lines.push(Line::from(vec![
+        Span::styled(marker(threshold_row), Style::default().fg(Color::Yellow)),
+        Span::styled("Always keep drops worth at least ", Style::default().fg(Color::Gray)),
+        Span::styled(threshold, Style::default().fg(Color::Rgb(100, 100, 60))),
+    ]));
+
+    // Per-item rules
+    lines.push(Line::from(""));
+    if item_rules.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No item rules. Press [I] on an item in the inventory to add one.",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    for (i, (id, action)) in item_rules.iter().enumerate() {
+        let row = threshold_row + 1 + i;
+        if row == selected {
+            selected_line = lines.len() as u16;
+        }
+        let (name, color) = get_item(id).map_or((*id, Color::Gray), |def| (def.name, def.rarity.color()));
+        lines.push(Line::from(vec![
+            Span::styled(marker(row), Style::default().fg(Color::Yellow)),
+            Span::styled(format!("{:<24}", name), Style::default().fg(color)),
+            Span::styled(action.label(), action_style(*action)),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " [E] Change  [+/-] Threshold  [D] Delete item rule  [P] On/Off  [F] Close",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let visible_height = area.height;
+    let margin = 2u16;
+    let scroll_y = if selected_line + margin >= visible_height {
+        (selected_line + margin + 1).saturating_sub(visible_height)
+    } else {
+        0
+    };
+    frame.render_widget(Paragraph::new(lines).scroll((scroll_y, 0)), area);
+}
@@ -1,6 +1,13 @@
+pub mod achievements;
+pub mod automation;
+pub mod bounties;
+pub mod challenges;
+pub mod collection;
+pub mod crafting;
 pub mod inventory;
 pub mod rebirth;
 pub mod relics;
 pub mod skills;
 pub mod stats;
+pub mod transcendence;
 pub mod upgrades;
@@ -4,10 +4,11 @@ use ratatui::Frame;
 
 use crate::app::App;
 use crate::data::rebirth_skills::all_rebirth_skills;
+use crate::ui::widgets::number::format_number;
 
 pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
-    let rb = &app.state.rebirth;
-    let player = &app.state.player;
+    let rb = &app.state().rebirth;
+    let player = &app.state().player;
 
     let mut lines = Vec::new();
 
@@ -43,7 +44,7 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
     lines.push(Line::from(vec![
         Span::styled("  Essence:        ", Style::default().fg(Color::Gray)),
         Span::styled(
-            format!("{}", rb.essence),
+            format_number(rb.essence),
             Style::default()
                 .fg(Color::Rgb(200, 150, 255))
                 .add_modifier(Modifier::BOLD),
@@ -72,11 +73,11 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
     ]));
 
     // Estimated essence reward
-    let est_essence = rb.calculate_essence_reward(player.gp);
+    let est_essence = app.engine.essence_reward();
     lines.push(Line::from(vec![
         Span::styled("  Est. Reward:    ", Style::default().fg(Color::Gray)),
         Span::styled(
-            format!("{} Essence (from current GP)", est_essence),
+            format!("{} Essence (from current GP)", format_number(est_essence)),
             Style::default()
                 .fg(Color::Rgb(200, 150, 255))
                 .add_modifier(Modifier::BOLD),
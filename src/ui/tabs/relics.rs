@@ -1,12 +1,13 @@
 use ratatui::prelude::*;
-use ratatui::widgets::Paragraph;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 use ratatui::Frame;
 
 use crate::app::App;
-use crate::data::relics::get_relic;
+use crate::data::relics::{fusion_inputs, get_relic};
+use crate::game::relic::{MAX_RELIC_LEVEL, RelicState, level_mult, level_up_cost, shards_spent};
 
 pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
-    let owned = &app.state.relics.owned;
+    let owned = &app.state().relics.owned;
 
     if owned.is_empty() {
         let lines = vec![
@@ -37,6 +38,14 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
                 "  \u{2022} Equip up to 5 at once",
                 Style::default().fg(Color::Rgb(100, 100, 60)),
             )),
+            Line::from(Span::styled(
+                "  \u{2022} Duplicates become shards for leveling",
+                Style::default().fg(Color::Rgb(100, 100, 60)),
+            )),
+            Line::from(Span::styled(
+                "  \u{2022} Fuse spare relics into a higher tier",
+                Style::default().fg(Color::Rgb(100, 100, 60)),
+            )),
             Line::from(""),
             Line::from(Span::styled(
                 "  Keep opening chests!",
@@ -51,19 +60,11 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
     // Split: relic list + footer
     let sections = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Min(1), Constraint::Length(3)])
+        .constraints([Constraint::Min(1), Constraint::Length(4)])
         .split(area);
 
     // Organize relics by rarity
     use crate::game::item::Rarity;
-    let rarities_order = [
-        Rarity::Mythic,
-        Rarity::Legendary,
-        Rarity::Epic,
-        Rarity::Rare,
-        Rarity::Uncommon,
-    ];
-
     let mut organized_relics: Vec<(usize, String, Rarity)> = Vec::new();
     for (original_idx, relic_id) in owned.iter().enumerate() {
         if let Some(relic_def) = get_relic(relic_id) {
@@ -71,9 +72,9 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
         }
     }
 
-    // Sort by rarity tier (Mythic first)
+    // Sort by rarity tier, highest first
     organized_relics.sort_by_key(|(_, _, rarity)| {
-        rarities_order.iter().position(|r| r == rarity).unwrap_or(99)
+        Rarity::ALL.iter().rev().position(|r| r == rarity).unwrap_or(usize::MAX)
     });
 
     let mut lines = Vec::new();
@@ -112,7 +113,7 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
         display_idx_to_original.push(*original_idx);
 
         let is_selected = display_idx == app.tab_scroll;
-        let is_equipped = app.state.relics.is_equipped(relic_id);
+        let is_equipped = app.state().relics.is_equipped(relic_id);
 
         // Track which line the selected relic is on
         if is_selected {
@@ -145,6 +146,14 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
                     },
                 ),
                 Span::styled(relic_def.name, name_style),
+                Span::styled(
+                    format!(" Lv.{}", app.state().relics.level(relic_id)),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(
+                    if app.fusion_picks.contains(relic_id) { "  [FUSE]" } else { "" },
+                    Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD),
+                ),
             ]));
 
             // Always show effect
@@ -157,6 +166,32 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
             ]));
 
             if is_selected {
+                let relics = &app.state().relics;
+                let level = relics.level(relic_id);
+                let level_text = match level_up_cost(level) {
+                    Some(cost) => format!(
+                        "     Level {}/{} (x{:.1} effect) \u{2014} [L] Level up: {} {} shards (have {})",
+                        level,
+                        MAX_RELIC_LEVEL,
+                        level_mult(level),
+                        cost,
+                        relic_def.rarity.label(),
+                        relics.shards(relic_def.rarity)
+                    ),
+                    None => format!(
+                        "     Level {}/{} (x{:.1} effect) \u{2014} Max level",
+                        level,
+                        MAX_RELIC_LEVEL,
+                        level_mult(level)
+                    ),
+                };
+                let affordable = level_up_cost(level)
+                    .is_some_and(|cost| relics.shards(relic_def.rarity) >= cost);
+                lines.push(Line::from(Span::styled(
+                    level_text,
+                    Style::default().fg(if affordable { Color::Cyan } else { Color::DarkGray }),
+                )));
+
                 let status = if is_equipped {
                     "Equipped \u{2014} Press [E] to unequip"
                 } else {
@@ -189,7 +224,7 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(paragraph, sections[0]);
 
     // Footer with equip info
-    let equipped_count = app.state.relics.equipped.len();
+    let equipped_count = app.state().relics.equipped.len();
     let max = app.max_equipped_relics();
     let slot_bar: String = (0..max)
         .map(|i| if i < equipped_count { '\u{25c6}' } else { '\u{25c7}' })
@@ -206,10 +241,132 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
                 Style::default().fg(Color::DarkGray),
             ),
         ]),
+        shard_line(app),
+        loadout_line(app),
         Line::from(Span::styled(
-            " [E] Equip/Unequip  [U] Unequip All  [\u{2191}\u{2193}] Navigate",
+            " [E] Equip/Unequip  [L] Level Up  [F] Fuse  [U] Unequip All  [\u{2191}\u{2193}] Navigate",
             Style::default().fg(Color::DarkGray),
         )),
     ];
     frame.render_widget(Paragraph::new(footer_lines), sections[1]);
+
+    if app.fusion_confirm {
+        draw_fusion_overlay(frame, app, area);
+    }
+}
+
+/// Asks before fusing the marked relics, since they're gone for good.
+fn draw_fusion_overlay(frame: &mut Frame, app: &App, area: Rect) {
+    let relics = &app.state().relics;
+    let picks: Vec<_> = app.fusion_picks.iter().filter_map(|id| get_relic(id)).collect();
+    let Some(rarity) = picks.first().map(|r| r.rarity) else {
+        return;
+    };
+
+    let overlay_width = 52.min(area.width.saturating_sub(4));
+    let overlay_height = (9 + picks.len() as u16).min(area.height.saturating_sub(2));
+    let x = area.x + (area.width.saturating_sub(overlay_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(overlay_height)) / 2;
+    let overlay_area = Rect::new(x, y, overlay_width, overlay_height);
+    frame.render_widget(Clear, overlay_area);
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!(" Fuse these {} relics?", fusion_inputs(rarity).unwrap_or(picks.len())),
+            Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+        )),
+    ];
+    for relic in &picks {
+        let mut spans = vec![
+            Span::raw("   "),
+            Span::styled(relic.name, Style::default().fg(relic.rarity.color())),
+            Span::styled(
+                format!(" Lv.{}", relics.level(relic.id)),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ];
+        if relics.is_equipped(relic.id) {
+            spans.push(Span::styled(
+                " (will be unequipped)",
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+    let next = rarity.next_tier();
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::raw(" Result: a random "),
+        Span::styled(next.label(), Style::default().fg(next.color()).add_modifier(Modifier::BOLD)),
+        Span::raw(" relic"),
+    ]));
+    let refund: u32 = picks.iter().map(|r| shards_spent(relics.level(r.id))).sum();
+    if refund > 0 {
+        lines.push(Line::from(Span::styled(
+            format!(" Refunds {} {} shards from levels", refund, rarity.label()),
+            Style::default().fg(Color::Cyan),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(" [Y] Fuse", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+        Span::raw("   "),
+        Span::styled("[N] Cancel", Style::default().fg(Color::DarkGray)),
+    ]));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Magenta))
+        .title(" Relic Fusion ");
+    frame.render_widget(Paragraph::new(lines).block(block), overlay_area);
+}
+
+/// Saved loadouts by hotkey, or the name being typed for one.
+fn loadout_line(app: &App) -> Line<'static> {
+    if let Some((slot, name)) = &app.loadout_naming {
+        return Line::from(vec![
+            Span::styled(format!(" Name loadout {}: ", slot + 1), Style::default().fg(Color::Gray)),
+            Span::styled(format!("{}_", name), Style::default().fg(Color::White)),
+            Span::styled("  [Enter] Save  [Esc] Cancel", Style::default().fg(Color::DarkGray)),
+        ]);
+    }
+
+    let relics = &app.state().relics;
+    let mut spans = vec![Span::styled(" Loadouts:", Style::default().fg(Color::Gray))];
+    for slot in 0..RelicState::MAX_LOADOUTS {
+        let (text, style) = match relics.loadout(slot) {
+            Some(loadout) if loadout.matches(&relics.equipped) => (
+                format!(" [{}] {}", slot + 1, loadout.name),
+                Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+            ),
+            Some(loadout) => (
+                format!(" [{}] {}", slot + 1, loadout.name),
+                Style::default().fg(Color::White),
+            ),
+            None => (format!(" [{}] -", slot + 1), Style::default().fg(Color::DarkGray)),
+        };
+        spans.push(Span::styled(text, style));
+    }
+    spans.push(if app.loadout_pick {
+        Span::styled("  Pick a slot to save into", Style::default().fg(Color::Yellow))
+    } else {
+        Span::styled("  [S] Save", Style::default().fg(Color::DarkGray))
+    });
+    Line::from(spans)
+}
+
+/// Shard counts for each relic rarity.
+fn shard_line(app: &App) -> Line<'static> {
+    use crate::game::item::Rarity;
+
+    let mut spans = vec![Span::styled(" Shards:", Style::default().fg(Color::Gray))];
+    // Relics start at Uncommon, so there are never Common shards
+    for rarity in Rarity::ALL[1..].iter().copied() {
+        spans.push(Span::styled(
+            format!(" {}", app.state().relics.shards(rarity)),
+            Style::default().fg(rarity.color()),
+        ));
+    }
+    Line::from(spans)
 }
@@ -4,10 +4,13 @@ use ratatui::Frame;
 
 use crate::app::App;
 use crate::data::skills::{SkillBranch, all_skills, get_skill};
+use crate::game::skill_tree::respec_cost;
 
 pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
     let skills = all_skills();
-    let tree = &app.state.skill_tree;
+    let tree = &app.state().skill_tree;
+    let level = app.state().player.level;
+    let cost_mult = app.engine.modifiers().skill_cost_mult();
 
     // Clamp scroll to valid range
     let tab_scroll = app.tab_scroll.min(skills.len().saturating_sub(1));
@@ -31,9 +34,7 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
     lines.push(Line::from(""));
 
     let mut current_branch: Option<SkillBranch> = None;
-    let mut skill_index = 0usize;
-
-    for skill in skills {
+    for (skill_index, skill) in skills.iter().enumerate() {
         // Branch header
         if current_branch != Some(skill.branch) {
             if current_branch.is_some() {
@@ -57,7 +58,7 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
         }
 
         let is_learned = tree.has_skill(skill.id);
-        let can_learn = tree.can_learn(skill.id);
+        let can_learn = tree.can_learn(skill.id, cost_mult);
         let prereqs_met = skill.prerequisites.iter().all(|p| tree.has_skill(p));
         let is_selected = skill_index == tab_scroll;
 
@@ -88,15 +89,12 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
 
         let selector = if is_selected { "\u{25b6}" } else { " " };
 
-        let cost_str = if skill.cost > 1 {
-            format!(" ({}pt)", skill.cost)
-        } else {
-            " (1pt)".to_string()
-        };
+        let cost = skill.cost * cost_mult;
+        let cost_str = format!(" ({}pt)", cost);
 
         let cost_color = if is_learned {
             Color::DarkGray
-        } else if tree.skill_points >= skill.cost {
+        } else if tree.skill_points >= cost {
             Color::Cyan
         } else {
             Color::Red
@@ -139,12 +137,40 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
             }
         }
 
-        skill_index += 1;
+        // Show how to unlearn it if learned and selected
+        if is_selected && is_learned {
+            let text = if tree.is_leaf(skill.id) {
+                format!(
+                    "\u{2514} [X] Unlearn for {} GP (+{}pt)",
+                    respec_cost(tree.respecs, level, false),
+                    skill.cost
+                )
+            } else {
+                let needed_by: Vec<&str> = skills
+                    .iter()
+                    .filter(|s| tree.has_skill(s.id) && s.prerequisites.contains(&skill.id))
+                    .map(|s| s.name)
+                    .collect();
+                format!("\u{2514} Needed by: {}", needed_by.join(", "))
+            };
+            lines.push(Line::from(vec![
+                Span::raw("       "),
+                Span::styled(
+                    text,
+                    Style::default()
+                        .fg(Color::DarkGray)
+                        .add_modifier(Modifier::ITALIC),
+                ),
+            ]));
+        }
     }
 
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
-        " [E] Learn selected  [\u{2191}\u{2193}] Navigate",
+        format!(
+            " [E] Learn selected  [X] Unlearn  [Z] Reset tree ({} GP)  [\u{2191}\u{2193}] Navigate",
+            respec_cost(tree.respecs, level, true)
+        ),
         Style::default().fg(Color::DarkGray),
     )));
 
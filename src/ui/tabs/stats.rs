@@ -3,10 +3,11 @@ use ratatui::widgets::Paragraph;
 use ratatui::Frame;
 
 use crate::app::App;
+use crate::ui::widgets::number::format_number;
 
 pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
-    let stats = &app.state.stats;
-    let player = &app.state.player;
+    let stats = &app.state().stats;
+    let player = &app.state().player;
 
     let mut lines = vec![
         Line::from(vec![
@@ -29,16 +30,17 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
     ];
 
     let stat_entries = [
-        ("Chests Opened", format_num(stats.chests_opened), Color::White),
-        ("Items Found", format_num(stats.items_found), Color::White),
-        ("Total GP Earned", format_num(stats.total_gp_earned), Color::Yellow),
-        ("Total XP Earned", format_num(stats.total_xp_earned), Color::Cyan),
-        ("Mythics", format_num(stats.mythics_found), Color::Rgb(255, 50, 50)),
-        ("Legendaries", format_num(stats.legendaries_found), Color::Yellow),
-        ("Epics", format_num(stats.epics_found), Color::Magenta),
-        ("Rares", format_num(stats.rares_found), Color::Blue),
-        ("Critical Hits", format_num(stats.crits_rolled), Color::Red),
-        ("Best Single Drop", format!("{} GP", format_num(stats.highest_single_gp)), Color::Yellow),
+        ("Chests Opened", format_number(stats.chests_opened), Color::White),
+        ("Items Found", format_number(stats.items_found), Color::White),
+        ("Total GP Earned", format_number(stats.total_gp_earned), Color::Yellow),
+        ("Total XP Earned", format_number(stats.total_xp_earned), Color::Cyan),
+        ("Divines", format_number(stats.divines_found), Color::Rgb(255, 245, 170)),
+        ("Mythics", format_number(stats.mythics_found), Color::Rgb(255, 50, 50)),
+        ("Legendaries", format_number(stats.legendaries_found), Color::Yellow),
+        ("Epics", format_number(stats.epics_found), Color::Magenta),
+        ("Rares", format_number(stats.rares_found), Color::Blue),
+        ("Critical Hits", format_number(stats.crits_rolled), Color::Red),
+        ("Best Single Drop", format!("{} GP", format_number(stats.highest_single_gp)), Color::Yellow),
     ];
 
     for (label, value, value_color) in &stat_entries {
@@ -57,7 +59,7 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
     }
 
     // Rebirth info
-    if app.state.rebirth.rebirth_count > 0 || app.state.rebirth.total_essence_earned > 0 {
+    if app.state().rebirth.rebirth_count > 0 || app.state().rebirth.total_essence_earned > 0 {
         lines.push(Line::from(""));
         lines.push(Line::from(vec![
             Span::styled(
@@ -78,10 +80,10 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
         lines.push(Line::from(""));
 
         let rebirth_entries = [
-            ("Rebirth Count", format_num(app.state.rebirth.rebirth_count as u64), Color::Rgb(150, 100, 255)),
-            ("Total Essence", format_num(app.state.rebirth.total_essence_earned), Color::Rgb(200, 150, 255)),
-            ("Highest Level", format_num(app.state.rebirth.highest_level_ever as u64), Color::Cyan),
-            ("Rebirth Skills", format_num(app.state.rebirth.rebirth_skills.len() as u64), Color::Green),
+            ("Rebirth Count", format_number(app.state().rebirth.rebirth_count as u64), Color::Rgb(150, 100, 255)),
+            ("Total Essence", format_number(app.state().rebirth.total_essence_earned), Color::Rgb(200, 150, 255)),
+            ("Highest Level", format_number(app.state().rebirth.highest_level_ever as u64), Color::Cyan),
+            ("Rebirth Skills", format_number(app.state().rebirth.rebirth_skills.len() as u64), Color::Green),
         ];
 
         for (label, value, value_color) in &rebirth_entries {
@@ -164,13 +166,3 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
     let paragraph = Paragraph::new(lines).scroll((scroll, 0));
     frame.render_widget(paragraph, area);
 }
-
-fn format_num(n: u64) -> String {
-    if n >= 1_000_000 {
-        format!("{:.1}M", n as f64 / 1_000_000.0)
-    } else if n >= 1_000 {
-        format!("{:.1}K", n as f64 / 1_000.0)
-    } else {
-        n.to_string()
-    }
-}
@@ -0,0 +1,218 @@
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::data::transcendence_skills::{all_transcendence_skills, get_transcendence_skill};
+use crate::game::transcendence::MIN_ESSENCE;
+use crate::ui::widgets::number::format_number;
+
+const AETHER_COLOR: Color = Color::Rgb(120, 220, 255);
+
+pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
+    let state = app.state();
+    let tr = &state.transcendence;
+    let rb = &state.rebirth;
+
+    let mut lines = Vec::new();
+
+    // === Transcendence Status Section ===
+    lines.push(Line::from(vec![
+        Span::styled("\u{2500}\u{2500} ", Style::default().fg(AETHER_COLOR)),
+        Span::styled(
+            "Transcendence",
+            Style::default()
+                .fg(AETHER_COLOR)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            " \u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}",
+            Style::default().fg(AETHER_COLOR),
+        ),
+    ]));
+    lines.push(Line::from(Span::styled(
+        "  Resets rebirth skills and essence for Aether.",
+        Style::default().fg(Color::DarkGray),
+    )));
+    lines.push(Line::from(""));
+
+    lines.push(Line::from(vec![
+        Span::styled("  Transcended:    ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            format!("{}", tr.count),
+            Style::default()
+                .fg(AETHER_COLOR)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("  Aether:         ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            format_number(tr.aether),
+            Style::default()
+                .fg(AETHER_COLOR)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("  Essence Earned: ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            format!("{} / {}", format_number(rb.total_essence_earned), MIN_ESSENCE),
+            if tr.can_transcend(rb.total_essence_earned) {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default().fg(Color::Red)
+            },
+        ),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("  Best Level:     ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            format!("{}", rb.highest_level_ever.max(state.player.level)),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("  Est. Reward:    ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            format!("{} Aether", format_number(app.engine.aether_reward())),
+            Style::default()
+                .fg(AETHER_COLOR)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]));
+    lines.push(Line::from(""));
+
+    if tr.can_transcend(rb.total_essence_earned) {
+        if app.transcend_confirm {
+            lines.push(Line::from(Span::styled(
+                "  [T] CONFIRM TRANSCENDENCE - Press T again!",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )));
+        } else {
+            lines.push(Line::from(Span::styled(
+                "  [T] Transcend",
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            )));
+        }
+    } else {
+        lines.push(Line::from(Span::styled(
+            format!("  [T] Transcend (need {} total Essence)", MIN_ESSENCE),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    lines.push(Line::from(""));
+
+    // === Transcendence Skills Section ===
+    lines.push(Line::from(vec![
+        Span::styled("\u{2500}\u{2500} ", Style::default().fg(AETHER_COLOR)),
+        Span::styled(
+            "Aether Skills",
+            Style::default()
+                .fg(AETHER_COLOR)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            " \u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}",
+            Style::default().fg(AETHER_COLOR),
+        ),
+    ]));
+    lines.push(Line::from(""));
+
+    let skills = all_transcendence_skills();
+    let tab_scroll = app.tab_scroll.min(skills.len().saturating_sub(1));
+    let mut selected_line: u16 = 0;
+
+    for (i, skill) in skills.iter().enumerate() {
+        let is_learned = tr.has_skill(skill.id);
+        let can_learn = tr.can_learn(skill.id);
+        let is_selected = i == tab_scroll;
+        if is_selected {
+            selected_line = lines.len() as u16;
+        }
+
+        let (marker, marker_color) = if is_learned {
+            ("[*]", Color::Green)
+        } else if can_learn {
+            ("[ ]", Color::Yellow)
+        } else {
+            ("[x]", Color::DarkGray)
+        };
+
+        let name_style = if is_selected {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else if is_learned {
+            Style::default().fg(Color::Green)
+        } else if can_learn {
+            Style::default().fg(Color::White)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+
+        let (cost_str, cost_color) = if is_learned {
+            ("Learned".to_string(), Color::Green)
+        } else if tr.aether >= skill.aether_cost {
+            (format!("{} Aether", skill.aether_cost), AETHER_COLOR)
+        } else {
+            (format!("{} Aether", skill.aether_cost), Color::Red)
+        };
+
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", marker), Style::default().fg(marker_color)),
+            Span::styled(format!("{:<22}", skill.name), name_style),
+            Span::styled(cost_str, Style::default().fg(cost_color)),
+        ]));
+
+        if is_selected {
+            lines.push(Line::from(vec![
+                Span::raw("      "),
+                Span::styled(
+                    skill.description,
+                    Style::default()
+                        .fg(Color::Gray)
+                        .add_modifier(Modifier::ITALIC),
+                ),
+            ]));
+
+            let missing: Vec<&str> = skill
+                .prerequisites
+                .iter()
+                .filter(|pre| !tr.has_skill(pre))
+                .map(|pre| get_transcendence_skill(pre).map_or(*pre, |s| s.name))
+                .collect();
+            if !is_learned && !missing.is_empty() {
+                lines.push(Line::from(vec![
+                    Span::raw("      "),
+                    Span::styled(
+                        format!("Requires: {}", missing.join(", ")),
+                        Style::default()
+                            .fg(Color::Red)
+                            .add_modifier(Modifier::ITALIC),
+                    ),
+                ]));
+            }
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " [E] Buy skill  [T] Transcend  [\u{2191}\u{2193}] Navigate",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let visible_height = area.height;
+    let margin = 2u16;
+    let scroll_y = if selected_line + margin >= visible_height {
+        (selected_line + margin + 1).saturating_sub(visible_height)
+    } else {
+        0
+    };
+    let paragraph = Paragraph::new(lines).scroll((scroll_y, 0));
+    frame.render_widget(paragraph, area);
+}
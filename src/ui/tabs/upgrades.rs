@@ -4,6 +4,7 @@ use ratatui::Frame;
 
 use crate::app::App;
 use crate::data::upgrades::{UpgradeCategory, all_upgrades};
+use crate::ui::widgets::number::format_number;
 
 pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
     let upgrades = all_upgrades();
@@ -52,7 +53,7 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
             current_category = Some(upg.category);
         }
 
-        let level = app.state.upgrades.get_level(upg.id);
+        let level = app.state().upgrades.get_level(upg.id);
         let maxed = level >= upg.max_level;
         let cost = if maxed {
             "MAX".to_string()
@@ -64,7 +65,7 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
         if is_selected {
             selected_line = lines.len() as u16;
         }
-        let can_afford = !maxed && app.state.player.gp >= upg.cost_at_level(level);
+        let can_afford = !maxed && app.state().player.gp >= upg.cost_at_level(level);
 
         let marker = if is_selected { "\u{25b6}" } else { " " };
         let name_style = if is_selected {
@@ -145,7 +146,7 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(paragraph, sections[0]);
 
     // GP bar at bottom
-    let gp = app.state.player.gp;
+    let gp = app.state().player.gp;
     let gp_label = format!(" GP: {} ", format_number(gp));
     let gauge = Gauge::default()
         .gauge_style(Style::default().fg(Color::Yellow).bg(Color::Rgb(40, 40, 20)))
@@ -153,21 +154,3 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
         .label(gp_label);
     frame.render_widget(gauge, sections[1]);
 }
-
-fn format_number(n: u64) -> String {
-    if n >= 1_000_000_000_000_000_000 {
-        format!("{:.2}Qi", n as f64 / 1_000_000_000_000_000_000.0)
-    } else if n >= 1_000_000_000_000_000 {
-        format!("{:.2}Qa", n as f64 / 1_000_000_000_000_000.0)
-    } else if n >= 1_000_000_000_000 {
-        format!("{:.2}T", n as f64 / 1_000_000_000_000.0)
-    } else if n >= 1_000_000_000 {
-        format!("{:.2}B", n as f64 / 1_000_000_000.0)
-    } else if n >= 1_000_000 {
-        format!("{:.2}M", n as f64 / 1_000_000.0)
-    } else if n >= 1_000 {
-        format!("{:.1}K", n as f64 / 1_000.0)
-    } else {
-        n.to_string()
-    }
-}
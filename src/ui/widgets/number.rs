@@ -0,0 +1,60 @@
+use crate::game::bignum::BigNum;
+
+/// Suffixes for each power of 1000 after the first.
+const SUFFIXES: [&str; 11] = ["K", "M", "B", "T", "Qa", "Qi", "Sx", "Sp", "Oc", "No", "Dc"];
+
+/// Short form of a GP, XP or essence amount: `950`, `12.3K`, `4.56Qa`, and
+/// scientific notation (`1.23e45`) past the last suffix.
+pub fn format_number(n: impl Into<BigNum>) -> String {
+    let n = n.into();
+    let (mantissa, exponent) = n.scientific();
+    if exponent < 3 {
+        return n.to_string();
+    }
+    let mut group = (exponent / 3) as usize;
+    let mut scaled = mantissa * 10f64.powi((exponent % 3) as i32);
+    // Round first, so 999.96K reads 1.00M rather than 1000.0K
+    let precision = 10f64.powi(decimals(group) as i32);
+    if (scaled * precision).round() >= 1000.0 * precision {
+        scaled /= 1000.0;
+        group += 1;
+    }
+    match SUFFIXES.get(group - 1) {
+        Some(suffix) => format!("{:.*}{}", decimals(group), scaled, suffix),
+        None => n.to_string(),
+    }
+}
+
+/// Thousands get one decimal place, larger suffixes two.
+fn decimals(group: usize) -> usize {
+    if group == 1 { 1 } else { 2 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_number;
+    use crate::game::bignum::BigNum;
+
+    #[test]
+    fn formats_with_suffixes_then_scientific() {
+        let cases = [
+            (BigNum::ZERO, "0"),
+            (BigNum::from(950u64), "950"),
+            (BigNum::from(1_000u64), "1.0K"),
+            (BigNum::from(12_300u64), "12.3K"),
+            (BigNum::from(999_940u64), "999.9K"),
+            (BigNum::from(999_960u64), "1.00M"),
+            (BigNum::from(4_560_000u64), "4.56M"),
+            (BigNum::from(999_994_000u64), "999.99M"),
+            (BigNum::from(999_996_000u64), "1.00B"),
+            (BigNum::from_parts(1.23, 15), "1.23Qa"),
+            (BigNum::from_parts(4.56, 33), "4.56Dc"),
+            (BigNum::from_parts(9.99996, 35), "1.00e36"),
+            (BigNum::from_parts(1.23, 45), "1.23e45"),
+            (BigNum::from_parts(9.999, 45), "1.00e46"),
+        ];
+        for (n, expected) in cases {
+            assert_eq!(format_number(n), expected, "{n:?}");
+        }
+    }
+}
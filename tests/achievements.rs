@@ -0,0 +1,63 @@
+mod common;
+
+use lootbox_game::game::events::{Command, GameEvent, Rejection};
+
+fn unlocked(events: &[GameEvent]) -> Vec<&str> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            GameEvent::AchievementUnlocked { id } => Some(id.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn an_achievement_unlocks_on_its_threshold_and_only_once() {
+    let mut game = common::engine(7);
+    game.state.stats.chests_opened = 98;
+    let gp_mult = game.state.player.gp_multiplier;
+
+    let (_, events) = game.open_chest_now();
+    assert!(!unlocked(&events).contains(&"first_steps"));
+    assert_eq!(game.state.stats.chests_opened, 99);
+
+    let (_, events) = game.open_chest_now();
+    assert!(unlocked(&events).contains(&"first_steps"));
+    assert!(game.state.achievements.is_unlocked("first_steps"));
+    assert!(game.state.player.gp_multiplier > gp_mult);
+
+    let (_, events) = game.open_chest_now();
+    assert!(!unlocked(&events).contains(&"first_steps"));
+}
+
+#[test]
+fn achievements_outlast_a_rebirth() {
+    let mut game = common::engine(7);
+    game.state.stats.chests_opened = 99;
+    game.open_chest_now();
+    game.state.player.level = game.state.rebirth.min_level_for_rebirth();
+    game.apply(Command::Rebirth);
+    assert!(game.state.achievements.is_unlocked("first_steps"));
+}
+
+#[test]
+fn only_unlocked_titles_can_be_shown() {
+    let mut game = common::engine(7);
+    let events = game.apply(Command::SetTitle(Some("compulsive_opener".into())));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::UnknownId)]));
+
+    game.state.achievements.unlocked.insert("compulsive_opener".into());
+    game.state.achievements.unlocked.insert("first_steps".into());
+    let events = game.apply(Command::SetTitle(Some("compulsive_opener".into())));
+    assert!(matches!(&events[..], [GameEvent::TitleChanged { title: Some(t) }] if t == "the Insatiable"));
+
+    // First Steps has no title to show
+    let events = game.apply(Command::SetTitle(Some("first_steps".into())));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::UnknownId)]));
+    assert_eq!(game.state.achievements.title.as_deref(), Some("the Insatiable"));
+
+    let events = game.apply(Command::SetTitle(None));
+    assert!(matches!(events[..], [GameEvent::TitleChanged { title: None }]));
+    assert_eq!(game.state.achievements.title, None);
+}
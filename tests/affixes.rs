@@ -0,0 +1,71 @@
+mod common;
+
+use lootbox_game::data::affixes::{AffixKind, affix_chances, get_affix};
+use lootbox_game::data::items::get_item;
+use lootbox_game::game::chest::ChestType;
+use lootbox_game::game::events::GameEvent;
+use lootbox_game::game::inventory::Inventory;
+use lootbox_game::game::item::affixed_name;
+
+use common::item;
+
+#[test]
+fn prefixes_go_before_the_name_and_suffixes_after() {
+    let affixes = [get_affix("of_haste").unwrap(), get_affix("gleaming").unwrap()];
+    assert_eq!(affixed_name("Gold Bar", &affixes), "Gleaming Gold Bar of Haste");
+}
+
+#[test]
+fn drops_roll_affixes_by_rarity() {
+    let mut game = common::engine(7);
+    game.state.current_chest_type = ChestType::Gold;
+    let mut affixed = 0;
+    for _ in 0..300 {
+        let (_, events) = game.open_chest_now();
+        for e in &events {
+            let GameEvent::ItemFound { item, .. } = e else { continue };
+            let defs: Vec<_> = item.affix_defs().collect();
+            assert_eq!(defs.len(), item.affixes.len(), "unknown affix on {item:?}");
+            let prefixes = defs.iter().filter(|a| a.kind == AffixKind::Prefix).count();
+            let suffixes = defs.len() - prefixes;
+            let (prefix_chance, suffix_chance) = affix_chances(item.rarity);
+            assert!(prefixes <= usize::from(prefix_chance > 0.0));
+            assert!(suffixes <= usize::from(suffix_chance > 0.0));
+            assert_eq!(item.name, affixed_name(get_item(&item.id).unwrap().name, &defs));
+            if !defs.is_empty() {
+                affixed += 1;
+            }
+        }
+    }
+    assert!(affixed > 0, "no affixes in 300 Gold chests");
+}
+
+#[test]
+fn only_copies_with_the_same_affixes_stack() {
+    let mut gleaming = item("gold_bar");
+    gleaming.affixes = vec!["gleaming".into()];
+    let mut inventory = Inventory::default();
+    inventory.add(item("gold_bar"));
+    inventory.add(gleaming.clone());
+    inventory.add(item("gold_bar"));
+    inventory.add(gleaming);
+    assert_eq!(inventory.items.len(), 2);
+    assert!(inventory.items.iter().all(|i| i.count == 2));
+}
+
+#[test]
+fn held_affixes_count_once_while_in_the_inventory() {
+    let mut game = common::engine(7);
+    let luck = game.modifiers().stat_bonuses().luck;
+
+    let mut lucky = item("gold_bar");
+    lucky.affixes = vec!["lucky".into()];
+    game.state.inventory.add(lucky);
+    let mut other = item("sapphire_ring");
+    other.affixes = vec!["lucky".into()];
+    game.state.inventory.add(other);
+    assert_eq!(game.modifiers().stat_bonuses().luck, luck + 2.0);
+
+    game.state.inventory.items.clear();
+    assert_eq!(game.modifiers().stat_bonuses().luck, luck);
+}
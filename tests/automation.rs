@@ -0,0 +1,100 @@
+mod common;
+
+use lootbox_game::data::upgrades::{UpgradeCategory, all_upgrades, get_upgrade};
+use lootbox_game::game::automation::{AutoRule, RuleAction};
+use lootbox_game::game::bignum::BigNum;
+use lootbox_game::game::engine::GameEngine;
+use lootbox_game::game::events::{Command, GameEvent, Rejection};
+
+fn quartermaster(rule: AutoRule) -> GameEngine {
+    let mut game = common::engine(7);
+    game.state.rebirth.rebirth_skills.insert("rb_quartermaster".into());
+    game.apply(Command::AddRule(rule));
+    let events = game.apply(Command::ToggleAutomation);
+    assert!(matches!(events[..], [GameEvent::AutomationToggled { enabled: true }]));
+    game
+}
+
+fn auto_bought(events: &[GameEvent]) -> Vec<(String, u32)> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            GameEvent::AutoBought { id, level } => Some((id.clone(), *level)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn automation_needs_quartermaster() {
+    let mut game = common::engine(7);
+    let events = game.apply(Command::ToggleAutomation);
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::AutomationLocked)]));
+    let events = game.apply(Command::AddRule(AutoRule::default()));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::AutomationLocked)]));
+    assert!(game.state.automation.rules.is_empty());
+}
+
+#[test]
+fn a_rule_buys_only_within_its_budget() {
+    let mut game = quartermaster(AutoRule {
+        category: Some(UpgradeCategory::Speed),
+        ..AutoRule::default()
+    });
+    let cost = get_upgrade("nimble_fingers").unwrap().cost_at_level(0);
+
+    // 10% of this is just short of the cheapest Speed upgrade
+    game.state.player.gp = BigNum::from(cost * 10 - 1);
+    assert!(auto_bought(&game.tick()).is_empty());
+    assert_eq!(game.state.upgrades.get_level("nimble_fingers"), 0);
+
+    game.state.player.gp = BigNum::from(cost * 10);
+    assert_eq!(auto_bought(&game.tick()), [("nimble_fingers".to_string(), 1)]);
+    assert_eq!(game.state.player.gp, cost * 9);
+}
+
+#[test]
+fn a_paused_or_disabled_rule_buys_nothing() {
+    let mut game = quartermaster(AutoRule {
+        enabled: false,
+        ..AutoRule::default()
+    });
+    game.state.player.gp = BigNum::from(1_000_000_000u64);
+    assert!(auto_bought(&game.tick()).is_empty());
+
+    game.apply(Command::SetRule(0, AutoRule::default()));
+    game.apply(Command::ToggleAutomation);
+    assert!(auto_bought(&game.tick()).is_empty());
+
+    game.apply(Command::ToggleAutomation);
+    assert_eq!(auto_bought(&game.tick()).len(), 1);
+}
+
+/// Every Speed upgrade at level 2 except Nimble Fingers at 3, which still
+/// leaves it the cheapest.
+fn nimble_ahead(action: RuleAction) -> GameEngine {
+    let mut game = quartermaster(AutoRule {
+        action,
+        category: Some(UpgradeCategory::Speed),
+        max_cost_pct: 100,
+        ..AutoRule::default()
+    });
+    for upg in all_upgrades().iter().filter(|u| u.category == UpgradeCategory::Speed) {
+        game.state.upgrades.levels.insert(upg.id.to_string(), 2);
+    }
+    game.state.upgrades.levels.insert("nimble_fingers".into(), 3);
+    game.state.player.gp = BigNum::from(1_000_000_000u64);
+    game
+}
+
+#[test]
+fn a_balance_rule_skips_upgrades_ahead_of_the_rest() {
+    let mut game = nimble_ahead(RuleAction::Cheapest);
+    assert_eq!(auto_bought(&game.tick()), [("nimble_fingers".to_string(), 4)]);
+
+    let mut game = nimble_ahead(RuleAction::Balance { within: 1 });
+    let bought = auto_bought(&game.tick());
+    assert_eq!(bought.len(), 1);
+    assert_ne!(bought[0].0, "nimble_fingers");
+    assert_eq!(bought[0].1, 3);
+}
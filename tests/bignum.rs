@@ -0,0 +1,178 @@
+mod common;
+
+use lootbox_game::data::effects::Effect;
+use lootbox_game::data::pack::{self, DataPack};
+use lootbox_game::data::skills::{SkillBranch, SkillDef};
+use lootbox_game::game::bignum::BigNum;
+use lootbox_game::game::engine::GameEngine;
+use lootbox_game::game::events::GameEvent;
+
+/// The built-in pack plus one skill whose loot multipliers alone are past
+/// what an `f64` can hold.
+fn install_overflow_pack() {
+    let mut pack = DataPack::builtin();
+    pack.skills.push(SkillDef {
+        id: "test_overflow",
+        name: "Overflow",
+        description: "Multiplies loot past the f64 range.",
+        branch: SkillBranch::Fortune,
+        prerequisites: &[],
+        cost: 1,
+        effects: &[
+            Effect::LootGp(1e200),
+            Effect::LootGp(1e200),
+            Effect::LootXp(1e200),
+            Effect::LootXp(1e200),
+        ],
+    });
+    // Every test here installs the same pack, so losing the race is fine
+    pack::install(pack);
+}
+
+fn found(events: &[GameEvent]) -> (BigNum, BigNum) {
+    events
+        .iter()
+        .find_map(|e| match e {
+            GameEvent::ItemFound { item, .. } => Some((item.gp_value, item.xp_value)),
+            _ => None,
+        })
+        .expect("chest dropped an item")
+}
+
+#[test]
+fn loot_past_the_f64_range_keeps_growing() {
+    install_overflow_pack();
+    let mut game: GameEngine = common::engine(7);
+    common::learn(&mut game, &["test_overflow"]);
+
+    let (_, events) = game.open_chest_now();
+    let (gp, xp) = found(&events);
+    assert!(gp.log10() > 400.0, "gp {gp:?}");
+    assert!(xp.log10() > 400.0, "xp {xp:?}");
+    assert!(gp < BigNum::MAX);
+    assert!(xp < BigNum::MAX);
+
+    // A second drop adds to the total instead of pinning it at the cap
+    let after_one = game.state.player.gp;
+    game.open_chest_now();
+    assert!(game.state.player.gp > after_one);
+}
+
+#[test]
+fn equal_values_normalize_to_the_same_form() {
+    let cases = [
+        (BigNum::from_parts(1.0, 15), BigNum::new(1e15)),
+        (BigNum::from_parts(1000.0, 12), BigNum::from_parts(1.0, 15)),
+        (BigNum::new(1e20), BigNum::from_parts(1.0, 20)),
+        (BigNum::from_parts(5.0, -1), BigNum::new(0.5)),
+        (BigNum::from_parts(1.0, 16) / 10.0, BigNum::new(1e15)),
+        (BigNum::from_parts(1.0, 20) / 1e10, BigNum::new(1e10)),
+        (BigNum::new(-3.0), BigNum::ZERO),
+        (BigNum::new(f64::NAN), BigNum::ZERO),
+        (BigNum::new(f64::INFINITY), BigNum::MAX),
+    ];
+    for (a, b) in cases {
+        assert_eq!(a, b);
+    }
+
+    let scientific = [
+        (BigNum::from(12_345u64), (1.2345, 4)),
+        (BigNum::from_parts(1.23, 45), (1.23, 45)),
+        (BigNum::from_parts(4.5, 1000), (4.5, 1000)),
+    ];
+    for (n, (mantissa, exponent)) in scientific {
+        let (m, e) = n.scientific();
+        assert!((m - mantissa).abs() < 1e-9 && e == exponent, "{n:?}: {m}e{e}");
+    }
+}
+
+#[test]
+fn ordering_follows_the_value() {
+    let ascending = [
+        BigNum::ZERO,
+        BigNum::new(0.5),
+        BigNum::from(999u64),
+        BigNum::from(1_000u64),
+        BigNum::new(9.99e14),
+        BigNum::new(1e15),
+        BigNum::from_parts(9.99, 20),
+        BigNum::from_parts(1.0, 21),
+        BigNum::from_parts(1.0, 1_000_000),
+        BigNum::MAX,
+    ];
+    for pair in ascending.windows(2) {
+        assert!(pair[0] < pair[1], "{:?} < {:?}", pair[0], pair[1]);
+        assert!(pair[1] > pair[0]);
+    }
+}
+
+#[test]
+fn subtraction_stops_at_zero() {
+    let cases = [
+        (BigNum::from(10u64), BigNum::from(3u64), BigNum::from(7u64)),
+        (BigNum::from(5u64), BigNum::from(10u64), BigNum::ZERO),
+        (BigNum::from(5u64), BigNum::from(5u64), BigNum::ZERO),
+        (BigNum::from_parts(2.0, 20), BigNum::from_parts(1.0, 20), BigNum::from_parts(1.0, 20)),
+        // Too small to show at this size
+        (BigNum::from_parts(1.0, 40), BigNum::from(1u64), BigNum::from_parts(1.0, 40)),
+    ];
+    for (a, b, expected) in cases {
+        assert_eq!(a - b, expected, "{a:?} - {b:?}");
+    }
+}
+
+#[test]
+fn multiplying_by_a_float() {
+    let cases = [
+        (BigNum::from(10u64), 2.5, BigNum::from(25u64)),
+        (BigNum::from(10u64), 0.0, BigNum::ZERO),
+        (BigNum::from(10u64), -2.0, BigNum::ZERO),
+        (BigNum::from(10u64), f64::NAN, BigNum::ZERO),
+        (BigNum::new(1e14), 100.0, BigNum::from_parts(1.0, 16)),
+        (BigNum::from_parts(2.0, 300), 1e300, BigNum::from_parts(2.0, 600)),
+    ];
+    for (n, factor, expected) in cases {
+        assert_eq!(n * factor, expected, "{n:?} * {factor}");
+    }
+}
+
+#[test]
+fn displays_whole_numbers_then_scientific() {
+    let cases = [
+        (BigNum::ZERO, "0"),
+        (BigNum::new(12_345.7), "12345"),
+        (BigNum::new(9.99e14), "999000000000000"),
+        (BigNum::from_parts(1.23, 45), "1.23e45"),
+        (BigNum::from_parts(9.994, 45), "9.99e45"),
+        (BigNum::from_parts(9.999, 45), "1.00e46"),
+    ];
+    for (n, expected) in cases {
+        assert_eq!(n.to_string(), expected);
+    }
+}
+
+#[test]
+fn serializes_as_a_number_or_a_mantissa_exponent_string() {
+    let cases = [
+        (BigNum::from(12_345u64), serde_json::json!(12_345)),
+        (BigNum::new(0.5), serde_json::json!(0.5)),
+        (BigNum::from_parts(123.0, 43), serde_json::json!("123000000000000e31")),
+    ];
+    for (n, json) in cases {
+        assert_eq!(serde_json::to_value(n).unwrap(), json);
+        assert_eq!(serde_json::from_value::<BigNum>(json).unwrap(), n);
+    }
+    let parsed: BigNum = serde_json::from_str("\"1.5E20\"").unwrap();
+    assert_eq!(parsed, BigNum::from_parts(1.5, 20));
+    assert!(serde_json::from_str::<BigNum>("\"lots\"").is_err());
+}
+
+#[test]
+fn u64_amounts_from_old_saves_round_trip() {
+    for value in [0, 1, 999_999_999_999_999, 1_000_000_000_000_000, u64::MAX] {
+        let n: BigNum = serde_json::from_value(serde_json::json!(value)).unwrap();
+        assert_eq!(n, BigNum::from(value));
+        let text = serde_json::to_string(&n).unwrap();
+        assert_eq!(serde_json::from_str::<BigNum>(&text).unwrap(), n, "{value} as {text}");
+    }
+}
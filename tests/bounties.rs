@@ -0,0 +1,94 @@
+mod common;
+
+use lootbox_game::game::bignum::BigNum;
+use lootbox_game::game::bounty::{BOUNTY_SLOTS, Bounty, BountyReward, Objective, REFRESH_CHESTS};
+use lootbox_game::game::chest::ChestType;
+use lootbox_game::game::engine::GameEngine;
+use lootbox_game::game::events::{Command, GameEvent, Rejection};
+
+fn open_chests(chest: ChestType, count: u32) -> Objective {
+    Objective::OpenChests { chest, count }
+}
+
+/// A game with exactly these bounties on the board.
+fn board(bounties: Vec<Bounty>) -> GameEngine {
+    let mut game = common::engine(7);
+    game.state.bounties.bounties = bounties;
+    game
+}
+
+#[test]
+fn bounties_count_drops_from_their_own_chest() {
+    let mut game = board(vec![
+        Bounty::new(open_chests(ChestType::Iron, 2), BountyReward::SkillPoints(1)),
+        Bounty::new(open_chests(ChestType::Wooden, 2), BountyReward::SkillPoints(1)),
+    ]);
+    game.state.unlocked_chests.push(ChestType::Iron);
+    game.state.current_chest_type = ChestType::Iron;
+
+    game.open_chest_now();
+    let (_, events) = game.open_chest_now();
+    assert!(events.iter().any(|e| matches!(e, GameEvent::ItemFound { chest: ChestType::Iron, .. })));
+    assert!(events.iter().any(|e| matches!(e, GameEvent::BountyCompleted { index: 0 })));
+    let bounties = &game.state.bounties.bounties;
+    assert!(bounties[0].done);
+    assert_eq!(bounties[1].progress, 0);
+}
+
+#[test]
+fn claiming_pays_the_reward_and_posts_a_new_bounty() {
+    let mut done = Bounty::new(open_chests(ChestType::Wooden, 1), BountyReward::Gp(BigNum::from(500u64)));
+    done.done = true;
+    let mut game = board(vec![
+        Bounty::new(open_chests(ChestType::Wooden, 50), BountyReward::SkillPoints(1)),
+        done,
+    ]);
+
+    let events = game.apply(Command::ClaimBounty(0));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::BountyNotDone)]));
+    let events = game.apply(Command::ClaimBounty(5));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::UnknownId)]));
+
+    let events = game.apply(Command::ClaimBounty(1));
+    assert!(matches!(&events[..], [GameEvent::BountyClaimed { reward: BountyReward::Gp(gp) }] if *gp == 500));
+    assert_eq!(game.state.player.gp, 500);
+    let bounties = &game.state.bounties.bounties;
+    assert_eq!(bounties.len(), 2);
+    assert!(!bounties[1].done);
+}
+
+#[test]
+fn the_board_rotates_unfinished_bounties() {
+    let unfinished = Bounty::new(open_chests(ChestType::Wooden, 50), BountyReward::SkillPoints(1));
+    let mut done = Bounty::new(open_chests(ChestType::Wooden, 1), BountyReward::SkillPoints(2));
+    done.done = true;
+    let mut game = board(vec![unfinished.clone(), done.clone()]);
+    game.state.stats.chests_opened = REFRESH_CHESTS - 2;
+
+    let (_, events) = game.open_chest_now();
+    assert!(!events.iter().any(|e| matches!(e, GameEvent::BountiesRefreshed)));
+    let (_, events) = game.open_chest_now();
+    assert!(events.iter().any(|e| matches!(e, GameEvent::BountiesRefreshed)));
+    let bounties = &game.state.bounties.bounties;
+    assert_eq!(bounties[0].progress, 0);
+    assert_ne!(bounties[0], unfinished);
+    assert_eq!(bounties[1], done);
+    assert_eq!(game.state.bounties.refreshed_at, REFRESH_CHESTS);
+}
+
+#[test]
+fn new_bounties_fall_back_to_wooden_without_unlocked_chests() {
+    let mut game = common::engine(7);
+    assert_eq!(game.state.bounties.bounties.len(), BOUNTY_SLOTS);
+    game.state.unlocked_chests.clear();
+    for _ in 0..20 {
+        game.state.bounties.bounties[0].done = true;
+        game.apply(Command::ClaimBounty(0));
+        match &game.state.bounties.bounties[0].objective {
+            Objective::FindRarity { chest, .. }
+            | Objective::FindItem { chest, .. }
+            | Objective::OpenChests { chest, .. } => assert_eq!(*chest, ChestType::Wooden),
+            _ => {}
+        }
+    }
+}
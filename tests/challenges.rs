@@ -0,0 +1,155 @@
+mod common;
+
+use lootbox_game::data::challenges::get_challenge;
+use lootbox_game::data::skills::get_skill;
+use lootbox_game::game::automation::AutoRule;
+use lootbox_game::game::bignum::BigNum;
+use lootbox_game::game::chest::ChestType;
+use lootbox_game::game::engine::GameEngine;
+use lootbox_game::game::events::{Command, GameEvent, LootSource, Rejection, RespecTarget};
+use lootbox_game::game::offline::TICKS_PER_SEC;
+
+/// A game that has just started challenge `id`.
+fn challenging(id: &str) -> GameEngine {
+    let mut game = common::engine(7);
+    game.state.player.level = game.state.rebirth.min_level_for_rebirth();
+    let events = game.apply(Command::StartChallenge(id.into()));
+    assert!(events.iter().any(|e| matches!(e, GameEvent::ChallengeStarted { id: i } if i == id)));
+    assert_eq!(game.state.player.level, 1);
+    game
+}
+
+#[test]
+fn a_challenge_needs_a_rebirth_and_runs_one_at_a_time() {
+    let mut game = common::engine(7);
+    let events = game.apply(Command::StartChallenge("dunce_cap".into()));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::RebirthLevelTooLow { .. })]));
+
+    // Dragon's Hoard asks for GP, so the level needed here doesn't finish it
+    let mut game = challenging("dragons_hoard");
+    game.state.player.level = game.state.rebirth.min_level_for_rebirth();
+    let events = game.apply(Command::StartChallenge("molasses".into()));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::ChallengeRunning)]));
+}
+
+#[test]
+fn reaching_the_goal_completes_the_challenge_and_grants_its_reward() {
+    let mut game = challenging("dunce_cap");
+    let xp_mult = game.state.player.xp_multiplier;
+
+    game.state.player.level = 25;
+    let events = game.tick();
+    assert!(events.iter().any(|e| matches!(e, GameEvent::ChallengeCompleted { id, .. } if id == "dunce_cap")));
+    assert!(game.state.rebirth.challenge.is_none());
+    assert!(game.state.rebirth.has_completed_challenge("dunce_cap"));
+    assert!(game.state.player.xp_multiplier > xp_mult);
+}
+
+#[test]
+fn running_out_of_time_fails_the_challenge() {
+    let mut game = challenging("dunce_cap");
+    let limit = get_challenge("dunce_cap").unwrap().time_limit_secs * TICKS_PER_SEC;
+    game.state.rebirth.challenge.as_mut().unwrap().ticks = limit - 2;
+
+    let events = game.tick();
+    assert!(!events.iter().any(|e| matches!(e, GameEvent::ChallengeFailed { .. })));
+    let events = game.tick();
+    assert!(events.iter().any(|e| matches!(e, GameEvent::ChallengeFailed { id } if id == "dunce_cap")));
+    assert!(game.state.rebirth.challenge.is_none());
+    assert!(!game.state.rebirth.has_completed_challenge("dunce_cap"));
+}
+
+#[test]
+fn abandoning_fails_the_challenge() {
+    let mut game = challenging("molasses");
+    let events = game.apply(Command::AbandonChallenge);
+    assert!(matches!(&events[..], [GameEvent::ChallengeFailed { id }] if id == "molasses"));
+    let events = game.apply(Command::AbandonChallenge);
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::NoChallenge)]));
+}
+
+#[test]
+fn a_respec_refunds_the_doubled_cost_after_the_challenge_ends() {
+    let mut game = challenging("dunce_cap");
+    let cost = get_skill("lucky_charm").unwrap().cost;
+    game.state.skill_tree.skill_points = cost * 2 - 1;
+    let events = game.apply(Command::LearnSkill("lucky_charm".into()));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::NoSkillPoints)]));
+    game.state.skill_tree.skill_points = cost * 2;
+    game.apply(Command::LearnSkill("lucky_charm".into()));
+    assert_eq!(game.state.skill_tree.skill_points, 0);
+
+    // Skills cost the usual amount again from here on
+    game.apply(Command::AbandonChallenge);
+    let target = RespecTarget::Skill("lucky_charm".into());
+    game.state.player.gp = BigNum::from(game.respec_cost(&target));
+    let events = game.apply(Command::Respec(target));
+    assert!(matches!(&events[..], [GameEvent::SkillsRespecced { points, .. }] if *points == cost * 2));
+    assert_eq!(game.state.skill_tree.skill_points, cost * 2);
+}
+
+#[test]
+fn no_selling_keeps_recycled_commons_from_the_scavenger_too() {
+    let mut game = challenging("dragons_hoard");
+    for id in ["scavenger", "relic_hunter", "recycler"] {
+        game.state.skill_tree.learned.insert(id.to_string());
+    }
+
+    let mut scavenged = 0;
+    for _ in 0..2000 {
+        let (_, events) = game.open_chest_now();
+        let found: Vec<_> = events
+            .iter()
+            .filter_map(|e| match e {
+                GameEvent::ItemFound { item, source, .. } => Some((item, source)),
+                _ => None,
+            })
+            .collect();
+        for (item, source) in found {
+            if matches!(source, LootSource::Scavenger) {
+                scavenged += 1;
+            }
+            assert!(game.state.inventory.items.iter().any(|i| i.id == item.id), "{} was recycled", item.id);
+        }
+        if scavenged >= 3 {
+            return;
+        }
+    }
+    panic!("only {scavenged} scavenged drops in 2000 chests");
+}
+
+#[test]
+fn penny_pincher_blocks_upgrades_by_hand_and_by_automation() {
+    let mut game = common::engine(7);
+    game.state.rebirth.rebirth_skills.insert("rb_quartermaster".into());
+    game.state.player.level = game.state.rebirth.min_level_for_rebirth();
+    game.apply(Command::StartChallenge("penny_pincher".into()));
+    game.apply(Command::AddRule(AutoRule::default()));
+    game.apply(Command::ToggleAutomation);
+    game.state.player.gp = BigNum::from(1_000_000u64);
+
+    let events = game.apply(Command::BuyUpgrade("swift_hands".into()));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::BannedByChallenge)]));
+    let events = game.tick();
+    assert!(!events.iter().any(|e| matches!(e, GameEvent::AutoBought { .. })));
+    assert_eq!(game.state.upgrades.get_level("swift_hands"), 0);
+
+    game.apply(Command::AbandonChallenge);
+    let events = game.apply(Command::BuyUpgrade("swift_hands".into()));
+    assert!(events.iter().any(|e| matches!(e, GameEvent::UpgradeBought { .. })));
+}
+
+#[test]
+fn head_start_chests_come_back_when_the_challenge_ends() {
+    let mut game = common::engine(7);
+    game.state.rebirth.rebirth_skills.insert("rb_chest_unlock".into());
+    game.state.player.level = game.state.rebirth.min_level_for_rebirth();
+    game.apply(Command::StartChallenge("splinters".into()));
+    assert_eq!(game.state.unlocked_chests, [ChestType::Wooden]);
+
+    let events = game.apply(Command::AbandonChallenge);
+    for ct in [ChestType::Iron, ChestType::Silver] {
+        assert!(game.state.unlocked_chests.contains(&ct), "{ct:?} still locked");
+        assert!(events.iter().any(|e| matches!(e, GameEvent::ChestUnlocked(c) if *c == ct)));
+    }
+}
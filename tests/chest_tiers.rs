@@ -0,0 +1,107 @@
+mod common;
+
+use lootbox_game::data::effects::Effect;
+use lootbox_game::data::pack::{self, DataPack};
+use lootbox_game::data::skills::{SkillBranch, SkillDef};
+use lootbox_game::game::bignum::BigNum;
+use lootbox_game::game::chest::ChestType;
+use lootbox_game::game::engine::GameEngine;
+use lootbox_game::game::events::{Command, GameEvent, Rejection};
+use lootbox_game::game::item::Rarity;
+
+/// The built-in pack plus a skill that upgrades every drop's rarity.
+fn install_upgrade_pack() {
+    let mut pack = DataPack::builtin();
+    pack.skills.push(SkillDef {
+        id: "test_always_upgrade",
+        name: "Always Upgrade",
+        description: "Every drop goes up a rarity.",
+        branch: SkillBranch::Chaos,
+        prerequisites: &[],
+        cost: 1,
+        effects: &[Effect::RarityUpgrade { chance: 1.0, chaotic: false }],
+    });
+    // Every test here installs the same pack, so losing the race is fine
+    pack::install(pack);
+}
+
+fn drop_rarities(game: &mut GameEngine, chest: ChestType, chests: u32) -> Vec<Rarity> {
+    game.state.current_chest_type = chest;
+    let mut rarities = Vec::new();
+    for _ in 0..chests {
+        let (_, events) = game.open_chest_now();
+        rarities.extend(events.iter().filter_map(|e| match e {
+            GameEvent::ItemFound { item, .. } => Some(item.rarity),
+            _ => None,
+        }));
+    }
+    rarities
+}
+
+#[test]
+fn an_upgraded_mythic_from_a_void_chest_stays_mythic() {
+    install_upgrade_pack();
+    let mut game = common::engine(7);
+    common::learn(&mut game, &["test_always_upgrade"]);
+
+    let rarities = drop_rarities(&mut game, ChestType::Void, 500);
+    assert!(rarities.contains(&Rarity::Mythic));
+    assert!(!rarities.contains(&Rarity::Divine));
+    assert_eq!(Rarity::Mythic.next_tier(), Rarity::Mythic);
+    assert_eq!(Rarity::Divine.next_tier(), Rarity::Divine);
+}
+
+#[test]
+fn divine_items_drop_from_the_new_tiers() {
+    install_upgrade_pack();
+    let mut game = common::engine(7);
+    assert!(drop_rarities(&mut game, ChestType::Celestial, 1000).contains(&Rarity::Divine));
+    assert!(drop_rarities(&mut game, ChestType::Primordial, 1000).contains(&Rarity::Divine));
+    assert!(game.state.stats.divines_found > 0);
+}
+
+/// Level up from `level - 1` to `level` by opening one chest, returning
+/// the chests that unlocked.
+fn level_up_to(game: &mut GameEngine, level: u32) -> Vec<ChestType> {
+    game.state.player.level = level - 1;
+    game.state.player.xp_to_next = BigNum::from(1u64);
+    game.state.player.xp = BigNum::ZERO;
+    let (_, events) = game.open_chest_now();
+    assert!(events.iter().any(|e| matches!(e, GameEvent::LevelUp { level: l } if *l == level)));
+    events
+        .iter()
+        .filter_map(|e| match e {
+            GameEvent::ChestUnlocked(ct) => Some(*ct),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn the_new_tiers_need_their_transcendence_key_and_level() {
+    install_upgrade_pack();
+    let mut keyless = common::engine(7);
+    assert!(!level_up_to(&mut keyless, 60).contains(&ChestType::Celestial));
+    assert!(!level_up_to(&mut keyless, 75).contains(&ChestType::Primordial));
+
+    let mut game = common::engine(7);
+    for id in ["ts_celestial_key", "ts_primordial_key"] {
+        game.state.transcendence.skills.insert(id.into());
+    }
+    let events = game.apply(Command::SelectChest(ChestType::Celestial));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::ChestLocked(ChestType::Celestial))]));
+
+    assert!(!level_up_to(&mut game, 59).contains(&ChestType::Celestial));
+    assert!(level_up_to(&mut game, 60).contains(&ChestType::Celestial));
+    assert!(!level_up_to(&mut game, 74).contains(&ChestType::Primordial));
+    assert!(level_up_to(&mut game, 75).contains(&ChestType::Primordial));
+    let events = game.apply(Command::SelectChest(ChestType::Primordial));
+    assert!(events.iter().any(|e| matches!(e, GameEvent::ChestStarted(ChestType::Primordial))));
+}
+
+#[test]
+fn chest_index_follows_the_tier_list() {
+    for (i, ct) in ChestType::ALL.iter().enumerate() {
+        assert_eq!(ct.index(), i, "{}", ct.name());
+    }
+}
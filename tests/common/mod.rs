@@ -0,0 +1,83 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+
+use std::path::Path;
+use std::sync::OnceLock;
+
+use tempfile::TempDir;
+
+use lootbox_game::data::items::get_item;
+use lootbox_game::data::skills::get_skill;
+use lootbox_game::game::bignum::BigNum;
+use lootbox_game::game::engine::GameEngine;
+use lootbox_game::game::events::{Command, GameEvent};
+use lootbox_game::game::item::ItemInstance;
+use lootbox_game::game::profiles;
+use lootbox_game::game::rng::GameRng;
+use lootbox_game::game::state::GameState;
+
+/// A fresh game rolling from `seed`.
+pub fn engine(seed: u64) -> GameEngine {
+    GameEngine::new(state(seed))
+}
+
+/// A fresh state rolling from `seed`, for tests that set things up before
+/// the engine sees them.
+pub fn state(seed: u64) -> GameState {
+    GameState {
+        rng: GameRng::from_seed(seed),
+        ..GameState::default()
+    }
+}
+
+/// One plain copy of a built-in item, at its base value.
+pub fn item(id: &str) -> ItemInstance {
+    let def = get_item(id).expect("built-in item");
+    ItemInstance {
+        id: def.id.to_string(),
+        name: def.name.to_string(),
+        rarity: def.rarity,
+        gp_value: BigNum::from(def.base_gp),
+        xp_value: BigNum::from(def.base_xp),
+        is_crit: false,
+        count: 1,
+        affixes: Vec::new(),
+    }
+}
+
+/// Tick until the current chest has rolled its loot, returning everything
+/// that happened on the way.
+pub fn tick_until_found(engine: &mut GameEngine) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    for _ in 0..10_000 {
+        events.extend(engine.tick());
+        if events.iter().any(|e| matches!(e, GameEvent::ItemFound { .. })) {
+            return events;
+        }
+    }
+    panic!("no item found after 10,000 ticks");
+}
+
+/// Grant exactly the skill points `ids` cost and learn them in order.
+pub fn learn(engine: &mut GameEngine, ids: &[&str]) {
+    for id in ids {
+        engine.state.skill_tree.skill_points += get_skill(id).expect("built-in skill").cost;
+        let events = engine.apply(Command::LearnSkill(id.to_string()));
+        assert!(
+            events.iter().any(|e| matches!(e, GameEvent::SkillLearned { .. })),
+            "could not learn {id}: {events:?}"
+        );
+    }
+}
+
+/// A scratch data directory for this test binary's profiles and saves.
+/// Tests sharing it use their own profile names.
+pub fn data_dir() -> &'static Path {
+    static DIR: OnceLock<TempDir> = OnceLock::new();
+    DIR.get_or_init(|| {
+        let dir = TempDir::new().expect("temp dir");
+        assert!(profiles::use_data_dir(dir.path().to_path_buf()));
+        dir
+    })
+    .path()
+}
@@ -0,0 +1,61 @@
+mod common;
+
+use lootbox_game::game::engine::GameEngine;
+use lootbox_game::game::events::{Command, GameEvent, Rejection};
+use lootbox_game::game::item::Rarity;
+
+use common::item;
+
+fn holding(stacks: &[(&str, u32)]) -> GameEngine {
+    let mut game = common::engine(7);
+    for &(id, count) in stacks {
+        for _ in 0..count {
+            game.state.inventory.add(item(id));
+        }
+    }
+    game
+}
+
+fn count_of(game: &GameEngine, id: &str) -> u32 {
+    game.state.inventory.items.iter().filter(|i| i.id == id).map(|i| i.count).sum()
+}
+
+#[test]
+fn a_craft_without_the_ingredients_is_rejected() {
+    let mut game = holding(&[("rusty_coin", 4), ("pebble", 1)]);
+    let events = game.apply(Command::Craft("refine_common".into()));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::MissingIngredients)]));
+    assert_eq!(count_of(&game, "rusty_coin"), 4);
+    assert_eq!(count_of(&game, "pebble"), 1);
+
+    let events = game.apply(Command::Craft("no_such_recipe".into()));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::UnknownId)]));
+}
+
+#[test]
+fn a_successful_craft_uses_the_cheapest_inputs_and_adds_the_output() {
+    let mut game = holding(&[("iron_ring", 5), ("pebble", 5)]);
+    let events = game.apply(Command::Craft("refine_common".into()));
+    let crafted = events
+        .iter()
+        .find_map(|e| match e {
+            GameEvent::Crafted { recipe, item } if recipe == "refine_common" => item.clone(),
+            _ => None,
+        })
+        .expect("refining commons always works");
+    assert_eq!(crafted.rarity, Rarity::Uncommon);
+    assert_eq!(count_of(&game, "pebble"), 0);
+    assert_eq!(count_of(&game, "iron_ring"), 5);
+    assert_eq!(count_of(&game, &crafted.id), 1);
+    assert!(game.state.collection.has_found(&crafted.id));
+}
+
+#[test]
+fn a_failed_craft_still_uses_up_its_inputs() {
+    let mut game = holding(&[("silver_coin", 5)]);
+    // Bad enough luck that nothing can succeed
+    game.state.player.luck = -1000.0;
+    let events = game.apply(Command::Craft("refine_uncommon".into()));
+    assert!(matches!(&events[..], [GameEvent::Crafted { item: None, .. }]));
+    assert!(game.state.inventory.items.is_empty());
+}
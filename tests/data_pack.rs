@@ -0,0 +1,164 @@
+use std::fs;
+use std::path::PathBuf;
+
+use lootbox_game::data::affixes::AffixEntry;
+use lootbox_game::data::chests::LootEntry;
+use lootbox_game::data::pack::{DataPack, PackError, ValidationError};
+use lootbox_game::data::sets::SetMembers;
+use lootbox_game::game::chest::ChestType;
+
+fn pack_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("lootbox_pack_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn builtin_pack_is_valid() {
+    assert_eq!(DataPack::builtin().validate(), Vec::new());
+}
+
+#[test]
+fn duplicate_ids_are_reported() {
+    let mut pack = DataPack::builtin();
+    pack.items.push(pack.items[0].clone());
+    pack.skills.push(pack.skills[3].clone());
+    let errors = pack.validate();
+    assert!(errors.contains(&ValidationError::DuplicateId {
+        table: "items",
+        id: pack.items[0].id.to_string(),
+    }));
+    assert!(errors.contains(&ValidationError::DuplicateId {
+        table: "skills",
+        id: pack.skills[3].id.to_string(),
+    }));
+    assert_eq!(errors.len(), 2);
+}
+
+#[test]
+fn chest_without_a_loot_table_is_reported() {
+    let mut pack = DataPack::builtin();
+    pack.loot_tables.remove(&ChestType::Void);
+    assert_eq!(pack.validate(), vec![ValidationError::MissingLootTable(ChestType::Void)]);
+}
+
+#[test]
+fn loot_entry_for_an_unknown_item_is_reported() {
+    let mut pack = DataPack::builtin();
+    let table = pack.loot_tables.get_mut(&ChestType::Iron).unwrap();
+    table.entries.push(LootEntry { item_id: "golden_goose", weight: 1.0 });
+    assert_eq!(
+        pack.validate(),
+        vec![ValidationError::UnknownItem {
+            chest: ChestType::Iron,
+            item_id: "golden_goose".into(),
+        }]
+    );
+}
+
+#[test]
+fn set_naming_an_unknown_item_is_reported() {
+    let mut pack = DataPack::builtin();
+    let set = pack
+        .sets
+        .iter_mut()
+        .find(|s| matches!(s.members, SetMembers::Items(_)))
+        .unwrap();
+    set.members = SetMembers::Items(&["pebble", "golden_goose"]);
+    let set_id = set.id.to_string();
+    assert_eq!(
+        pack.validate(),
+        vec![ValidationError::UnknownSetItem {
+            set: set_id,
+            item_id: "golden_goose".into(),
+        }]
+    );
+}
+
+#[test]
+fn affix_pool_naming_an_unknown_affix_is_reported() {
+    let mut pack = DataPack::builtin();
+    let pool = pack.affix_pools.get_mut(&ChestType::Gold).unwrap();
+    pool.entries.push(AffixEntry { affix_id: "of_nothing", weight: 1.0 });
+    assert_eq!(
+        pack.validate(),
+        vec![ValidationError::UnknownAffix {
+            chest: ChestType::Gold,
+            affix_id: "of_nothing".into(),
+        }]
+    );
+}
+
+#[test]
+fn unknown_prerequisite_is_reported() {
+    let mut pack = DataPack::builtin();
+    let skill = pack.rebirth_skills.iter_mut().find(|s| s.prerequisites.is_empty()).unwrap();
+    skill.prerequisites = &["rb_missing"];
+    let id = skill.id.to_string();
+    assert_eq!(
+        pack.validate(),
+        vec![ValidationError::UnknownPrerequisite {
+            table: "rebirth_skills",
+            id,
+            prerequisite: "rb_missing".into(),
+        }]
+    );
+}
+
+#[test]
+fn prerequisite_cycle_is_reported() {
+    let mut pack = DataPack::builtin();
+    // golden_touch already requires lucky_charm
+    let lucky = pack.skills.iter_mut().find(|s| s.id == "lucky_charm").unwrap();
+    lucky.prerequisites = &["golden_touch"];
+    let errors = pack.validate();
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    let ValidationError::PrerequisiteCycle { table, cycle } = &errors[0] else {
+        panic!("expected a cycle, got {errors:?}");
+    };
+    assert_eq!(*table, "skills");
+    assert!(cycle.contains(&"lucky_charm".to_string()));
+    assert!(cycle.contains(&"golden_touch".to_string()));
+    assert_eq!(cycle.first(), cycle.last());
+}
+
+#[test]
+fn written_pack_loads_back() {
+    let dir = pack_dir("round_trip");
+    DataPack::builtin().write_dir(&dir).unwrap();
+    let pack = DataPack::load_dir(&dir).unwrap();
+    assert_eq!(pack.items.len(), DataPack::builtin().items.len());
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn invalid_pack_on_disk_is_refused() {
+    let dir = pack_dir("invalid");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("items.toml"),
+        r#"
+[[items]]
+id = "pebble"
+name = "Pebble"
+rarity = "Common"
+base_gp = 1
+base_xp = 1
+description = "Twice."
+
+[[items]]
+id = "pebble"
+name = "Pebble"
+rarity = "Common"
+base_gp = 1
+base_xp = 1
+description = "Twice."
+"#,
+    )
+    .unwrap();
+    let Err(PackError::Invalid(errors)) = DataPack::load_dir(&dir) else {
+        panic!("pack should be refused");
+    };
+    assert!(errors.contains(&ValidationError::DuplicateId { table: "items", id: "pebble".into() }));
+    let _ = fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,68 @@
+mod common;
+
+use lootbox_game::game::bignum::BigNum;
+use lootbox_game::game::engine::GameEngine;
+use lootbox_game::game::events::{Command, GameEvent};
+use lootbox_game::game::save::{load_from_str, to_save_string};
+
+use common::{engine, learn, tick_until_found};
+
+/// Skills with random procs, so the stream is drawn from in many places.
+const SKILLS: &[&str] = &[
+    "lucky_charm",
+    "golden_touch",
+    "windfall",
+    "critical_eye",
+    "crit_cascade",
+    "multi_drop",
+    "scavenger",
+];
+
+/// Round `round` of a fixed script: open a chest by ticking, collect it,
+/// and now and then buy an upgrade.
+fn play_round(game: &mut GameEngine, round: u32) -> Vec<GameEvent> {
+    let mut events = game.apply(Command::OpenChest);
+    events.extend(tick_until_found(game));
+    events.extend(game.apply(Command::Collect));
+    if round % 5 == 4 {
+        events.extend(game.apply(Command::BuyUpgrade("swift_hands".into())));
+    }
+    events
+}
+
+fn setup(seed: u64) -> GameEngine {
+    let mut game = engine(seed);
+    learn(&mut game, SKILLS);
+    game.state.player.gp = BigNum::from(1_000_000u64);
+    game
+}
+
+#[test]
+fn same_seed_and_commands_give_the_same_drops_across_a_reload() {
+    let mut straight = setup(1234);
+    let mut reloaded = setup(1234);
+
+    let mut straight_log = Vec::new();
+    let mut reloaded_log = Vec::new();
+    for round in 0..60 {
+        if round == 30 {
+            let text = to_save_string(&reloaded.state).unwrap();
+            reloaded = GameEngine::new(load_from_str(&text).unwrap());
+        }
+        straight_log.push(format!("{:?}", play_round(&mut straight, round)));
+        reloaded_log.push(format!("{:?}", play_round(&mut reloaded, round)));
+    }
+
+    assert_eq!(straight_log, reloaded_log);
+    assert_eq!(straight.state.rng.draws(), reloaded.state.rng.draws());
+    assert_eq!(straight.state.player.gp, reloaded.state.player.gp);
+}
+
+#[test]
+fn different_seeds_give_different_drops() {
+    let mut a = setup(1);
+    let mut b = setup(2);
+    let a_log: Vec<_> = (0..20).map(|r| format!("{:?}", play_round(&mut a, r))).collect();
+    let b_log: Vec<_> = (0..20).map(|r| format!("{:?}", play_round(&mut b, r))).collect();
+    assert_ne!(a_log, b_log);
+}
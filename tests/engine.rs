@@ -0,0 +1,171 @@
+mod common;
+
+use lootbox_game::data::upgrades::get_upgrade;
+use lootbox_game::game::bignum::BigNum;
+use lootbox_game::game::chest::{ChestState, ChestType};
+use lootbox_game::game::events::{Command, GameEvent, LootSource, Rejection};
+
+use common::{engine, item, learn, tick_until_found};
+
+#[test]
+fn opening_a_chest_finds_an_item_and_collecting_resets_it() {
+    let mut game = engine(7);
+
+    let events = game.apply(Command::OpenChest);
+    assert!(matches!(events[..], [GameEvent::ChestStarted(ChestType::Wooden)]));
+    assert_eq!(game.state.chest_progress.state, ChestState::Opening);
+
+    let events = tick_until_found(&mut game);
+    let found = events
+        .iter()
+        .find_map(|e| match e {
+            GameEvent::ItemFound { item, source: LootSource::Chest, .. } => Some(item.clone()),
+            _ => None,
+        })
+        .expect("chest drop");
+    assert_eq!(game.state.chest_progress.state, ChestState::Revealing);
+    assert_eq!(game.state.stats.chests_opened, 1);
+    assert_eq!(game.state.player.gp, found.gp_value);
+    assert_eq!(game.state.inventory.items.len(), 1);
+    assert_eq!(game.state.inventory.items[0].id, found.id);
+
+    let events = game.apply(Command::Collect);
+    assert!(matches!(events[..], [GameEvent::ChestCollected]));
+    assert_eq!(game.state.chest_progress.state, ChestState::Idle);
+}
+
+#[test]
+fn collecting_an_idle_chest_does_nothing() {
+    let mut game = engine(7);
+    assert!(game.apply(Command::Collect).is_empty());
+}
+
+#[test]
+fn buying_an_upgrade_spends_gp_and_raises_its_level() {
+    let mut game = engine(7);
+    let cost = get_upgrade("swift_hands").unwrap().cost_at_level(0);
+    let speed = game.state.player.speed;
+
+    let events = game.apply(Command::BuyUpgrade("swift_hands".into()));
+    assert!(matches!(&events[..], [GameEvent::Rejected(Rejection::NotEnoughGp { cost: c })] if *c == cost));
+
+    game.state.player.gp = BigNum::from(cost + 5);
+    let events = game.apply(Command::BuyUpgrade("swift_hands".into()));
+    assert!(matches!(&events[..], [GameEvent::UpgradeBought { id, level: 1 }] if id == "swift_hands"));
+    assert_eq!(game.state.player.gp, 5);
+    assert_eq!(game.state.upgrades.get_level("swift_hands"), 1);
+    assert!(game.state.player.speed > speed);
+}
+
+#[test]
+fn learning_a_skill_spends_points_and_applies_it() {
+    let mut game = engine(7);
+    let events = game.apply(Command::LearnSkill("lucky_charm".into()));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::NoSkillPoints)]));
+
+    let luck = game.state.player.luck;
+    game.state.skill_tree.skill_points = 1;
+    let events = game.apply(Command::LearnSkill("lucky_charm".into()));
+    assert!(matches!(&events[..], [GameEvent::SkillLearned { id }] if id == "lucky_charm"));
+    assert_eq!(game.state.skill_tree.skill_points, 0);
+    assert!(game.state.skill_tree.has_skill("lucky_charm"));
+    assert!(game.state.player.luck > luck);
+
+    let events = game.apply(Command::LearnSkill("lucky_charm".into()));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::AlreadyLearned)]));
+}
+
+#[test]
+fn rebirth_pays_essence_and_resets_the_run() {
+    let mut game = engine(7);
+    let events = game.apply(Command::Rebirth);
+    assert!(matches!(
+        events[..],
+        [GameEvent::Rejected(Rejection::RebirthLevelTooLow { required: 25, current: 1 })]
+    ));
+
+    game.state.player.level = 25;
+    game.state.player.gp = BigNum::from(1_000_000u64);
+    game.state.inventory.add(item("pebble"));
+    let expected = game.essence_reward();
+    assert!(expected > BigNum::ZERO);
+
+    let events = game.apply(Command::Rebirth);
+    let essence = events
+        .iter()
+        .find_map(|e| match e {
+            GameEvent::Rebirth { count: 1, essence, .. } => Some(*essence),
+            _ => None,
+        })
+        .expect("rebirth event");
+    assert_eq!(essence, expected);
+    assert_eq!(game.state.rebirth.essence, expected);
+    assert_eq!(game.state.rebirth.rebirth_count, 1);
+    assert_eq!(game.state.rebirth.highest_level_ever, 25);
+    assert_eq!(game.state.player.level, 1);
+    assert!(game.state.player.gp.is_zero());
+    assert!(game.state.inventory.items.is_empty());
+}
+
+#[test]
+fn selling_needs_the_alchemy_skill_and_pays_part_of_the_value() {
+    let mut game = engine(7);
+    game.state.inventory.add(item("silver_coin"));
+    game.state.inventory.add(item("silver_coin"));
+
+    let events = game.apply(Command::SellItem(0));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::SellingLocked)]));
+
+    learn(&mut game, &["transmute_basics"]);
+    let value = item("silver_coin").gp_value;
+    let events = game.apply(Command::SellItem(0));
+    let sold = events
+        .iter()
+        .find_map(|e| match e {
+            GameEvent::ItemSold { gp, remaining: 1, .. } => Some(*gp),
+            _ => None,
+        })
+        .expect("sale event");
+    assert_eq!(sold, (value * 0.5).floor());
+    assert_eq!(game.state.player.gp, sold);
+    assert_eq!(game.state.inventory.items[0].count, 1);
+
+    let events = game.apply(Command::SellAll);
+    assert!(events.iter().any(|e| matches!(e, GameEvent::ItemsSold { count: 1, .. })));
+    assert!(game.state.inventory.items.is_empty());
+    let events = game.apply(Command::SellAll);
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::NothingToSell)]));
+}
+
+#[test]
+fn opening_a_chest_now_matches_waiting_it_out() {
+    let mut waited = engine(7);
+    let mut rushed = engine(7);
+
+    waited.apply(Command::OpenChest);
+    let mut ticks = 0;
+    let waited_drop = loop {
+        ticks += 1;
+        if let Some(GameEvent::ItemFound { item, .. }) =
+            waited.tick().into_iter().find(|e| matches!(e, GameEvent::ItemFound { .. }))
+        {
+            break item;
+        }
+    };
+
+    let (rushed_ticks, events) = rushed.open_chest_now();
+    let rushed_drop = events
+        .into_iter()
+        .find_map(|e| match e {
+            GameEvent::ItemFound { item, .. } => Some(item),
+            _ => None,
+        })
+        .expect("chest drop");
+    assert_eq!(rushed_ticks, ticks);
+    assert_eq!(rushed_drop.id, waited_drop.id);
+    assert_eq!(rushed_drop.gp_value, waited_drop.gp_value);
+    assert_eq!(rushed.state.player.gp, waited.state.player.gp);
+    // The sim opens back to back, so the chest is already collected
+    assert_eq!(rushed.state.chest_progress.state, ChestState::Idle);
+    assert_eq!(rushed.state.stats.chests_opened, 1);
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use lootbox_game::game::bignum::BigNum;
+use lootbox_game::game::inventory::{Inventory, InventoryQuery, InventoryView, SortMode};
+use lootbox_game::game::item::Rarity;
+
+use common::item;
+
+/// Found in this order: a Rusty Coin worth more than usual, a Gold Bar,
+/// three Silver Coins and a Crown of Ages.
+fn inventory() -> Inventory {
+    let mut inventory = Inventory::default();
+    let mut coin = item("rusty_coin");
+    coin.gp_value = BigNum::from(5000u64);
+    inventory.add(coin);
+    inventory.add(item("gold_bar"));
+    for _ in 0..3 {
+        inventory.add(item("silver_coin"));
+    }
+    inventory.add(item("crown_of_ages"));
+    inventory
+}
+
+fn rows(query: &InventoryQuery) -> Vec<usize> {
+    InventoryView::new(&inventory(), query).rows().to_vec()
+}
+
+fn sorted(sort: SortMode) -> Vec<usize> {
+    rows(&InventoryQuery { sort, ..InventoryQuery::default() })
+}
+
+#[test]
+fn each_sort_mode_orders_the_stacks() {
+    assert_eq!(sorted(SortMode::Rarity), [3, 1, 2, 0]);
+    assert_eq!(sorted(SortMode::Value), [0, 3, 1, 2]);
+    assert_eq!(sorted(SortMode::Count), [2, 0, 1, 3]);
+    assert_eq!(sorted(SortMode::Name), [3, 1, 0, 2]);
+    assert_eq!(sorted(SortMode::Newest), [3, 2, 1, 0]);
+}
+
+#[test]
+fn rarity_filter_and_search_narrow_the_rows() {
+    let uncommon = InventoryQuery { rarity: Some(Rarity::Uncommon), ..InventoryQuery::default() };
+    assert_eq!(rows(&uncommon), [2]);
+
+    let coins = InventoryQuery { search: "COIN".into(), ..InventoryQuery::default() };
+    assert_eq!(rows(&coins), [2, 0]);
+
+    let both = InventoryQuery { rarity: Some(Rarity::Common), ..coins };
+    assert_eq!(rows(&both), [0]);
+
+    let nothing = InventoryQuery { search: "dragon".into(), ..InventoryQuery::default() };
+    assert!(InventoryView::new(&inventory(), &nothing).is_empty());
+}
+
+#[test]
+fn rows_map_back_to_inventory_items() {
+    let inventory = inventory();
+    let query = InventoryQuery { sort: SortMode::Name, ..InventoryQuery::default() };
+    let view = InventoryView::new(&inventory, &query);
+    assert_eq!(view.len(), inventory.items.len());
+    let names: Vec<&str> = (0..view.len())
+        .map(|row| inventory.items[view.item_index(row).unwrap()].name.as_str())
+        .collect();
+    assert_eq!(names, ["Crown of Ages", "Gold Bar", "Rusty Coin", "Silver Coin"]);
+    assert_eq!(view.item_index(view.len()), None);
+}
@@ -0,0 +1,89 @@
+mod common;
+
+use lootbox_game::data::relics::all_relics;
+use lootbox_game::game::engine::GameEngine;
+use lootbox_game::game::events::{Command, GameEvent, Rejection};
+use lootbox_game::game::item::Rarity;
+
+/// Four owned relics, all equipped thanks to Relic Affinity and saved as
+/// loadout 0.
+fn four_relic_loadout() -> (GameEngine, Vec<String>) {
+    let mut game = common::engine(7);
+    game.state.rebirth.rebirth_skills.insert("rb_relic_slot".into());
+    let ids: Vec<String> = all_relics()
+        .iter()
+        .filter(|r| r.rarity == Rarity::Uncommon)
+        .take(4)
+        .map(|r| r.id.to_string())
+        .collect();
+    for id in &ids {
+        game.state.relics.add_relic(id.clone());
+        game.apply(Command::ToggleRelic(id.clone()));
+    }
+    assert_eq!(game.state.relics.equipped, ids);
+    let events = game.apply(Command::SaveRelicLoadout { slot: 0, name: "Luck".into() });
+    assert!(matches!(&events[..], [GameEvent::RelicLoadoutSaved { slot: 0, name }] if name == "Luck"));
+    game.apply(Command::UnequipAllRelics);
+    (game, ids)
+}
+
+fn equip(game: &mut GameEngine, slot: usize) -> (Vec<String>, Vec<String>) {
+    let events = game.apply(Command::EquipRelicLoadout(slot));
+    match &events[..] {
+        [GameEvent::RelicLoadoutEquipped { missing, no_room, .. }] => (missing.clone(), no_room.clone()),
+        _ => panic!("loadout not equipped: {events:?}"),
+    }
+}
+
+#[test]
+fn equipping_a_loadout_restores_its_relics() {
+    let (mut game, ids) = four_relic_loadout();
+    let (missing, no_room) = equip(&mut game, 0);
+    assert!(missing.is_empty() && no_room.is_empty());
+    assert_eq!(game.state.relics.equipped, ids);
+}
+
+#[test]
+fn a_loadout_reports_relics_without_room() {
+    let (mut game, ids) = four_relic_loadout();
+    game.state.rebirth.rebirth_skills.remove("rb_relic_slot");
+    let (missing, no_room) = equip(&mut game, 0);
+    assert!(missing.is_empty());
+    assert_eq!(no_room, [ids[3].clone()]);
+    assert_eq!(game.state.relics.equipped, ids[..3]);
+}
+
+#[test]
+fn a_loadout_reports_relics_no_longer_owned() {
+    let (mut game, ids) = four_relic_loadout();
+    game.state.rebirth.rebirth_skills.remove("rb_relic_slot");
+    game.state.relics.remove(&ids[1]);
+    let (missing, no_room) = equip(&mut game, 0);
+    assert_eq!(missing, [ids[1].clone()]);
+    assert!(no_room.is_empty());
+    assert_eq!(game.state.relics.equipped, [ids[0].clone(), ids[2].clone(), ids[3].clone()]);
+}
+
+#[test]
+fn loadouts_outlast_a_rebirth() {
+    let (mut game, ids) = four_relic_loadout();
+    game.state.player.level = game.state.rebirth.min_level_for_rebirth();
+    let events = game.apply(Command::Rebirth);
+    assert!(events.iter().any(|e| matches!(e, GameEvent::Rebirth { .. })));
+    assert!(game.state.relics.owned.is_empty());
+    assert_eq!(game.state.relics.loadout(0).map(|l| &l.ids), Some(&ids));
+
+    let (missing, _) = equip(&mut game, 0);
+    assert_eq!(missing, ids);
+}
+
+#[test]
+fn bad_loadout_commands_are_rejected() {
+    let mut game = common::engine(7);
+    let events = game.apply(Command::EquipRelicLoadout(0));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::EmptyLoadout)]));
+    let events = game.apply(Command::SaveRelicLoadout { slot: 0, name: "Empty".into() });
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::NoRelicsEquipped)]));
+    let events = game.apply(Command::SaveRelicLoadout { slot: 99, name: "Far".into() });
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::UnknownId)]));
+}
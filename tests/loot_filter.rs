@@ -0,0 +1,97 @@
+mod common;
+
+use lootbox_game::game::bignum::BigNum;
+use lootbox_game::game::engine::GameEngine;
+use lootbox_game::game::events::{Command, GameEvent, Rejection};
+use lootbox_game::game::item::{ItemInstance, Rarity};
+use lootbox_game::game::loot_filter::FilterAction;
+
+/// A game with the filter unlocked and on, doing `action` to every rarity.
+fn filtering(action: FilterAction) -> GameEngine {
+    let mut game = common::engine(7);
+    common::learn(&mut game, &["transmute_basics", "gold_synthesis", "alchemical_sieve"]);
+    for rarity in Rarity::ALL {
+        game.apply(Command::SetRarityFilter(rarity, action));
+    }
+    let events = game.apply(Command::ToggleLootFilter);
+    assert!(matches!(events[..], [GameEvent::LootFilterToggled { enabled: true }]));
+    game
+}
+
+fn found(events: &[GameEvent]) -> ItemInstance {
+    events
+        .iter()
+        .find_map(|e| match e {
+            GameEvent::ItemFound { item, .. } => Some(item.clone()),
+            _ => None,
+        })
+        .expect("chest drop")
+}
+
+fn filtered(events: &[GameEvent]) -> (FilterAction, BigNum) {
+    events
+        .iter()
+        .find_map(|e| match e {
+            GameEvent::DropFiltered { action, value, .. } => Some((*action, *value)),
+            _ => None,
+        })
+        .expect("drop filtered")
+}
+
+#[test]
+fn the_filter_needs_alchemical_sieve() {
+    let mut game = common::engine(7);
+    let events = game.apply(Command::ToggleLootFilter);
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::FilterLocked)]));
+    let events = game.apply(Command::SetRarityFilter(Rarity::Common, FilterAction::Sell));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::FilterLocked)]));
+    assert!(game.state.loot_filter.rarities.is_empty());
+}
+
+#[test]
+fn a_sell_rule_sells_the_drop_at_the_sell_price() {
+    let mut game = filtering(FilterAction::Sell);
+    let (_, events) = game.open_chest_now();
+    let item = found(&events);
+    let (action, gp) = filtered(&events);
+    assert_eq!(action, FilterAction::Sell);
+    assert_eq!(gp, game.sell_price(&item));
+    assert!(game.state.inventory.items.is_empty());
+}
+
+#[test]
+fn a_salvage_rule_turns_the_drop_into_xp() {
+    let mut game = filtering(FilterAction::Salvage);
+    let (_, events) = game.open_chest_now();
+    let item = found(&events);
+    let (action, xp) = filtered(&events);
+    assert_eq!(action, FilterAction::Salvage);
+    assert!(xp > 0 && xp < item.xp_value);
+    assert!(game.state.inventory.items.is_empty());
+}
+
+#[test]
+fn item_rules_and_the_gp_threshold_beat_the_rarity_rule() {
+    let mut game = filtering(FilterAction::Sell);
+    game.apply(Command::SetKeepAboveGp(BigNum::from(1u64)));
+    let (_, events) = game.open_chest_now();
+    let item = found(&events);
+    assert!(!events.iter().any(|e| matches!(e, GameEvent::DropFiltered { .. })));
+    assert_eq!(game.state.inventory.items.len(), 1);
+
+    // The item's own rule wins over the threshold
+    game.apply(Command::SetItemFilter(item.id.clone(), Some(FilterAction::Salvage)));
+    let filter = &game.state.loot_filter;
+    assert_eq!(filter.action_for(&item), FilterAction::Salvage);
+    game.apply(Command::SetItemFilter(item.id.clone(), None));
+    assert_eq!(game.state.loot_filter.action_for(&item), FilterAction::Keep);
+}
+
+#[test]
+fn a_paused_filter_keeps_everything() {
+    let mut game = filtering(FilterAction::Sell);
+    game.apply(Command::ToggleLootFilter);
+    let (_, events) = game.open_chest_now();
+    assert!(!events.iter().any(|e| matches!(e, GameEvent::DropFiltered { .. })));
+    assert_eq!(game.state.inventory.items.len(), 1);
+}
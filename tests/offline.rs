@@ -0,0 +1,61 @@
+mod common;
+
+use lootbox_game::game::bignum::BigNum;
+use lootbox_game::game::engine::GameEngine;
+use lootbox_game::game::events::Command;
+use lootbox_game::game::offline::catch_up;
+
+use common::{engine, learn};
+
+const SAVED_AT: u64 = 1_700_000_000;
+
+/// A game that opens chests on its own, with `night_shifts` hours of cap.
+fn unattended(night_shifts: u32) -> GameEngine {
+    let mut game = engine(3);
+    learn(&mut game, &["swift_hands", "auto_opener"]);
+    game.state.player.gp = BigNum::from(1_000_000_000u64);
+    for _ in 0..night_shifts {
+        game.apply(Command::BuyUpgrade("night_shift".into()));
+    }
+    game.state.player.gp = BigNum::ZERO;
+    game.state.last_saved = SAVED_AT;
+    game
+}
+
+#[test]
+fn time_away_is_played_out_up_to_the_cap() {
+    let mut game = unattended(1);
+    let report = catch_up(&mut game, SAVED_AT + 3 * 3600).expect("offline progress");
+    assert_eq!(report.away_secs, 3 * 3600);
+    assert_eq!(report.simulated_secs, 3600);
+    assert!(report.chests > 0);
+    assert_eq!(report.chests, game.state.stats.chests_opened);
+    assert_eq!(report.gp, game.state.stats.total_gp_earned);
+    assert_eq!(report.items, game.state.stats.items_found);
+}
+
+#[test]
+fn a_longer_cap_opens_more_chests() {
+    let mut short = unattended(1);
+    let mut long = unattended(2);
+    let now = SAVED_AT + 5 * 3600;
+    let short = catch_up(&mut short, now).unwrap();
+    let long = catch_up(&mut long, now).unwrap();
+    assert_eq!(long.simulated_secs, 2 * 3600);
+    assert!(long.chests > short.chests);
+}
+
+#[test]
+fn nothing_happens_without_a_cap_a_save_time_or_an_auto_opener() {
+    let mut no_cap = unattended(0);
+    assert!(catch_up(&mut no_cap, SAVED_AT + 3600).is_none());
+
+    let mut never_saved = unattended(1);
+    never_saved.state.last_saved = 0;
+    assert!(catch_up(&mut never_saved, SAVED_AT + 3600).is_none());
+
+    let mut manual = engine(3);
+    manual.state.last_saved = SAVED_AT;
+    assert!(catch_up(&mut manual, SAVED_AT + 3600).is_none());
+    assert_eq!(manual.state.stats.chests_opened, 0);
+}
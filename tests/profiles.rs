@@ -0,0 +1,124 @@
+mod common;
+
+use std::fs;
+
+use lootbox_game::game::profiles::{
+    self, DEFAULT_PROFILE, MAX_NAME_LEN, ProfileError, adopt_legacy_save, validate_name,
+};
+use lootbox_game::game::save::{load_game, save_game};
+use lootbox_game::game::state::GameState;
+
+/// Make `name` with a save at `level`.
+fn saved_profile(name: &str, level: u32) {
+    common::data_dir();
+    profiles::create(name).unwrap();
+    let mut state = GameState::default();
+    state.player.level = level;
+    save_game(name, &state).unwrap();
+}
+
+fn level_of(name: &str) -> u32 {
+    load_game(name).unwrap().expect("a save").player.level
+}
+
+#[test]
+fn profile_names_must_be_plain() {
+    let longest = "a".repeat(MAX_NAME_LEN);
+    let too_long = "a".repeat(MAX_NAME_LEN + 1);
+    let cases = [
+        ("main", true),
+        ("Speed run_2", true),
+        ("hard-mode", true),
+        ("Ünïcode", true),
+        (longest.as_str(), true),
+        ("", false),
+        (too_long.as_str(), false),
+        (" leading", false),
+        ("trailing ", false),
+        ("a/b", false),
+        ("..", false),
+        ("dot.name", false),
+    ];
+    for (name, valid) in cases {
+        assert_eq!(validate_name(name).is_ok(), valid, "{name:?}");
+    }
+}
+
+#[test]
+fn create_makes_an_empty_profile_once() {
+    common::data_dir();
+    profiles::create("fresh").unwrap();
+    assert!(load_game("fresh").unwrap().is_none());
+    let listed = profiles::list();
+    let fresh = listed.iter().find(|p| p.name == "fresh").expect("listed");
+    assert_eq!(fresh.level, 1);
+    assert!(!fresh.damaged);
+
+    assert!(matches!(profiles::create("fresh"), Err(ProfileError::AlreadyExists(_))));
+    assert!(matches!(profiles::create("no/slash"), Err(ProfileError::InvalidName(_))));
+}
+
+#[test]
+fn copy_duplicates_the_save() {
+    saved_profile("original", 7);
+    profiles::copy("original", "duplicate").unwrap();
+    assert_eq!(level_of("original"), 7);
+    assert_eq!(level_of("duplicate"), 7);
+
+    assert!(matches!(profiles::copy("missing", "elsewhere"), Err(ProfileError::NotFound(_))));
+    assert!(matches!(
+        profiles::copy("original", "duplicate"),
+        Err(ProfileError::AlreadyExists(_))
+    ));
+}
+
+#[test]
+fn rename_moves_the_save() {
+    saved_profile("before", 4);
+    profiles::rename("before", "after").unwrap();
+    assert!(load_game("before").unwrap().is_none());
+    assert_eq!(level_of("after"), 4);
+    assert!(matches!(profiles::rename("before", "again"), Err(ProfileError::NotFound(_))));
+}
+
+#[test]
+fn delete_removes_the_profile() {
+    saved_profile("doomed", 3);
+    profiles::delete("doomed").unwrap();
+    assert!(!profiles::list().iter().any(|p| p.name == "doomed"));
+    assert!(matches!(profiles::delete("doomed"), Err(ProfileError::NotFound(_))));
+}
+
+#[test]
+fn damaged_save_is_listed_as_damaged() {
+    saved_profile("broken", 9);
+    let path = profiles::profile_dir("broken").unwrap().join("save.json");
+    fs::write(path, "not json").unwrap();
+    let listed = profiles::list();
+    let broken = listed.iter().find(|p| p.name == "broken").expect("listed");
+    assert!(broken.damaged);
+}
+
+#[test]
+fn legacy_save_moves_into_the_default_profile() {
+    let root = common::data_dir();
+    let mut state = GameState::default();
+    state.player.level = 12;
+    save_game("staging", &state).unwrap();
+    let staging = profiles::profile_dir("staging").unwrap();
+    fs::copy(staging.join("save.json"), root.join("save.json")).unwrap();
+    fs::write(root.join("save.1.json"), "older").unwrap();
+
+    adopt_legacy_save();
+    assert!(!root.join("save.json").exists());
+    assert!(!root.join("save.1.json").exists());
+    assert_eq!(level_of(DEFAULT_PROFILE), 12);
+    let main = profiles::profile_dir(DEFAULT_PROFILE).unwrap();
+    assert_eq!(fs::read_to_string(main.join("save.1.json")).unwrap(), "older");
+
+    // Once the default profile exists a stray save is left alone
+    fs::write(root.join("save.json"), "stray").unwrap();
+    adopt_legacy_save();
+    assert!(root.join("save.json").exists());
+    assert_eq!(level_of(DEFAULT_PROFILE), 12);
+}
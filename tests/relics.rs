@@ -0,0 +1,177 @@
+mod common;
+
+use lootbox_game::data::relics::{all_relics, get_relic, relic_stat_totals};
+use lootbox_game::game::chest::ChestType;
+use lootbox_game::game::engine::GameEngine;
+use lootbox_game::game::events::{Command, GameEvent, Rejection};
+use lootbox_game::game::item::Rarity;
+use lootbox_game::game::relic::{MAX_RELIC_LEVEL, shards_spent};
+
+/// A game that owns `ids` and has `shards` of each rarity.
+fn owning(ids: &[&str], shards: u32) -> GameEngine {
+    let mut game = common::engine(7);
+    for id in ids {
+        game.state.relics.add_relic(id.to_string());
+    }
+    for rarity in Rarity::ALL {
+        game.state.relics.shards.insert(rarity, shards);
+    }
+    game
+}
+
+#[test]
+fn duplicate_drops_become_shards() {
+    let mut game = common::engine(7);
+    for relic in all_relics().iter().filter(|r| r.min_chest_tier <= 2) {
+        game.state.relics.add_relic(relic.id.to_string());
+    }
+    game.state.current_chest_type = ChestType::Silver;
+    let owned = game.state.relics.owned.len();
+
+    for _ in 0..5000 {
+        let (_, events) = game.open_chest_now();
+        assert!(!events.iter().any(|e| matches!(e, GameEvent::RelicFound { .. })));
+        let Some(id) = events.iter().find_map(|e| match e {
+            GameEvent::RelicDuplicate { id } => Some(id.clone()),
+            _ => None,
+        }) else {
+            continue;
+        };
+        let rarity = get_relic(&id).unwrap().rarity;
+        assert_eq!(game.state.relics.shards(rarity), 1);
+        assert_eq!(game.state.relics.owned.len(), owned);
+        return;
+    }
+    panic!("no duplicate relic in 5000 Silver chests");
+}
+
+#[test]
+fn upgrading_a_relic_spends_shards_up_to_the_max_level() {
+    let mut game = owning(&["lucky_coin"], 0);
+    let events = game.apply(Command::UpgradeRelic("lucky_coin".into()));
+    assert!(matches!(
+        events[..],
+        [GameEvent::Rejected(Rejection::NotEnoughShards { rarity: Rarity::Uncommon, cost: 2 })]
+    ));
+
+    let total = shards_spent(MAX_RELIC_LEVEL);
+    game.state.relics.shards.insert(Rarity::Uncommon, total);
+    for level in 2..=MAX_RELIC_LEVEL {
+        let events = game.apply(Command::UpgradeRelic("lucky_coin".into()));
+        assert!(matches!(&events[..], [GameEvent::RelicUpgraded { level: l, .. }, ..] if *l == level));
+    }
+    assert_eq!(game.state.relics.level("lucky_coin"), MAX_RELIC_LEVEL);
+    assert_eq!(game.state.relics.shards(Rarity::Uncommon), 0);
+
+    let events = game.apply(Command::UpgradeRelic("lucky_coin".into()));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::AlreadyMaxed)]));
+}
+
+#[test]
+fn only_owned_relics_can_be_upgraded() {
+    let mut game = owning(&[], 100);
+    let events = game.apply(Command::UpgradeRelic("lucky_coin".into()));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::UnknownId)]));
+    assert_eq!(game.state.relics.shards(Rarity::Uncommon), 100);
+}
+
+#[test]
+fn relic_levels_scale_their_effect() {
+    let mut game = owning(&["lucky_coin"], 100);
+    game.apply(Command::ToggleRelic("lucky_coin".into()));
+    assert_eq!(relic_stat_totals(&game.state.relics).luck, 2.0);
+    let luck = game.state.player.luck;
+
+    game.apply(Command::UpgradeRelic("lucky_coin".into()));
+    assert_eq!(relic_stat_totals(&game.state.relics).luck, 3.0);
+    assert_eq!(game.state.player.luck, luck + 1.0);
+}
+
+fn ids_of(rarity: Rarity) -> Vec<&'static str> {
+    all_relics().iter().filter(|r| r.rarity == rarity).map(|r| r.id).collect()
+}
+
+fn fuse(game: &mut GameEngine, ids: &[&str]) -> Vec<GameEvent> {
+    game.apply(Command::FuseRelics(ids.iter().map(|id| id.to_string()).collect()))
+}
+
+#[test]
+fn fusing_relics_makes_one_of_the_next_rarity_and_refunds_their_levels() {
+    let inputs = &ids_of(Rarity::Uncommon)[..3];
+    let mut game = owning(inputs, 2);
+    game.apply(Command::UpgradeRelic(inputs[0].into()));
+    game.apply(Command::ToggleRelic(inputs[1].into()));
+    assert_eq!(game.state.relics.shards(Rarity::Uncommon), 0);
+
+    let events = fuse(&mut game, inputs);
+    let id = events
+        .iter()
+        .find_map(|e| match e {
+            GameEvent::RelicsFused { id, duplicate: false, .. } => Some(id.clone()),
+            _ => None,
+        })
+        .expect("fused");
+    assert_eq!(get_relic(&id).unwrap().rarity, Rarity::Rare);
+    assert_eq!(game.state.relics.owned, [id]);
+    assert!(game.state.relics.equipped.is_empty());
+    assert_eq!(game.state.relics.shards(Rarity::Uncommon), 2);
+}
+
+#[test]
+fn fusing_into_a_full_rarity_gives_a_shard() {
+    let inputs = &ids_of(Rarity::Uncommon)[..3];
+    let mut game = owning(inputs, 0);
+    for id in ids_of(Rarity::Rare) {
+        game.state.relics.add_relic(id.to_string());
+    }
+    let events = fuse(&mut game, inputs);
+    assert!(events.iter().any(|e| matches!(e, GameEvent::RelicsFused { duplicate: true, .. })));
+    assert_eq!(game.state.relics.shards(Rarity::Rare), 1);
+}
+
+#[test]
+fn invalid_fusions_are_rejected_and_keep_their_relics() {
+    let uncommon = ids_of(Rarity::Uncommon);
+    let rare = ids_of(Rarity::Rare);
+    let mythic = ids_of(Rarity::Mythic);
+    let owned: Vec<&str> = [&uncommon[..3], &rare[..1], &mythic[..2]].concat();
+    let mut game = owning(&owned, 0);
+
+    let invalid = [
+        &uncommon[..2],
+        &[uncommon[0], uncommon[1], rare[0]][..],
+        &mythic[..2],
+    ];
+    for ids in invalid {
+        let events = fuse(&mut game, ids);
+        assert!(matches!(events[..], [GameEvent::Rejected(Rejection::InvalidFusion)]), "{ids:?}");
+    }
+    let unknown = [
+        &[][..],
+        &[uncommon[0], uncommon[0], uncommon[1]][..],
+        &[uncommon[0], uncommon[1], uncommon[3]][..],
+    ];
+    for ids in unknown {
+        let events = fuse(&mut game, ids);
+        assert!(matches!(events[..], [GameEvent::Rejected(Rejection::UnknownId)]), "{ids:?}");
+    }
+    assert_eq!(game.state.relics.owned.len(), owned.len());
+}
+
+#[test]
+fn relic_drop_chance_adds_flat_bonuses_after_relic_boosts() {
+    let mut game = common::engine(7);
+    game.state.current_chest_type = ChestType::Gold;
+    assert_eq!(game.relic_drop_chance(Rarity::Common), 0.0);
+    assert!((game.relic_drop_chance(Rarity::Legendary) - 0.20).abs() < 1e-9);
+
+    // Relic Hunter doubles the base, Lodestone adds 10% to that, Deep
+    // Salvage adds a flat 10% and World Explorer scales the lot by 1.5
+    for id in ["relic_hunter", "deep_salvage", "world_explorer"] {
+        game.state.skill_tree.learned.insert(id.to_string());
+    }
+    game.state.relics.add_relic("lodestone".into());
+    game.state.relics.equipped.push("lodestone".into());
+    let expected = (0.20 * 2.0 * 1.10 + 0.10) * 1.5;
+    assert!((game.relic_drop_chance(Rarity::Legendary) - expected).abs() < 1e-9);
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use lootbox_game::data::skills::get_skill;
+use lootbox_game::game::bignum::BigNum;
+use lootbox_game::game::events::{Command, GameEvent, Rejection, RespecTarget};
+use lootbox_game::game::skill_tree::respec_cost;
+
+use common::learn;
+
+fn skill_cost(id: &str) -> u32 {
+    get_skill(id).unwrap().cost
+}
+
+#[test]
+fn respec_cost_doubles_and_triples_for_the_whole_tree() {
+    assert_eq!(respec_cost(0, 10, false), 2_500);
+    assert_eq!(respec_cost(1, 10, false), 5_000);
+    assert_eq!(respec_cost(2, 10, true), 30_000);
+    assert_eq!(respec_cost(0, 0, false), 250);
+    assert_eq!(respec_cost(80, 10, false), u64::MAX);
+}
+
+#[test]
+fn respeccing_a_skill_refunds_its_points_for_gp() {
+    let mut game = common::engine(7);
+    learn(&mut game, &["lucky_charm"]);
+    let target = RespecTarget::Skill("lucky_charm".into());
+    let gp = game.respec_cost(&target);
+
+    let events = game.apply(Command::Respec(target.clone()));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::NotEnoughGp { cost })] if cost == gp));
+
+    game.state.player.gp = BigNum::from(gp);
+    let events = game.apply(Command::Respec(target.clone()));
+    assert!(matches!(
+        &events[..],
+        [GameEvent::SkillsRespecced { ids, points, cost }]
+            if ids == &["lucky_charm"] && *points == skill_cost("lucky_charm") && *cost == gp
+    ));
+    assert!(!game.state.skill_tree.has_skill("lucky_charm"));
+    assert_eq!(game.state.skill_tree.skill_points, skill_cost("lucky_charm"));
+    assert_eq!(game.state.player.gp, 0);
+    assert_eq!(game.respec_cost(&target), gp * 2);
+}
+
+#[test]
+fn respeccing_the_whole_tree_refunds_every_skill() {
+    let mut game = common::engine(7);
+    let ids = ["lucky_charm", "golden_touch", "swift_hands"];
+    learn(&mut game, &ids);
+    game.state.player.gp = BigNum::from(game.respec_cost(&RespecTarget::WholeTree));
+
+    let events = game.apply(Command::Respec(RespecTarget::WholeTree));
+    let points: u32 = ids.iter().map(|id| skill_cost(id)).sum();
+    assert!(matches!(&events[..], [GameEvent::SkillsRespecced { ids, points: p, .. }] if ids.len() == 3 && *p == points));
+    assert!(game.state.skill_tree.learned.is_empty());
+    assert_eq!(game.state.skill_tree.skill_points, points);
+}
+
+#[test]
+fn only_learned_leaf_skills_can_be_respecced() {
+    let mut game = common::engine(7);
+    game.state.player.gp = BigNum::from(1_000_000u64);
+    let events = game.apply(Command::Respec(RespecTarget::WholeTree));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::NotLearned)]));
+    let events = game.apply(Command::Respec(RespecTarget::Skill("lucky_charm".into())));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::NotLearned)]));
+
+    learn(&mut game, &["lucky_charm", "golden_touch"]);
+    let events = game.apply(Command::Respec(RespecTarget::Skill("lucky_charm".into())));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::SkillInUse)]));
+    assert_eq!(game.state.player.gp, 1_000_000);
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use std::fs::{self, File};
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use lootbox_game::game::bignum::BigNum;
+use lootbox_game::game::save::{
+    BACKUP_COUNT, BACKUP_INTERVAL, SaveError, load_backups, load_game, save_game,
+    set_aside_unreadable_save,
+};
+use lootbox_game::game::state::GameState;
+use serde_json::Value;
+
+fn profile_dir(profile: &str) -> PathBuf {
+    common::data_dir().join("profiles").join(profile)
+}
+
+fn save_with_gp(profile: &str, gp: u64) {
+    let mut state = GameState::default();
+    state.player.gp = BigNum::from(gp);
+    save_game(profile, &state).expect("save");
+}
+
+fn gp_in(path: PathBuf) -> BigNum {
+    let save: Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+    serde_json::from_value(save["player"]["gp"].clone()).unwrap()
+}
+
+/// Date the newest backup far enough back that the next save rotates.
+fn age_newest_backup(profile: &str) {
+    let file = File::options().write(true).open(profile_dir(profile).join("save.1.json")).unwrap();
+    file.set_modified(SystemTime::now() - BACKUP_INTERVAL * 2).unwrap();
+}
+
+#[test]
+fn save_goes_through_a_temp_file_and_loads_back() {
+    save_with_gp("atomic", 250);
+    let dir = profile_dir("atomic");
+    assert!(dir.join("save.json").exists());
+    assert!(!dir.join("save.json.tmp").exists());
+    // Nothing to back up on the first save
+    assert!(!dir.join("save.1.json").exists());
+
+    let state = load_game("atomic").unwrap().expect("a save");
+    assert_eq!(state.player.gp, 250);
+    assert!(load_game("never_saved").unwrap().is_none());
+}
+
+#[test]
+fn edited_save_file_is_rejected() {
+    save_with_gp("edited", 250);
+    let path = profile_dir("edited").join("save.json");
+    let text = fs::read_to_string(&path).unwrap().replacen("250", "999999", 1);
+    fs::write(&path, text).unwrap();
+    assert!(matches!(load_game("edited"), Err(SaveError::ChecksumMismatch)));
+}
+
+#[test]
+fn backups_rotate_once_per_interval() {
+    let dir = profile_dir("rotating");
+    save_with_gp("rotating", 1);
+    save_with_gp("rotating", 2);
+    assert_eq!(gp_in(dir.join("save.1.json")), 1);
+
+    // Autosaves soon after keep the backup as it was
+    save_with_gp("rotating", 3);
+    assert_eq!(gp_in(dir.join("save.1.json")), 1);
+    assert!(!dir.join("save.2.json").exists());
+
+    age_newest_backup("rotating");
+    save_with_gp("rotating", 4);
+    assert_eq!(gp_in(dir.join("save.1.json")), 3);
+    assert_eq!(gp_in(dir.join("save.2.json")), 1);
+
+    for gp in 5..5 + BACKUP_COUNT as u64 {
+        age_newest_backup("rotating");
+        save_with_gp("rotating", gp);
+    }
+    assert!(dir.join(format!("save.{BACKUP_COUNT}.json")).exists());
+    assert!(!dir.join(format!("save.{}.json", BACKUP_COUNT + 1)).exists());
+    let backups = load_backups("rotating");
+    assert_eq!(backups.len(), BACKUP_COUNT);
+    assert_eq!(backups[0].state.player.gp, 3 + BACKUP_COUNT as u64);
+}
+
+#[test]
+fn unreadable_save_is_set_aside_and_a_backup_restored() {
+    save_with_gp("restored", 100);
+    save_with_gp("restored", 200);
+    let dir = profile_dir("restored");
+    fs::write(dir.join("save.json"), "{ not a save").unwrap();
+    assert!(matches!(load_game("restored"), Err(SaveError::Json(_))));
+
+    let aside = set_aside_unreadable_save("restored").expect("moved aside");
+    assert_eq!(fs::read_to_string(aside).unwrap(), "{ not a save");
+    assert!(load_game("restored").unwrap().is_none());
+
+    let backup = load_backups("restored").into_iter().next().expect("a backup");
+    assert_eq!(backup.path, dir.join("save.1.json"));
+    save_game("restored", &backup.state).unwrap();
+    assert_eq!(load_game("restored").unwrap().unwrap().player.gp, 100);
+}
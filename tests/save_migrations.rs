@@ -0,0 +1,300 @@
+use lootbox_game::game::bignum::BigNum;
+use lootbox_game::game::item::Rarity;
+use lootbox_game::game::loot_filter::FilterAction;
+use lootbox_game::game::rng::GameRng;
+use lootbox_game::game::save::{SAVE_VERSION, SaveError, load_from_str, to_save_string};
+use lootbox_game::game::state::GameState;
+use rand::RngCore;
+use serde_json::Value;
+
+const V0: &str = include_str!("fixtures/saves/v0.json");
+const V1: &str = include_str!("fixtures/saves/v1.json");
+const V2: &str = include_str!("fixtures/saves/v2.json");
+const V3: &str = include_str!("fixtures/saves/v3.json");
+const V4: &str = include_str!("fixtures/saves/v4.json");
+const V5: &str = include_str!("fixtures/saves/v5.json");
+const V6: &str = include_str!("fixtures/saves/v6.json");
+const V7: &str = include_str!("fixtures/saves/v7.json");
+const V8: &str = include_str!("fixtures/saves/v8.json");
+const V9: &str = include_str!("fixtures/saves/v9.json");
+const V10: &str = include_str!("fixtures/saves/v10.json");
+const V11: &str = include_str!("fixtures/saves/v11.json");
+const V12: &str = include_str!("fixtures/saves/v12.json");
+const V13: &str = include_str!("fixtures/saves/v13.json");
+const V14: &str = include_str!("fixtures/saves/v14.json");
+
+#[test]
+fn v0_key_upgrades_become_skills() {
+    let state = load_from_str(V0).expect("v0 save loads");
+    assert_eq!(state.save_version, SAVE_VERSION);
+    assert_eq!(state.player.level, 6);
+    assert_eq!(state.player.gp, 500);
+
+    for id in ["iron_key", "silver_key", "swift_hands", "auto_opener"] {
+        assert!(state.skill_tree.has_skill(id), "missing skill {id}");
+    }
+    assert!(!state.skill_tree.has_skill("gold_key"));
+    // 5 points earned by level 6, 4 spent on the converted skills
+    assert_eq!(state.skill_tree.skill_points, 1);
+
+    assert_eq!(state.rebirth.rebirth_count, 0);
+    assert_eq!(state.rebirth.highest_level_ever, 6);
+    assert!(state.inventory.items.iter().all(|i| i.count == 1));
+    assert_eq!(state.stats.mythics_found, 0);
+    assert!(state.show_animations && state.chest_sounds && state.ui_sounds);
+}
+
+#[test]
+fn v1_keeps_skills_and_rebirth_and_gains_rng() {
+    let state = load_from_str(V1).expect("v1 save loads");
+    assert_eq!(state.save_version, SAVE_VERSION);
+    assert!(state.skill_tree.has_skill("lucky_charm"));
+    assert_eq!(state.skill_tree.skill_points, 0);
+    assert_eq!(state.rebirth.rebirth_count, 1);
+    assert_eq!(state.rebirth.essence, 20);
+    assert!(state.rebirth.has_rebirth_skill("rb_lucky_start"));
+    assert_eq!(state.rng.draws(), 0);
+    assert_eq!(state.counters.consecutive_chests, 0);
+}
+
+#[test]
+fn v2_keeps_rng_position() {
+    let state = load_from_str(V2).expect("v2 save loads");
+    assert_eq!(state.save_version, SAVE_VERSION);
+    assert_eq!(state.rng.seed(), 42);
+    assert_eq!(state.rng.draws(), 120);
+    assert_eq!(state.upgrades.get_level("swift_hands"), 1);
+
+    // The saved generator carries on where 120 draws from seed 42 left off
+    let mut expected = GameRng::from_seed(42);
+    for _ in 0..120 {
+        expected.next_u64();
+    }
+    let mut rng = state.rng.clone();
+    assert_eq!(rng.next_u64(), expected.next_u64());
+}
+
+#[test]
+fn current_save_round_trips() {
+    let state = load_from_str(V2).unwrap();
+    let json = serde_json::to_string(&state).unwrap();
+    let reloaded: GameState = load_from_str(&json).unwrap();
+    assert_eq!(reloaded.player.xp, state.player.xp);
+    assert_eq!(reloaded.inventory.items.len(), state.inventory.items.len());
+    assert_eq!(reloaded.rng.draws(), state.rng.draws());
+}
+
+#[test]
+fn changed_field_type_is_an_error_not_a_new_game() {
+    let mut save: Value = serde_json::from_str(V2).unwrap();
+    save["player"]["gp"] = Value::String("500".into());
+    let result = load_from_str(&save.to_string());
+    assert!(matches!(result, Err(SaveError::Json(_))));
+}
+
+#[test]
+fn newer_save_is_refused() {
+    let mut save: Value = serde_json::from_str(V2).unwrap();
+    save["save_version"] = (SAVE_VERSION + 1).into();
+    let result = load_from_str(&save.to_string());
+    assert!(matches!(result, Err(SaveError::TooNew { .. })));
+}
+
+#[test]
+fn checksummed_save_round_trips() {
+    let state = load_from_str(V2).unwrap();
+    let text = to_save_string(&state).unwrap();
+    let reloaded = load_from_str(&text).unwrap();
+    assert_eq!(reloaded.player.gp, state.player.gp);
+}
+
+#[test]
+fn edited_save_fails_its_checksum() {
+    let state = load_from_str(V2).unwrap();
+    let mut save: Value = serde_json::from_str(&to_save_string(&state).unwrap()).unwrap();
+    save["player"]["gp"] = 999_999.into();
+    let result = load_from_str(&save.to_string());
+    assert!(matches!(result, Err(SaveError::ChecksumMismatch)));
+}
+
+#[test]
+fn gp_past_u64_round_trips() {
+    let mut state = load_from_str(V2).unwrap();
+    state.player.gp = BigNum::from_parts(1.23, 45);
+    state.rebirth.essence = BigNum::from(u64::MAX) * 1000.0;
+    let text = to_save_string(&state).unwrap();
+    let reloaded = load_from_str(&text).unwrap();
+    assert_eq!(reloaded.player.gp, state.player.gp);
+    assert_eq!(reloaded.rebirth.essence, state.rebirth.essence);
+
+    // Amounts that fit stay plain numbers, as older saves have them
+    let save: Value = serde_json::from_str(&text).unwrap();
+    assert!(save["player"]["xp"].is_u64());
+    assert!(save["player"]["gp"].is_string());
+}
+
+#[test]
+fn v2_has_found_what_it_holds() {
+    let state = load_from_str(V2).unwrap();
+    assert!(!state.inventory.items.is_empty());
+    for item in &state.inventory.items {
+        assert!(state.collection.has_found(&item.id));
+    }
+    assert!(state.collection.completed.is_empty());
+}
+
+#[test]
+fn v2_bounties_rotate_from_chests_already_opened() {
+    let state = load_from_str(V2).unwrap();
+    assert!(state.bounties.bounties.is_empty());
+    assert_eq!(state.bounties.refreshed_at, state.stats.chests_opened);
+}
+
+#[test]
+fn v2_starts_every_later_feature_from_scratch() {
+    let state = load_from_str(V2).unwrap();
+    assert_eq!(state.last_saved, 0);
+    assert!(!state.automation.enabled);
+    assert!(state.automation.rules.is_empty());
+    assert!(!state.loot_filter.enabled);
+    assert!(state.loot_filter.rarities.is_empty());
+    assert!(state.loot_filter.keep_above_gp.is_zero());
+    assert!(state.inventory.items.iter().all(|item| item.affixes.is_empty()));
+    assert!(state.relics.shards.is_empty());
+    assert!(state.relics.owned.iter().all(|id| state.relics.level(id) == 1));
+    assert!(state.relics.loadouts.is_empty());
+    assert_eq!(state.skill_tree.respecs, 0);
+    assert!(state.skill_tree.paid.is_empty());
+    assert!(state.achievements.unlocked.is_empty());
+    assert_eq!(state.achievements.title, None);
+    assert!(state.rebirth.challenge.is_none());
+    assert!(state.rebirth.challenges_completed.is_empty());
+    assert_eq!(state.transcendence.count, 0);
+    assert!(state.transcendence.aether.is_zero());
+    assert!(state.transcendence.skills.is_empty());
+    assert_eq!(state.stats.divines_found, 0);
+}
+
+/// Load a fixture that names its own version and check it was brought up
+/// to date.
+fn load_versioned(text: &str, version: u64) -> GameState {
+    let save: Value = serde_json::from_str(text).unwrap();
+    assert_eq!(save["save_version"], version);
+    let state = load_from_str(text).unwrap_or_else(|e| panic!("v{version} save loads: {e}"));
+    assert_eq!(state.save_version, SAVE_VERSION);
+    state
+}
+
+#[test]
+fn v3_keeps_last_saved_and_gains_automation() {
+    let state = load_versioned(V3, 3);
+    assert_eq!(state.last_saved, 1_700_000_000);
+    assert_eq!(state.rng.draws(), 120);
+    assert!(!state.automation.enabled);
+    assert!(state.automation.rules.is_empty());
+}
+
+#[test]
+fn v4_keeps_automation_and_gains_loot_filter() {
+    let state = load_versioned(V4, 4);
+    assert!(state.automation.enabled);
+    assert_eq!(state.automation.rules.len(), 1);
+    assert_eq!(state.automation.rules[0].max_cost_pct, 50);
+    assert!(!state.loot_filter.enabled);
+    assert!(state.loot_filter.rarities.is_empty());
+    assert!(state.loot_filter.keep_above_gp.is_zero());
+}
+
+#[test]
+fn v5_keeps_loot_filter_and_finds_what_it_holds() {
+    let state = load_versioned(V5, 5);
+    assert!(state.loot_filter.enabled);
+    assert_eq!(state.loot_filter.rarities.get(&Rarity::Common), Some(&FilterAction::Sell));
+    assert_eq!(state.loot_filter.keep_above_gp, 1000);
+    assert_eq!(state.collection.found.len(), state.inventory.items.len());
+    for item in &state.inventory.items {
+        assert!(state.collection.has_found(&item.id));
+    }
+}
+
+#[test]
+fn v6_keeps_collection_and_items_stay_plain() {
+    let state = load_versioned(V6, 6);
+    // Found once and since sold, so not rebuilt from the inventory
+    assert!(state.collection.has_found("jade_pendant"));
+    assert!(!state.inventory.items.iter().any(|i| i.id == "jade_pendant"));
+    assert!(state.inventory.items.iter().all(|item| item.affixes.is_empty()));
+}
+
+#[test]
+fn v7_keeps_affixes_and_relics_start_at_level_one() {
+    let state = load_versioned(V7, 7);
+    assert_eq!(state.inventory.items[0].affixes, ["gleaming"]);
+    assert!(state.inventory.items[1..].iter().all(|item| item.affixes.is_empty()));
+    assert!(state.relics.shards.is_empty());
+    assert!(state.relics.levels.is_empty());
+}
+
+#[test]
+fn v8_keeps_relic_shards_and_levels_and_gains_loadouts() {
+    let state = load_versioned(V8, 8);
+    assert!(state.relics.is_equipped("lucky_coin"));
+    assert_eq!(state.relics.shards(Rarity::Uncommon), 3);
+    assert_eq!(state.relics.level("lucky_coin"), 2);
+    assert!(state.relics.loadouts.is_empty());
+}
+
+#[test]
+fn v9_keeps_loadouts_and_gains_respecs() {
+    let state = load_versioned(V9, 9);
+    let loadout = state.relics.loadout(0).expect("saved loadout");
+    assert_eq!(loadout.name, "Luck");
+    assert_eq!(loadout.ids, ["lucky_coin"]);
+    assert_eq!(state.skill_tree.respecs, 0);
+    assert!(state.skill_tree.paid.is_empty());
+}
+
+#[test]
+fn v10_keeps_respecs_and_gains_achievements() {
+    let state = load_versioned(V10, 10);
+    assert_eq!(state.skill_tree.respecs, 1);
+    assert_eq!(state.skill_tree.paid.get("lucky_charm"), Some(&1));
+    assert!(state.achievements.unlocked.is_empty());
+    assert_eq!(state.achievements.title, None);
+}
+
+#[test]
+fn v11_keeps_achievements_and_bounties_rotate_from_now() {
+    let state = load_versioned(V11, 11);
+    assert!(state.achievements.is_unlocked("first_steps"));
+    assert!(state.bounties.bounties.is_empty());
+    assert_eq!(state.bounties.refreshed_at, state.stats.chests_opened);
+}
+
+#[test]
+fn v12_keeps_bounties_and_gains_challenges() {
+    let state = load_versioned(V12, 12);
+    assert_eq!(state.bounties.refreshed_at, 50);
+    assert_eq!(state.bounties.bounties.len(), 1);
+    assert_eq!(state.bounties.bounties[0].progress, 4);
+    assert!(state.rebirth.challenge.is_none());
+    assert!(state.rebirth.challenges_completed.is_empty());
+}
+
+#[test]
+fn v13_keeps_challenges_and_gains_transcendence() {
+    let state = load_versioned(V13, 13);
+    assert!(state.rebirth.challenges_completed.contains("bare_hands"));
+    assert_eq!(state.transcendence.count, 0);
+    assert!(state.transcendence.aether.is_zero());
+    assert!(state.transcendence.skills.is_empty());
+}
+
+#[test]
+fn v14_keeps_transcendence_and_counts_no_divines() {
+    let state = load_versioned(V14, 14);
+    assert_eq!(state.transcendence.count, 1);
+    assert_eq!(state.transcendence.aether, 5);
+    assert!(state.transcendence.skills.contains("ts_crystal_dawn"));
+    assert_eq!(state.stats.divines_found, 0);
+}
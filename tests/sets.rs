@@ -0,0 +1,61 @@
+mod common;
+
+use lootbox_game::game::events::GameEvent;
+
+fn completed(events: &[GameEvent]) -> Vec<&str> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            GameEvent::SetCompleted { id } => Some(id.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn holding_the_last_piece_completes_a_set_once() {
+    let mut game = common::engine(7);
+    for id in ["rusty_coin", "torn_cloth", "feather"] {
+        game.state.inventory.add(common::item(id));
+    }
+    let speed = game.state.player.speed;
+
+    // Wooden chests drop the missing Bent Nail sooner or later
+    let mut done = false;
+    for _ in 0..1000 {
+        let (_, events) = game.open_chest_now();
+        let nail = events
+            .iter()
+            .any(|e| matches!(e, GameEvent::ItemFound { item, .. } if item.id == "bent_nail"));
+        let sets = completed(&events);
+        if done {
+            assert!(!sets.contains(&"wanderers_kit"), "completed twice");
+        } else {
+            assert_eq!(sets.contains(&"wanderers_kit"), nail);
+            done = nail;
+        }
+    }
+    assert!(done, "no nail in 1000 chests");
+    assert!(game.state.collection.is_complete("wanderers_kit"));
+    assert!(game.state.player.speed > speed);
+}
+
+#[test]
+fn a_completed_set_keeps_its_bonus_after_its_pieces_are_gone() {
+    let mut game = common::engine(7);
+    for id in ["rusty_coin", "torn_cloth", "feather"] {
+        game.state.inventory.add(common::item(id));
+    }
+    for _ in 0..1000 {
+        if game.state.collection.is_complete("wanderers_kit") {
+            break;
+        }
+        game.open_chest_now();
+    }
+    let speed = game.state.player.speed;
+
+    game.state.inventory.items.clear();
+    game.open_chest_now();
+    assert!(game.state.collection.is_complete("wanderers_kit"));
+    assert_eq!(game.state.player.speed, speed);
+}
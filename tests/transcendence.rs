@@ -0,0 +1,82 @@
+mod common;
+
+use lootbox_game::game::bignum::BigNum;
+use lootbox_game::game::chest::ChestType;
+use lootbox_game::game::events::{Command, GameEvent, Rejection};
+use lootbox_game::game::transcendence::{MIN_ESSENCE, TranscendenceState};
+
+#[test]
+fn aether_grows_with_the_root_of_essence_and_with_level() {
+    let state = TranscendenceState::default();
+    let aether = |essence: u64, level| state.calculate_aether_reward(BigNum::from(essence), level);
+    assert_eq!(aether(MIN_ESSENCE - 1, 100), 0);
+    assert_eq!(aether(MIN_ESSENCE, 50), 2);
+    assert_eq!(aether(250_000, 50), 10);
+    assert_eq!(aether(250_000, 100), 20);
+    // Anything that qualifies pays at least one
+    assert_eq!(aether(MIN_ESSENCE, 1), 1);
+}
+
+#[test]
+fn transcending_too_early_is_rejected() {
+    let mut game = common::engine(7);
+    game.state.rebirth.total_essence_earned = BigNum::from(MIN_ESSENCE - 1);
+    let events = game.apply(Command::Transcend);
+    assert!(matches!(
+        events[..],
+        [GameEvent::Rejected(Rejection::TranscendTooEarly { required: MIN_ESSENCE })]
+    ));
+    assert_eq!(game.state.transcendence.count, 0);
+}
+
+#[test]
+fn transcending_resets_rebirth_progress_for_aether() {
+    let mut game = common::engine(7);
+    let rebirth = &mut game.state.rebirth;
+    rebirth.total_essence_earned = BigNum::from(250_000u64);
+    rebirth.essence = BigNum::from(5_000u64);
+    rebirth.rebirth_count = 12;
+    rebirth.highest_level_ever = 80;
+    rebirth.rebirth_skills.insert("rb_starting_gp".into());
+    rebirth.challenges_completed.insert("molasses".into());
+    game.state.player.level = 100;
+    game.state.player.gp = BigNum::from(1_000_000u64);
+    game.state.achievements.unlocked.insert("first_steps".into());
+    let aether = game.aether_reward();
+    assert_eq!(aether, 20);
+
+    let events = game.apply(Command::Transcend);
+    assert!(events.iter().any(|e| matches!(e, GameEvent::Transcended { count: 1, aether: a } if *a == aether)));
+    let transcendence = &game.state.transcendence;
+    assert_eq!(transcendence.aether, aether);
+    assert_eq!(transcendence.total_aether_earned, aether);
+
+    let rebirth = &game.state.rebirth;
+    assert_eq!(rebirth.rebirth_count, 0);
+    assert!(rebirth.essence.is_zero());
+    assert!(rebirth.total_essence_earned.is_zero());
+    assert!(rebirth.rebirth_skills.is_empty());
+    assert_eq!(rebirth.highest_level_ever, 0);
+    assert!(rebirth.has_completed_challenge("molasses"));
+    assert!(game.state.achievements.is_unlocked("first_steps"));
+    assert_eq!(game.state.player.level, 1);
+    assert!(game.state.player.gp < 1_000_000);
+}
+
+#[test]
+fn transcendence_skills_cost_aether_and_outlast_rebirths() {
+    let mut game = common::engine(7);
+    game.state.transcendence.aether = BigNum::from(3u64);
+
+    let events = game.apply(Command::LearnTranscendenceSkill("ts_crystal_dawn".into()));
+    assert!(events.iter().any(|e| matches!(e, GameEvent::ChestUnlocked(ChestType::Crystal))));
+    assert_eq!(game.state.transcendence.aether, 2);
+
+    let events = game.apply(Command::LearnTranscendenceSkill("ts_shadow_dawn".into()));
+    assert!(matches!(events[..], [GameEvent::Rejected(Rejection::NotEnoughAether { cost: 3 })]));
+
+    game.state.player.level = game.state.rebirth.min_level_for_rebirth();
+    game.apply(Command::Rebirth);
+    assert!(game.state.transcendence.has_skill("ts_crystal_dawn"));
+    assert!(game.state.unlocked_chests.contains(&ChestType::Crystal));
+}